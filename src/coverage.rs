@@ -0,0 +1,1893 @@
+//! Unicode blocks, scripts and languages coverage.
+//!
+//! Based on Unicode 14.0 data. Language exemplar characters are based on
+//! [CLDR](https://cldr.unicode.org/) main exemplar sets,
+//! with the exception of CJK languages, which are represented by a small set
+//! of frequently used characters.
+
+use crate::language::LanguageTagParts;
+use crate::{cmap, Face};
+
+const BLOCKS_LEN: usize = 320;
+const SCRIPTS_LEN: usize = 161;
+
+/// A [Unicode block](https://www.unicode.org/Public/UCD/latest/ucd/Blocks.txt).
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Block {
+    /// The first code point of the block.
+    pub start: u32,
+    /// The last code point of the block.
+    pub end: u32,
+    /// A block name, like `Basic Latin`.
+    pub name: &'static str,
+}
+
+impl Block {
+    /// Returns a block that contains the specified character.
+    pub fn from_char(c: char) -> Option<Self> {
+        block_index(u32::from(c)).map(Self::from_index)
+    }
+
+    fn from_index(index: usize) -> Self {
+        let (start, end, name, _) = BLOCKS[index];
+        Block { start, end, name }
+    }
+}
+
+/// A [Unicode script](https://www.unicode.org/reports/tr24/).
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Script {
+    /// An [ISO 15924](https://unicode.org/iso15924/) script code, like `Latn`.
+    pub code: &'static str,
+    /// A script name, like `Latin`.
+    pub name: &'static str,
+}
+
+impl Script {
+    /// Returns a script of the specified character.
+    ///
+    /// Returns `None` for unassigned, private use, surrogate and control characters.
+    pub fn from_char(c: char) -> Option<Self> {
+        script_index(u32::from(c)).map(Self::from_index)
+    }
+
+    /// Returns a script with the specified ISO 15924 code.
+    ///
+    /// The code is case-insensitive.
+    pub fn from_code(code: &str) -> Option<Self> {
+        SCRIPTS
+            .iter()
+            .position(|v| v.0.eq_ignore_ascii_case(code))
+            .map(Self::from_index)
+    }
+
+    fn from_index(index: usize) -> Self {
+        let (code, name, _) = SCRIPTS[index];
+        Script { code, name }
+    }
+}
+
+/// A Unicode block coverage.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct BlockCoverage {
+    /// A block.
+    pub block: Block,
+    /// The number of characters from the block mapped by a font.
+    pub covered: u32,
+    /// The number of characters in the block.
+    ///
+    /// Unassigned, surrogate and control characters are excluded.
+    pub total: u32,
+}
+
+impl BlockCoverage {
+    /// Returns the covered characters percentage in a 0..=100 range.
+    #[inline]
+    pub fn percentage(&self) -> f32 {
+        percentage(self.covered, self.total)
+    }
+}
+
+/// A Unicode script coverage.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ScriptCoverage {
+    /// A script.
+    pub script: Script,
+    /// The number of characters from the script mapped by a font.
+    pub covered: u32,
+    /// The number of characters in the script.
+    ///
+    /// Control characters are excluded.
+    pub total: u32,
+}
+
+impl ScriptCoverage {
+    /// Returns the covered characters percentage in a 0..=100 range.
+    #[inline]
+    pub fn percentage(&self) -> f32 {
+        percentage(self.covered, self.total)
+    }
+}
+
+fn percentage(covered: u32, total: u32) -> f32 {
+    if total == 0 {
+        0.0
+    } else {
+        covered as f32 / total as f32 * 100.0
+    }
+}
+
+/// Unicode blocks and scripts coverage of a `cmap` subtable.
+#[derive(Clone)]
+pub struct Coverage {
+    blocks: [u32; BLOCKS_LEN],
+    scripts: [u32; SCRIPTS_LEN],
+}
+
+impl Coverage {
+    /// Calculates a coverage of a Unicode `cmap` subtable.
+    ///
+    /// Iterates over all subtable [mappings](cmap::Subtable::mappings),
+    /// so it's not a cheap operation.
+    pub fn from_subtable(subtable: cmap::Subtable) -> Self {
+        let mut coverage = Coverage {
+            blocks: [0; BLOCKS_LEN],
+            scripts: [0; SCRIPTS_LEN],
+        };
+
+        for (code_point, _) in subtable.mappings() {
+            let script = script_index(code_point);
+            if script.is_none() && !is_private_use(code_point) {
+                continue;
+            }
+
+            if let Some(index) = block_index(code_point) {
+                coverage.blocks[index] += 1;
+            }
+
+            if let Some(index) = script {
+                coverage.scripts[index] += 1;
+            }
+        }
+
+        coverage
+    }
+
+    /// Returns a coverage of the block that contains the specified character.
+    pub fn block(&self, c: char) -> Option<BlockCoverage> {
+        block_index(u32::from(c)).map(|index| self.block_at(index))
+    }
+
+    /// Returns a coverage of a script with the specified ISO 15924 code.
+    pub fn script(&self, code: &str) -> Option<ScriptCoverage> {
+        SCRIPTS
+            .iter()
+            .position(|v| v.0.eq_ignore_ascii_case(code))
+            .map(|index| self.script_at(index))
+    }
+
+    /// Returns an iterator over blocks with at least one covered character.
+    ///
+    /// Blocks are sorted by code point.
+    #[inline]
+    pub fn blocks(&self) -> BlocksCoverageIter<'_> {
+        BlocksCoverageIter {
+            coverage: self,
+            index: 0,
+        }
+    }
+
+    /// Returns an iterator over scripts with at least one covered character.
+    ///
+    /// Scripts are sorted by code.
+    #[inline]
+    pub fn scripts(&self) -> ScriptsCoverageIter<'_> {
+        ScriptsCoverageIter {
+            coverage: self,
+            index: 0,
+        }
+    }
+
+    fn block_at(&self, index: usize) -> BlockCoverage {
+        let total = BLOCKS[index].3;
+        BlockCoverage {
+            block: Block::from_index(index),
+            // A malformed subtable can map the same code point multiple times.
+            covered: self.blocks[index].min(total),
+            total,
+        }
+    }
+
+    fn script_at(&self, index: usize) -> ScriptCoverage {
+        let total = SCRIPTS[index].2;
+        ScriptCoverage {
+            script: Script::from_index(index),
+            covered: self.scripts[index].min(total),
+            total,
+        }
+    }
+}
+
+impl core::fmt::Debug for Coverage {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "Coverage {{ ... }}")
+    }
+}
+
+/// An iterator over covered blocks.
+#[derive(Clone, Copy)]
+#[allow(missing_debug_implementations)]
+pub struct BlocksCoverageIter<'a> {
+    coverage: &'a Coverage,
+    index: usize,
+}
+
+impl Iterator for BlocksCoverageIter<'_> {
+    type Item = BlockCoverage;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.index < BLOCKS_LEN {
+            self.index += 1;
+            if self.coverage.blocks[self.index - 1] != 0 {
+                return Some(self.coverage.block_at(self.index - 1));
+            }
+        }
+
+        None
+    }
+}
+
+/// An iterator over covered scripts.
+#[derive(Clone, Copy)]
+#[allow(missing_debug_implementations)]
+pub struct ScriptsCoverageIter<'a> {
+    coverage: &'a Coverage,
+    index: usize,
+}
+
+impl Iterator for ScriptsCoverageIter<'_> {
+    type Item = ScriptCoverage;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.index < SCRIPTS_LEN {
+            self.index += 1;
+            if self.coverage.scripts[self.index - 1] != 0 {
+                return Some(self.coverage.script_at(self.index - 1));
+            }
+        }
+
+        None
+    }
+}
+
+/// Returns exemplar characters of a language.
+///
+/// `language` is a BCP 47 language tag, like `vi` or `zh-TW`.
+/// The closest known language is used, so `pt-BR` resolves to `pt`
+/// and `zh-TW` to `zh-Hant`.
+///
+/// For cased scripts, only lowercase characters are stored.
+///
+/// Returns `None` for unknown languages.
+pub fn language_exemplars(language: &str) -> Option<&'static str> {
+    language_index(language).map(|index| LANGUAGES[index].2)
+}
+
+/// Returns an iterator over all languages with known exemplar characters.
+///
+/// Languages are represented by BCP 47 tags, like `en` or `zh-Hant`.
+#[inline]
+pub fn languages() -> Languages {
+    Languages { index: 0 }
+}
+
+/// An iterator over languages with known exemplar characters.
+#[derive(Clone, Copy)]
+#[allow(missing_debug_implementations)]
+pub struct Languages {
+    index: usize,
+}
+
+impl Iterator for Languages {
+    type Item = &'static str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let tag = LANGUAGES.get(self.index)?.0;
+        self.index += 1;
+        Some(tag)
+    }
+}
+
+/// An iterator over languages supported by a face.
+///
+/// Returned by [`Face::supported_languages`].
+#[derive(Clone, Copy)]
+#[allow(missing_debug_implementations)]
+pub struct SupportedLanguages<'a> {
+    pub(crate) face: &'a Face<'a>,
+    pub(crate) languages: Languages,
+}
+
+impl Iterator for SupportedLanguages<'_> {
+    type Item = &'static str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let face = self.face;
+        self.languages
+            .by_ref()
+            .find(|tag| supports_language(face, tag))
+    }
+}
+
+pub(crate) fn supports_language(face: &Face, language: &str) -> bool {
+    let (_, cased, exemplars) = match language_index(language) {
+        Some(index) => LANGUAGES[index],
+        None => return false,
+    };
+
+    exemplars.chars().all(|c| {
+        face.glyph_index(c).is_some()
+            && (!cased || c.to_uppercase().all(|c| face.glyph_index(c).is_some()))
+    })
+}
+
+fn language_index(language: &str) -> Option<usize> {
+    let locale = LanguageTagParts::parse(language.chars());
+    let mut best = None;
+    let mut best_score = 0;
+    for (index, (tag, _, _)) in LANGUAGES.iter().enumerate() {
+        let score = LanguageTagParts::parse(tag.chars()).match_score(&locale);
+        if score > best_score {
+            best = Some(index);
+            best_score = score;
+        }
+    }
+
+    best
+}
+
+fn block_index(c: u32) -> Option<usize> {
+    let index = match BLOCKS.binary_search_by(|v| v.0.cmp(&c)) {
+        Ok(index) => index,
+        Err(index) => index.checked_sub(1)?,
+    };
+
+    if c <= BLOCKS[index].1 {
+        Some(index)
+    } else {
+        None
+    }
+}
+
+fn script_index(c: u32) -> Option<usize> {
+    let index = match SCRIPT_RANGES.binary_search_by(|v| v.0.cmp(&c)) {
+        Ok(index) => index,
+        Err(index) => index.checked_sub(1)?,
+    };
+
+    let (_, end, script) = SCRIPT_RANGES[index];
+    if c <= end {
+        Some(usize::from(script))
+    } else {
+        None
+    }
+}
+
+fn is_private_use(c: u32) -> bool {
+    matches!(c, 0xE000..=0xF8FF | 0xF0000..=0xFFFFD | 0x100000..=0x10FFFD)
+}
+
+#[rustfmt::skip]
+static BLOCKS: [(u32, u32, &str, u32); BLOCKS_LEN] = [
+    (0x0000, 0x007F, "Basic Latin", 95),
+    (0x0080, 0x00FF, "Latin-1 Supplement", 96),
+    (0x0100, 0x017F, "Latin Extended-A", 128),
+    (0x0180, 0x024F, "Latin Extended-B", 208),
+    (0x0250, 0x02AF, "IPA Extensions", 96),
+    (0x02B0, 0x02FF, "Spacing Modifier Letters", 80),
+    (0x0300, 0x036F, "Combining Diacritical Marks", 112),
+    (0x0370, 0x03FF, "Greek and Coptic", 135),
+    (0x0400, 0x04FF, "Cyrillic", 256),
+    (0x0500, 0x052F, "Cyrillic Supplement", 48),
+    (0x0530, 0x058F, "Armenian", 91),
+    (0x0590, 0x05FF, "Hebrew", 88),
+    (0x0600, 0x06FF, "Arabic", 256),
+    (0x0700, 0x074F, "Syriac", 77),
+    (0x0750, 0x077F, "Arabic Supplement", 48),
+    (0x0780, 0x07BF, "Thaana", 50),
+    (0x07C0, 0x07FF, "NKo", 62),
+    (0x0800, 0x083F, "Samaritan", 61),
+    (0x0840, 0x085F, "Mandaic", 29),
+    (0x0860, 0x086F, "Syriac Supplement", 11),
+    (0x0870, 0x089F, "Arabic Extended-B", 41),
+    (0x08A0, 0x08FF, "Arabic Extended-A", 96),
+    (0x0900, 0x097F, "Devanagari", 128),
+    (0x0980, 0x09FF, "Bengali", 96),
+    (0x0A00, 0x0A7F, "Gurmukhi", 80),
+    (0x0A80, 0x0AFF, "Gujarati", 91),
+    (0x0B00, 0x0B7F, "Oriya", 91),
+    (0x0B80, 0x0BFF, "Tamil", 72),
+    (0x0C00, 0x0C7F, "Telugu", 100),
+    (0x0C80, 0x0CFF, "Kannada", 90),
+    (0x0D00, 0x0D7F, "Malayalam", 118),
+    (0x0D80, 0x0DFF, "Sinhala", 91),
+    (0x0E00, 0x0E7F, "Thai", 87),
+    (0x0E80, 0x0EFF, "Lao", 82),
+    (0x0F00, 0x0FFF, "Tibetan", 211),
+    (0x1000, 0x109F, "Myanmar", 160),
+    (0x10A0, 0x10FF, "Georgian", 88),
+    (0x1100, 0x11FF, "Hangul Jamo", 256),
+    (0x1200, 0x137F, "Ethiopic", 358),
+    (0x1380, 0x139F, "Ethiopic Supplement", 26),
+    (0x13A0, 0x13FF, "Cherokee", 92),
+    (0x1400, 0x167F, "Unified Canadian Aboriginal Syllabics", 640),
+    (0x1680, 0x169F, "Ogham", 29),
+    (0x16A0, 0x16FF, "Runic", 89),
+    (0x1700, 0x171F, "Tagalog", 23),
+    (0x1720, 0x173F, "Hanunoo", 23),
+    (0x1740, 0x175F, "Buhid", 20),
+    (0x1760, 0x177F, "Tagbanwa", 18),
+    (0x1780, 0x17FF, "Khmer", 114),
+    (0x1800, 0x18AF, "Mongolian", 158),
+    (0x18B0, 0x18FF, "Unified Canadian Aboriginal Syllabics Extended", 70),
+    (0x1900, 0x194F, "Limbu", 68),
+    (0x1950, 0x197F, "Tai Le", 35),
+    (0x1980, 0x19DF, "New Tai Lue", 83),
+    (0x19E0, 0x19FF, "Khmer Symbols", 32),
+    (0x1A00, 0x1A1F, "Buginese", 30),
+    (0x1A20, 0x1AAF, "Tai Tham", 127),
+    (0x1AB0, 0x1AFF, "Combining Diacritical Marks Extended", 31),
+    (0x1B00, 0x1B7F, "Balinese", 124),
+    (0x1B80, 0x1BBF, "Sundanese", 64),
+    (0x1BC0, 0x1BFF, "Batak", 56),
+    (0x1C00, 0x1C4F, "Lepcha", 74),
+    (0x1C50, 0x1C7F, "Ol Chiki", 48),
+    (0x1C80, 0x1C8F, "Cyrillic Extended-C", 9),
+    (0x1C90, 0x1CBF, "Georgian Extended", 46),
+    (0x1CC0, 0x1CCF, "Sundanese Supplement", 8),
+    (0x1CD0, 0x1CFF, "Vedic Extensions", 43),
+    (0x1D00, 0x1D7F, "Phonetic Extensions", 128),
+    (0x1D80, 0x1DBF, "Phonetic Extensions Supplement", 64),
+    (0x1DC0, 0x1DFF, "Combining Diacritical Marks Supplement", 64),
+    (0x1E00, 0x1EFF, "Latin Extended Additional", 256),
+    (0x1F00, 0x1FFF, "Greek Extended", 233),
+    (0x2000, 0x206F, "General Punctuation", 111),
+    (0x2070, 0x209F, "Superscripts and Subscripts", 42),
+    (0x20A0, 0x20CF, "Currency Symbols", 33),
+    (0x20D0, 0x20FF, "Combining Diacritical Marks for Symbols", 33),
+    (0x2100, 0x214F, "Letterlike Symbols", 80),
+    (0x2150, 0x218F, "Number Forms", 60),
+    (0x2190, 0x21FF, "Arrows", 112),
+    (0x2200, 0x22FF, "Mathematical Operators", 256),
+    (0x2300, 0x23FF, "Miscellaneous Technical", 256),
+    (0x2400, 0x243F, "Control Pictures", 39),
+    (0x2440, 0x245F, "Optical Character Recognition", 11),
+    (0x2460, 0x24FF, "Enclosed Alphanumerics", 160),
+    (0x2500, 0x257F, "Box Drawing", 128),
+    (0x2580, 0x259F, "Block Elements", 32),
+    (0x25A0, 0x25FF, "Geometric Shapes", 96),
+    (0x2600, 0x26FF, "Miscellaneous Symbols", 256),
+    (0x2700, 0x27BF, "Dingbats", 192),
+    (0x27C0, 0x27EF, "Miscellaneous Mathematical Symbols-A", 48),
+    (0x27F0, 0x27FF, "Supplemental Arrows-A", 16),
+    (0x2800, 0x28FF, "Braille Patterns", 256),
+    (0x2900, 0x297F, "Supplemental Arrows-B", 128),
+    (0x2980, 0x29FF, "Miscellaneous Mathematical Symbols-B", 128),
+    (0x2A00, 0x2AFF, "Supplemental Mathematical Operators", 256),
+    (0x2B00, 0x2BFF, "Miscellaneous Symbols and Arrows", 253),
+    (0x2C00, 0x2C5F, "Glagolitic", 96),
+    (0x2C60, 0x2C7F, "Latin Extended-C", 32),
+    (0x2C80, 0x2CFF, "Coptic", 123),
+    (0x2D00, 0x2D2F, "Georgian Supplement", 40),
+    (0x2D30, 0x2D7F, "Tifinagh", 59),
+    (0x2D80, 0x2DDF, "Ethiopic Extended", 79),
+    (0x2DE0, 0x2DFF, "Cyrillic Extended-A", 32),
+    (0x2E00, 0x2E7F, "Supplemental Punctuation", 94),
+    (0x2E80, 0x2EFF, "CJK Radicals Supplement", 115),
+    (0x2F00, 0x2FDF, "Kangxi Radicals", 214),
+    (0x2FF0, 0x2FFF, "Ideographic Description Characters", 12),
+    (0x3000, 0x303F, "CJK Symbols and Punctuation", 64),
+    (0x3040, 0x309F, "Hiragana", 93),
+    (0x30A0, 0x30FF, "Katakana", 96),
+    (0x3100, 0x312F, "Bopomofo", 43),
+    (0x3130, 0x318F, "Hangul Compatibility Jamo", 94),
+    (0x3190, 0x319F, "Kanbun", 16),
+    (0x31A0, 0x31BF, "Bopomofo Extended", 32),
+    (0x31C0, 0x31EF, "CJK Strokes", 36),
+    (0x31F0, 0x31FF, "Katakana Phonetic Extensions", 16),
+    (0x3200, 0x32FF, "Enclosed CJK Letters and Months", 255),
+    (0x3300, 0x33FF, "CJK Compatibility", 256),
+    (0x3400, 0x4DBF, "CJK Unified Ideographs Extension A", 6592),
+    (0x4DC0, 0x4DFF, "Yijing Hexagram Symbols", 64),
+    (0x4E00, 0x9FFF, "CJK Unified Ideographs", 20992),
+    (0xA000, 0xA48F, "Yi Syllables", 1165),
+    (0xA490, 0xA4CF, "Yi Radicals", 55),
+    (0xA4D0, 0xA4FF, "Lisu", 48),
+    (0xA500, 0xA63F, "Vai", 300),
+    (0xA640, 0xA69F, "Cyrillic Extended-B", 96),
+    (0xA6A0, 0xA6FF, "Bamum", 88),
+    (0xA700, 0xA71F, "Modifier Tone Letters", 32),
+    (0xA720, 0xA7FF, "Latin Extended-D", 193),
+    (0xA800, 0xA82F, "Syloti Nagri", 45),
+    (0xA830, 0xA83F, "Common Indic Number Forms", 10),
+    (0xA840, 0xA87F, "Phags-pa", 56),
+    (0xA880, 0xA8DF, "Saurashtra", 82),
+    (0xA8E0, 0xA8FF, "Devanagari Extended", 32),
+    (0xA900, 0xA92F, "Kayah Li", 48),
+    (0xA930, 0xA95F, "Rejang", 37),
+    (0xA960, 0xA97F, "Hangul Jamo Extended-A", 29),
+    (0xA980, 0xA9DF, "Javanese", 91),
+    (0xA9E0, 0xA9FF, "Myanmar Extended-B", 31),
+    (0xAA00, 0xAA5F, "Cham", 83),
+    (0xAA60, 0xAA7F, "Myanmar Extended-A", 32),
+    (0xAA80, 0xAADF, "Tai Viet", 72),
+    (0xAAE0, 0xAAFF, "Meetei Mayek Extensions", 23),
+    (0xAB00, 0xAB2F, "Ethiopic Extended-A", 32),
+    (0xAB30, 0xAB6F, "Latin Extended-E", 60),
+    (0xAB70, 0xABBF, "Cherokee Supplement", 80),
+    (0xABC0, 0xABFF, "Meetei Mayek", 56),
+    (0xAC00, 0xD7AF, "Hangul Syllables", 11172),
+    (0xD7B0, 0xD7FF, "Hangul Jamo Extended-B", 72),
+    (0xD800, 0xDB7F, "High Surrogates", 0),
+    (0xDB80, 0xDBFF, "High Private Use Surrogates", 0),
+    (0xDC00, 0xDFFF, "Low Surrogates", 0),
+    (0xE000, 0xF8FF, "Private Use Area", 6400),
+    (0xF900, 0xFAFF, "CJK Compatibility Ideographs", 472),
+    (0xFB00, 0xFB4F, "Alphabetic Presentation Forms", 58),
+    (0xFB50, 0xFDFF, "Arabic Presentation Forms-A", 631),
+    (0xFE00, 0xFE0F, "Variation Selectors", 16),
+    (0xFE10, 0xFE1F, "Vertical Forms", 10),
+    (0xFE20, 0xFE2F, "Combining Half Marks", 16),
+    (0xFE30, 0xFE4F, "CJK Compatibility Forms", 32),
+    (0xFE50, 0xFE6F, "Small Form Variants", 26),
+    (0xFE70, 0xFEFF, "Arabic Presentation Forms-B", 141),
+    (0xFF00, 0xFFEF, "Halfwidth and Fullwidth Forms", 225),
+    (0xFFF0, 0xFFFF, "Specials", 5),
+    (0x10000, 0x1007F, "Linear B Syllabary", 88),
+    (0x10080, 0x100FF, "Linear B Ideograms", 123),
+    (0x10100, 0x1013F, "Aegean Numbers", 57),
+    (0x10140, 0x1018F, "Ancient Greek Numbers", 79),
+    (0x10190, 0x101CF, "Ancient Symbols", 14),
+    (0x101D0, 0x101FF, "Phaistos Disc", 46),
+    (0x10280, 0x1029F, "Lycian", 29),
+    (0x102A0, 0x102DF, "Carian", 49),
+    (0x102E0, 0x102FF, "Coptic Epact Numbers", 28),
+    (0x10300, 0x1032F, "Old Italic", 39),
+    (0x10330, 0x1034F, "Gothic", 27),
+    (0x10350, 0x1037F, "Old Permic", 43),
+    (0x10380, 0x1039F, "Ugaritic", 31),
+    (0x103A0, 0x103DF, "Old Persian", 50),
+    (0x10400, 0x1044F, "Deseret", 80),
+    (0x10450, 0x1047F, "Shavian", 48),
+    (0x10480, 0x104AF, "Osmanya", 40),
+    (0x104B0, 0x104FF, "Osage", 72),
+    (0x10500, 0x1052F, "Elbasan", 40),
+    (0x10530, 0x1056F, "Caucasian Albanian", 53),
+    (0x10570, 0x105BF, "Vithkuqi", 70),
+    (0x10600, 0x1077F, "Linear A", 341),
+    (0x10780, 0x107BF, "Latin Extended-F", 57),
+    (0x10800, 0x1083F, "Cypriot Syllabary", 55),
+    (0x10840, 0x1085F, "Imperial Aramaic", 31),
+    (0x10860, 0x1087F, "Palmyrene", 32),
+    (0x10880, 0x108AF, "Nabataean", 40),
+    (0x108E0, 0x108FF, "Hatran", 26),
+    (0x10900, 0x1091F, "Phoenician", 29),
+    (0x10920, 0x1093F, "Lydian", 27),
+    (0x10980, 0x1099F, "Meroitic Hieroglyphs", 32),
+    (0x109A0, 0x109FF, "Meroitic Cursive", 90),
+    (0x10A00, 0x10A5F, "Kharoshthi", 68),
+    (0x10A60, 0x10A7F, "Old South Arabian", 32),
+    (0x10A80, 0x10A9F, "Old North Arabian", 32),
+    (0x10AC0, 0x10AFF, "Manichaean", 51),
+    (0x10B00, 0x10B3F, "Avestan", 61),
+    (0x10B40, 0x10B5F, "Inscriptional Parthian", 30),
+    (0x10B60, 0x10B7F, "Inscriptional Pahlavi", 27),
+    (0x10B80, 0x10BAF, "Psalter Pahlavi", 29),
+    (0x10C00, 0x10C4F, "Old Turkic", 73),
+    (0x10C80, 0x10CFF, "Old Hungarian", 108),
+    (0x10D00, 0x10D3F, "Hanifi Rohingya", 50),
+    (0x10E60, 0x10E7F, "Rumi Numeral Symbols", 31),
+    (0x10E80, 0x10EBF, "Yezidi", 47),
+    (0x10F00, 0x10F2F, "Old Sogdian", 40),
+    (0x10F30, 0x10F6F, "Sogdian", 42),
+    (0x10F70, 0x10FAF, "Old Uyghur", 26),
+    (0x10FB0, 0x10FDF, "Chorasmian", 28),
+    (0x10FE0, 0x10FFF, "Elymaic", 23),
+    (0x11000, 0x1107F, "Brahmi", 115),
+    (0x11080, 0x110CF, "Kaithi", 68),
+    (0x110D0, 0x110FF, "Sora Sompeng", 35),
+    (0x11100, 0x1114F, "Chakma", 71),
+    (0x11150, 0x1117F, "Mahajani", 39),
+    (0x11180, 0x111DF, "Sharada", 96),
+    (0x111E0, 0x111FF, "Sinhala Archaic Numbers", 20),
+    (0x11200, 0x1124F, "Khojki", 62),
+    (0x11280, 0x112AF, "Multani", 38),
+    (0x112B0, 0x112FF, "Khudawadi", 69),
+    (0x11300, 0x1137F, "Grantha", 86),
+    (0x11400, 0x1147F, "Newa", 97),
+    (0x11480, 0x114DF, "Tirhuta", 82),
+    (0x11580, 0x115FF, "Siddham", 92),
+    (0x11600, 0x1165F, "Modi", 79),
+    (0x11660, 0x1167F, "Mongolian Supplement", 13),
+    (0x11680, 0x116CF, "Takri", 68),
+    (0x11700, 0x1174F, "Ahom", 65),
+    (0x11800, 0x1184F, "Dogra", 60),
+    (0x118A0, 0x118FF, "Warang Citi", 84),
+    (0x11900, 0x1195F, "Dives Akuru", 72),
+    (0x119A0, 0x119FF, "Nandinagari", 65),
+    (0x11A00, 0x11A4F, "Zanabazar Square", 72),
+    (0x11A50, 0x11AAF, "Soyombo", 83),
+    (0x11AB0, 0x11ABF, "Unified Canadian Aboriginal Syllabics Extended-A", 16),
+    (0x11AC0, 0x11AFF, "Pau Cin Hau", 57),
+    (0x11C00, 0x11C6F, "Bhaiksuki", 97),
+    (0x11C70, 0x11CBF, "Marchen", 68),
+    (0x11D00, 0x11D5F, "Masaram Gondi", 75),
+    (0x11D60, 0x11DAF, "Gunjala Gondi", 63),
+    (0x11EE0, 0x11EFF, "Makasar", 25),
+    (0x11FB0, 0x11FBF, "Lisu Supplement", 1),
+    (0x11FC0, 0x11FFF, "Tamil Supplement", 51),
+    (0x12000, 0x123FF, "Cuneiform", 922),
+    (0x12400, 0x1247F, "Cuneiform Numbers and Punctuation", 116),
+    (0x12480, 0x1254F, "Early Dynastic Cuneiform", 196),
+    (0x12F90, 0x12FFF, "Cypro-Minoan", 99),
+    (0x13000, 0x1342F, "Egyptian Hieroglyphs", 1071),
+    (0x13430, 0x1343F, "Egyptian Hieroglyph Format Controls", 9),
+    (0x14400, 0x1467F, "Anatolian Hieroglyphs", 583),
+    (0x16800, 0x16A3F, "Bamum Supplement", 569),
+    (0x16A40, 0x16A6F, "Mro", 43),
+    (0x16A70, 0x16ACF, "Tangsa", 89),
+    (0x16AD0, 0x16AFF, "Bassa Vah", 36),
+    (0x16B00, 0x16B8F, "Pahawh Hmong", 127),
+    (0x16E40, 0x16E9F, "Medefaidrin", 91),
+    (0x16F00, 0x16F9F, "Miao", 149),
+    (0x16FE0, 0x16FFF, "Ideographic Symbols and Punctuation", 7),
+    (0x17000, 0x187FF, "Tangut", 6136),
+    (0x18800, 0x18AFF, "Tangut Components", 768),
+    (0x18B00, 0x18CFF, "Khitan Small Script", 470),
+    (0x18D00, 0x18D7F, "Tangut Supplement", 9),
+    (0x1AFF0, 0x1AFFF, "Kana Extended-B", 13),
+    (0x1B000, 0x1B0FF, "Kana Supplement", 256),
+    (0x1B100, 0x1B12F, "Kana Extended-A", 35),
+    (0x1B130, 0x1B16F, "Small Kana Extension", 7),
+    (0x1B170, 0x1B2FF, "Nushu", 396),
+    (0x1BC00, 0x1BC9F, "Duployan", 143),
+    (0x1BCA0, 0x1BCAF, "Shorthand Format Controls", 4),
+    (0x1CF00, 0x1CFCF, "Znamenny Musical Notation", 185),
+    (0x1D000, 0x1D0FF, "Byzantine Musical Symbols", 246),
+    (0x1D100, 0x1D1FF, "Musical Symbols", 233),
+    (0x1D200, 0x1D24F, "Ancient Greek Musical Notation", 70),
+    (0x1D2E0, 0x1D2FF, "Mayan Numerals", 20),
+    (0x1D300, 0x1D35F, "Tai Xuan Jing Symbols", 87),
+    (0x1D360, 0x1D37F, "Counting Rod Numerals", 25),
+    (0x1D400, 0x1D7FF, "Mathematical Alphanumeric Symbols", 996),
+    (0x1D800, 0x1DAAF, "Sutton SignWriting", 672),
+    (0x1DF00, 0x1DFFF, "Latin Extended-G", 31),
+    (0x1E000, 0x1E02F, "Glagolitic Supplement", 38),
+    (0x1E100, 0x1E14F, "Nyiakeng Puachue Hmong", 71),
+    (0x1E290, 0x1E2BF, "Toto", 31),
+    (0x1E2C0, 0x1E2FF, "Wancho", 59),
+    (0x1E7E0, 0x1E7FF, "Ethiopic Extended-B", 28),
+    (0x1E800, 0x1E8DF, "Mende Kikakui", 213),
+    (0x1E900, 0x1E95F, "Adlam", 88),
+    (0x1EC70, 0x1ECBF, "Indic Siyaq Numbers", 68),
+    (0x1ED00, 0x1ED4F, "Ottoman Siyaq Numbers", 61),
+    (0x1EE00, 0x1EEFF, "Arabic Mathematical Alphabetic Symbols", 143),
+    (0x1F000, 0x1F02F, "Mahjong Tiles", 44),
+    (0x1F030, 0x1F09F, "Domino Tiles", 100),
+    (0x1F0A0, 0x1F0FF, "Playing Cards", 82),
+    (0x1F100, 0x1F1FF, "Enclosed Alphanumeric Supplement", 200),
+    (0x1F200, 0x1F2FF, "Enclosed Ideographic Supplement", 64),
+    (0x1F300, 0x1F5FF, "Miscellaneous Symbols and Pictographs", 768),
+    (0x1F600, 0x1F64F, "Emoticons", 80),
+    (0x1F650, 0x1F67F, "Ornamental Dingbats", 48),
+    (0x1F680, 0x1F6FF, "Transport and Map Symbols", 117),
+    (0x1F700, 0x1F77F, "Alchemical Symbols", 116),
+    (0x1F780, 0x1F7FF, "Geometric Shapes Extended", 102),
+    (0x1F800, 0x1F8FF, "Supplemental Arrows-C", 150),
+    (0x1F900, 0x1F9FF, "Supplemental Symbols and Pictographs", 256),
+    (0x1FA00, 0x1FA6F, "Chess Symbols", 98),
+    (0x1FA70, 0x1FAFF, "Symbols and Pictographs Extended-A", 88),
+    (0x1FB00, 0x1FBFF, "Symbols for Legacy Computing", 212),
+    (0x20000, 0x2A6DF, "CJK Unified Ideographs Extension B", 42720),
+    (0x2A700, 0x2B73F, "CJK Unified Ideographs Extension C", 4153),
+    (0x2B740, 0x2B81F, "CJK Unified Ideographs Extension D", 222),
+    (0x2B820, 0x2CEAF, "CJK Unified Ideographs Extension E", 5762),
+    (0x2CEB0, 0x2EBEF, "CJK Unified Ideographs Extension F", 7473),
+    (0x2F800, 0x2FA1F, "CJK Compatibility Ideographs Supplement", 542),
+    (0x30000, 0x3134F, "CJK Unified Ideographs Extension G", 4939),
+    (0xE0000, 0xE007F, "Tags", 97),
+    (0xE0100, 0xE01EF, "Variation Selectors Supplement", 240),
+    (0xF0000, 0xFFFFF, "Supplementary Private Use Area-A", 65534),
+    (0x100000, 0x10FFFF, "Supplementary Private Use Area-B", 65534),
+];
+
+#[rustfmt::skip]
+static SCRIPTS: [(&str, &str, u32); SCRIPTS_LEN] = [
+    ("Adlm", "Adlam", 88),
+    ("Aghb", "Caucasian Albanian", 53),
+    ("Ahom", "Ahom", 65),
+    ("Arab", "Arabic", 1365),
+    ("Armi", "Imperial Aramaic", 31),
+    ("Armn", "Armenian", 96),
+    ("Avst", "Avestan", 61),
+    ("Bali", "Balinese", 124),
+    ("Bamu", "Bamum", 657),
+    ("Bass", "Bassa Vah", 36),
+    ("Batk", "Batak", 56),
+    ("Beng", "Bengali", 96),
+    ("Bhks", "Bhaiksuki", 97),
+    ("Bopo", "Bopomofo", 77),
+    ("Brah", "Brahmi", 115),
+    ("Brai", "Braille", 256),
+    ("Bugi", "Buginese", 30),
+    ("Buhd", "Buhid", 20),
+    ("Cakm", "Chakma", 71),
+    ("Cans", "Canadian Aboriginal", 726),
+    ("Cari", "Carian", 49),
+    ("Cham", "Cham", 83),
+    ("Cher", "Cherokee", 172),
+    ("Chrs", "Chorasmian", 28),
+    ("Copt", "Coptic", 137),
+    ("Cpmn", "Cypro Minoan", 99),
+    ("Cprt", "Cypriot", 55),
+    ("Cyrl", "Cyrillic", 443),
+    ("Deva", "Devanagari", 154),
+    ("Diak", "Dives Akuru", 72),
+    ("Dogr", "Dogra", 60),
+    ("Dsrt", "Deseret", 80),
+    ("Dupl", "Duployan", 143),
+    ("Egyp", "Egyptian Hieroglyphs", 1080),
+    ("Elba", "Elbasan", 40),
+    ("Elym", "Elymaic", 23),
+    ("Ethi", "Ethiopic", 523),
+    ("Geor", "Georgian", 173),
+    ("Glag", "Glagolitic", 134),
+    ("Gong", "Gunjala Gondi", 63),
+    ("Gonm", "Masaram Gondi", 75),
+    ("Goth", "Gothic", 27),
+    ("Gran", "Grantha", 85),
+    ("Grek", "Greek", 518),
+    ("Gujr", "Gujarati", 91),
+    ("Guru", "Gurmukhi", 80),
+    ("Hang", "Hangul", 11739),
+    ("Hani", "Han", 94215),
+    ("Hano", "Hanunoo", 21),
+    ("Hatr", "Hatran", 26),
+    ("Hebr", "Hebrew", 134),
+    ("Hira", "Hiragana", 380),
+    ("Hluw", "Anatolian Hieroglyphs", 583),
+    ("Hmng", "Pahawh Hmong", 127),
+    ("Hmnp", "Nyiakeng Puachue Hmong", 71),
+    ("Hung", "Old Hungarian", 108),
+    ("Ital", "Old Italic", 39),
+    ("Java", "Javanese", 90),
+    ("Kali", "Kayah Li", 47),
+    ("Kana", "Katakana", 320),
+    ("Khar", "Kharoshthi", 68),
+    ("Khmr", "Khmer", 146),
+    ("Khoj", "Khojki", 62),
+    ("Kits", "Khitan Small Script", 471),
+    ("Knda", "Kannada", 90),
+    ("Kthi", "Kaithi", 68),
+    ("Lana", "Tai Tham", 127),
+    ("Laoo", "Lao", 82),
+    ("Latn", "Latin", 1475),
+    ("Lepc", "Lepcha", 74),
+    ("Limb", "Limbu", 68),
+    ("Lina", "Linear A", 341),
+    ("Linb", "Linear B", 211),
+    ("Lisu", "Lisu", 49),
+    ("Lyci", "Lycian", 29),
+    ("Lydi", "Lydian", 27),
+    ("Mahj", "Mahajani", 39),
+    ("Maka", "Makasar", 25),
+    ("Mand", "Mandaic", 29),
+    ("Mani", "Manichaean", 51),
+    ("Marc", "Marchen", 68),
+    ("Medf", "Medefaidrin", 91),
+    ("Mend", "Mende Kikakui", 213),
+    ("Merc", "Meroitic Cursive", 90),
+    ("Mero", "Meroitic Hieroglyphs", 32),
+    ("Mlym", "Malayalam", 118),
+    ("Modi", "Modi", 79),
+    ("Mong", "Mongolian", 168),
+    ("Mroo", "Mro", 43),
+    ("Mtei", "Meetei Mayek", 79),
+    ("Mult", "Multani", 38),
+    ("Mymr", "Myanmar", 223),
+    ("Nand", "Nandinagari", 65),
+    ("Narb", "Old North Arabian", 32),
+    ("Nbat", "Nabataean", 40),
+    ("Newa", "Newa", 97),
+    ("Nkoo", "Nko", 62),
+    ("Nshu", "Nushu", 397),
+    ("Ogam", "Ogham", 29),
+    ("Olck", "Ol Chiki", 48),
+    ("Orkh", "Old Turkic", 73),
+    ("Orya", "Oriya", 91),
+    ("Osge", "Osage", 72),
+    ("Osma", "Osmanya", 40),
+    ("Ougr", "Old Uyghur", 26),
+    ("Palm", "Palmyrene", 32),
+    ("Pauc", "Pau Cin Hau", 57),
+    ("Perm", "Old Permic", 43),
+    ("Phag", "Phags Pa", 56),
+    ("Phli", "Inscriptional Pahlavi", 27),
+    ("Phlp", "Psalter Pahlavi", 29),
+    ("Phnx", "Phoenician", 29),
+    ("Plrd", "Miao", 149),
+    ("Prti", "Inscriptional Parthian", 30),
+    ("Rjng", "Rejang", 37),
+    ("Rohg", "Hanifi Rohingya", 50),
+    ("Runr", "Runic", 86),
+    ("Samr", "Samaritan", 61),
+    ("Sarb", "Old South Arabian", 32),
+    ("Saur", "Saurashtra", 82),
+    ("Sgnw", "SignWriting", 672),
+    ("Shaw", "Shavian", 48),
+    ("Shrd", "Sharada", 96),
+    ("Sidd", "Siddham", 92),
+    ("Sind", "Khudawadi", 69),
+    ("Sinh", "Sinhala", 111),
+    ("Sogd", "Sogdian", 42),
+    ("Sogo", "Old Sogdian", 40),
+    ("Sora", "Sora Sompeng", 35),
+    ("Soyo", "Soyombo", 83),
+    ("Sund", "Sundanese", 72),
+    ("Sylo", "Syloti Nagri", 45),
+    ("Syrc", "Syriac", 88),
+    ("Tagb", "Tagbanwa", 18),
+    ("Takr", "Takri", 68),
+    ("Tale", "Tai Le", 35),
+    ("Talu", "New Tai Lue", 83),
+    ("Taml", "Tamil", 123),
+    ("Tang", "Tangut", 6914),
+    ("Tavt", "Tai Viet", 72),
+    ("Telu", "Telugu", 100),
+    ("Tfng", "Tifinagh", 59),
+    ("Tglg", "Tagalog", 23),
+    ("Thaa", "Thaana", 50),
+    ("Thai", "Thai", 86),
+    ("Tibt", "Tibetan", 207),
+    ("Tirh", "Tirhuta", 82),
+    ("Tnsa", "Tangsa", 89),
+    ("Toto", "Toto", 31),
+    ("Ugar", "Ugaritic", 31),
+    ("Vaii", "Vai", 300),
+    ("Vith", "Vithkuqi", 70),
+    ("Wara", "Warang Citi", 84),
+    ("Wcho", "Wancho", 59),
+    ("Xpeo", "Old Persian", 50),
+    ("Xsux", "Cuneiform", 1234),
+    ("Yezi", "Yezidi", 47),
+    ("Yiii", "Yi", 1220),
+    ("Zanb", "Zanabazar Square", 72),
+    ("Zinh", "Inherited", 657),
+    ("Zyyy", "Common", 8187),
+];
+
+#[rustfmt::skip]
+static SCRIPT_RANGES: &[(u32, u32, u8)] = &[
+    (0x0020, 0x0040, 160),
+    (0x0041, 0x005A, 68),
+    (0x005B, 0x0060, 160),
+    (0x0061, 0x007A, 68),
+    (0x007B, 0x007E, 160),
+    (0x00A0, 0x00A9, 160),
+    (0x00AA, 0x00AA, 68),
+    (0x00AB, 0x00B9, 160),
+    (0x00BA, 0x00BA, 68),
+    (0x00BB, 0x00BF, 160),
+    (0x00C0, 0x00D6, 68),
+    (0x00D7, 0x00D7, 160),
+    (0x00D8, 0x00F6, 68),
+    (0x00F7, 0x00F7, 160),
+    (0x00F8, 0x02B8, 68),
+    (0x02B9, 0x02DF, 160),
+    (0x02E0, 0x02E4, 68),
+    (0x02E5, 0x02E9, 160),
+    (0x02EA, 0x02EB, 13),
+    (0x02EC, 0x02FF, 160),
+    (0x0300, 0x036F, 159),
+    (0x0370, 0x0373, 43),
+    (0x0374, 0x0374, 160),
+    (0x0375, 0x0377, 43),
+    (0x037A, 0x037D, 43),
+    (0x037E, 0x037E, 160),
+    (0x037F, 0x037F, 43),
+    (0x0384, 0x0384, 43),
+    (0x0385, 0x0385, 160),
+    (0x0386, 0x0386, 43),
+    (0x0387, 0x0387, 160),
+    (0x0388, 0x038A, 43),
+    (0x038C, 0x038C, 43),
+    (0x038E, 0x03A1, 43),
+    (0x03A3, 0x03E1, 43),
+    (0x03E2, 0x03EF, 24),
+    (0x03F0, 0x03FF, 43),
+    (0x0400, 0x0484, 27),
+    (0x0485, 0x0486, 159),
+    (0x0487, 0x052F, 27),
+    (0x0531, 0x0556, 5),
+    (0x0559, 0x058A, 5),
+    (0x058D, 0x058F, 5),
+    (0x0591, 0x05C7, 50),
+    (0x05D0, 0x05EA, 50),
+    (0x05EF, 0x05F4, 50),
+    (0x0600, 0x0604, 3),
+    (0x0605, 0x0605, 160),
+    (0x0606, 0x060B, 3),
+    (0x060C, 0x060C, 160),
+    (0x060D, 0x061A, 3),
+    (0x061B, 0x061B, 160),
+    (0x061C, 0x061E, 3),
+    (0x061F, 0x061F, 160),
+    (0x0620, 0x063F, 3),
+    (0x0640, 0x0640, 160),
+    (0x0641, 0x064A, 3),
+    (0x064B, 0x0655, 159),
+    (0x0656, 0x066F, 3),
+    (0x0670, 0x0670, 159),
+    (0x0671, 0x06DC, 3),
+    (0x06DD, 0x06DD, 160),
+    (0x06DE, 0x06FF, 3),
+    (0x0700, 0x070D, 132),
+    (0x070F, 0x074A, 132),
+    (0x074D, 0x074F, 132),
+    (0x0750, 0x077F, 3),
+    (0x0780, 0x07B1, 143),
+    (0x07C0, 0x07FA, 96),
+    (0x07FD, 0x07FF, 96),
+    (0x0800, 0x082D, 117),
+    (0x0830, 0x083E, 117),
+    (0x0840, 0x085B, 78),
+    (0x085E, 0x085E, 78),
+    (0x0860, 0x086A, 132),
+    (0x0870, 0x088E, 3),
+    (0x0890, 0x0891, 3),
+    (0x0898, 0x08E1, 3),
+    (0x08E2, 0x08E2, 160),
+    (0x08E3, 0x08FF, 3),
+    (0x0900, 0x0950, 28),
+    (0x0951, 0x0954, 159),
+    (0x0955, 0x0963, 28),
+    (0x0964, 0x0965, 160),
+    (0x0966, 0x097F, 28),
+    (0x0980, 0x0983, 11),
+    (0x0985, 0x098C, 11),
+    (0x098F, 0x0990, 11),
+    (0x0993, 0x09A8, 11),
+    (0x09AA, 0x09B0, 11),
+    (0x09B2, 0x09B2, 11),
+    (0x09B6, 0x09B9, 11),
+    (0x09BC, 0x09C4, 11),
+    (0x09C7, 0x09C8, 11),
+    (0x09CB, 0x09CE, 11),
+    (0x09D7, 0x09D7, 11),
+    (0x09DC, 0x09DD, 11),
+    (0x09DF, 0x09E3, 11),
+    (0x09E6, 0x09FE, 11),
+    (0x0A01, 0x0A03, 45),
+    (0x0A05, 0x0A0A, 45),
+    (0x0A0F, 0x0A10, 45),
+    (0x0A13, 0x0A28, 45),
+    (0x0A2A, 0x0A30, 45),
+    (0x0A32, 0x0A33, 45),
+    (0x0A35, 0x0A36, 45),
+    (0x0A38, 0x0A39, 45),
+    (0x0A3C, 0x0A3C, 45),
+    (0x0A3E, 0x0A42, 45),
+    (0x0A47, 0x0A48, 45),
+    (0x0A4B, 0x0A4D, 45),
+    (0x0A51, 0x0A51, 45),
+    (0x0A59, 0x0A5C, 45),
+    (0x0A5E, 0x0A5E, 45),
+    (0x0A66, 0x0A76, 45),
+    (0x0A81, 0x0A83, 44),
+    (0x0A85, 0x0A8D, 44),
+    (0x0A8F, 0x0A91, 44),
+    (0x0A93, 0x0AA8, 44),
+    (0x0AAA, 0x0AB0, 44),
+    (0x0AB2, 0x0AB3, 44),
+    (0x0AB5, 0x0AB9, 44),
+    (0x0ABC, 0x0AC5, 44),
+    (0x0AC7, 0x0AC9, 44),
+    (0x0ACB, 0x0ACD, 44),
+    (0x0AD0, 0x0AD0, 44),
+    (0x0AE0, 0x0AE3, 44),
+    (0x0AE6, 0x0AF1, 44),
+    (0x0AF9, 0x0AFF, 44),
+    (0x0B01, 0x0B03, 101),
+    (0x0B05, 0x0B0C, 101),
+    (0x0B0F, 0x0B10, 101),
+    (0x0B13, 0x0B28, 101),
+    (0x0B2A, 0x0B30, 101),
+    (0x0B32, 0x0B33, 101),
+    (0x0B35, 0x0B39, 101),
+    (0x0B3C, 0x0B44, 101),
+    (0x0B47, 0x0B48, 101),
+    (0x0B4B, 0x0B4D, 101),
+    (0x0B55, 0x0B57, 101),
+    (0x0B5C, 0x0B5D, 101),
+    (0x0B5F, 0x0B63, 101),
+    (0x0B66, 0x0B77, 101),
+    (0x0B82, 0x0B83, 137),
+    (0x0B85, 0x0B8A, 137),
+    (0x0B8E, 0x0B90, 137),
+    (0x0B92, 0x0B95, 137),
+    (0x0B99, 0x0B9A, 137),
+    (0x0B9C, 0x0B9C, 137),
+    (0x0B9E, 0x0B9F, 137),
+    (0x0BA3, 0x0BA4, 137),
+    (0x0BA8, 0x0BAA, 137),
+    (0x0BAE, 0x0BB9, 137),
+    (0x0BBE, 0x0BC2, 137),
+    (0x0BC6, 0x0BC8, 137),
+    (0x0BCA, 0x0BCD, 137),
+    (0x0BD0, 0x0BD0, 137),
+    (0x0BD7, 0x0BD7, 137),
+    (0x0BE6, 0x0BFA, 137),
+    (0x0C00, 0x0C0C, 140),
+    (0x0C0E, 0x0C10, 140),
+    (0x0C12, 0x0C28, 140),
+    (0x0C2A, 0x0C39, 140),
+    (0x0C3C, 0x0C44, 140),
+    (0x0C46, 0x0C48, 140),
+    (0x0C4A, 0x0C4D, 140),
+    (0x0C55, 0x0C56, 140),
+    (0x0C58, 0x0C5A, 140),
+    (0x0C5D, 0x0C5D, 140),
+    (0x0C60, 0x0C63, 140),
+    (0x0C66, 0x0C6F, 140),
+    (0x0C77, 0x0C7F, 140),
+    (0x0C80, 0x0C8C, 64),
+    (0x0C8E, 0x0C90, 64),
+    (0x0C92, 0x0CA8, 64),
+    (0x0CAA, 0x0CB3, 64),
+    (0x0CB5, 0x0CB9, 64),
+    (0x0CBC, 0x0CC4, 64),
+    (0x0CC6, 0x0CC8, 64),
+    (0x0CCA, 0x0CCD, 64),
+    (0x0CD5, 0x0CD6, 64),
+    (0x0CDD, 0x0CDE, 64),
+    (0x0CE0, 0x0CE3, 64),
+    (0x0CE6, 0x0CEF, 64),
+    (0x0CF1, 0x0CF2, 64),
+    (0x0D00, 0x0D0C, 85),
+    (0x0D0E, 0x0D10, 85),
+    (0x0D12, 0x0D44, 85),
+    (0x0D46, 0x0D48, 85),
+    (0x0D4A, 0x0D4F, 85),
+    (0x0D54, 0x0D63, 85),
+    (0x0D66, 0x0D7F, 85),
+    (0x0D81, 0x0D83, 125),
+    (0x0D85, 0x0D96, 125),
+    (0x0D9A, 0x0DB1, 125),
+    (0x0DB3, 0x0DBB, 125),
+    (0x0DBD, 0x0DBD, 125),
+    (0x0DC0, 0x0DC6, 125),
+    (0x0DCA, 0x0DCA, 125),
+    (0x0DCF, 0x0DD4, 125),
+    (0x0DD6, 0x0DD6, 125),
+    (0x0DD8, 0x0DDF, 125),
+    (0x0DE6, 0x0DEF, 125),
+    (0x0DF2, 0x0DF4, 125),
+    (0x0E01, 0x0E3A, 144),
+    (0x0E3F, 0x0E3F, 160),
+    (0x0E40, 0x0E5B, 144),
+    (0x0E81, 0x0E82, 67),
+    (0x0E84, 0x0E84, 67),
+    (0x0E86, 0x0E8A, 67),
+    (0x0E8C, 0x0EA3, 67),
+    (0x0EA5, 0x0EA5, 67),
+    (0x0EA7, 0x0EBD, 67),
+    (0x0EC0, 0x0EC4, 67),
+    (0x0EC6, 0x0EC6, 67),
+    (0x0EC8, 0x0ECD, 67),
+    (0x0ED0, 0x0ED9, 67),
+    (0x0EDC, 0x0EDF, 67),
+    (0x0F00, 0x0F47, 145),
+    (0x0F49, 0x0F6C, 145),
+    (0x0F71, 0x0F97, 145),
+    (0x0F99, 0x0FBC, 145),
+    (0x0FBE, 0x0FCC, 145),
+    (0x0FCE, 0x0FD4, 145),
+    (0x0FD5, 0x0FD8, 160),
+    (0x0FD9, 0x0FDA, 145),
+    (0x1000, 0x109F, 91),
+    (0x10A0, 0x10C5, 37),
+    (0x10C7, 0x10C7, 37),
+    (0x10CD, 0x10CD, 37),
+    (0x10D0, 0x10FA, 37),
+    (0x10FB, 0x10FB, 160),
+    (0x10FC, 0x10FF, 37),
+    (0x1100, 0x11FF, 46),
+    (0x1200, 0x1248, 36),
+    (0x124A, 0x124D, 36),
+    (0x1250, 0x1256, 36),
+    (0x1258, 0x1258, 36),
+    (0x125A, 0x125D, 36),
+    (0x1260, 0x1288, 36),
+    (0x128A, 0x128D, 36),
+    (0x1290, 0x12B0, 36),
+    (0x12B2, 0x12B5, 36),
+    (0x12B8, 0x12BE, 36),
+    (0x12C0, 0x12C0, 36),
+    (0x12C2, 0x12C5, 36),
+    (0x12C8, 0x12D6, 36),
+    (0x12D8, 0x1310, 36),
+    (0x1312, 0x1315, 36),
+    (0x1318, 0x135A, 36),
+    (0x135D, 0x137C, 36),
+    (0x1380, 0x1399, 36),
+    (0x13A0, 0x13F5, 22),
+    (0x13F8, 0x13FD, 22),
+    (0x1400, 0x167F, 19),
+    (0x1680, 0x169C, 98),
+    (0x16A0, 0x16EA, 116),
+    (0x16EB, 0x16ED, 160),
+    (0x16EE, 0x16F8, 116),
+    (0x1700, 0x1715, 142),
+    (0x171F, 0x171F, 142),
+    (0x1720, 0x1734, 48),
+    (0x1735, 0x1736, 160),
+    (0x1740, 0x1753, 17),
+    (0x1760, 0x176C, 133),
+    (0x176E, 0x1770, 133),
+    (0x1772, 0x1773, 133),
+    (0x1780, 0x17DD, 61),
+    (0x17E0, 0x17E9, 61),
+    (0x17F0, 0x17F9, 61),
+    (0x1800, 0x1801, 87),
+    (0x1802, 0x1803, 160),
+    (0x1804, 0x1804, 87),
+    (0x1805, 0x1805, 160),
+    (0x1806, 0x1819, 87),
+    (0x1820, 0x1878, 87),
+    (0x1880, 0x18AA, 87),
+    (0x18B0, 0x18F5, 19),
+    (0x1900, 0x191E, 70),
+    (0x1920, 0x192B, 70),
+    (0x1930, 0x193B, 70),
+    (0x1940, 0x1940, 70),
+    (0x1944, 0x194F, 70),
+    (0x1950, 0x196D, 135),
+    (0x1970, 0x1974, 135),
+    (0x1980, 0x19AB, 136),
+    (0x19B0, 0x19C9, 136),
+    (0x19D0, 0x19DA, 136),
+    (0x19DE, 0x19DF, 136),
+    (0x19E0, 0x19FF, 61),
+    (0x1A00, 0x1A1B, 16),
+    (0x1A1E, 0x1A1F, 16),
+    (0x1A20, 0x1A5E, 66),
+    (0x1A60, 0x1A7C, 66),
+    (0x1A7F, 0x1A89, 66),
+    (0x1A90, 0x1A99, 66),
+    (0x1AA0, 0x1AAD, 66),
+    (0x1AB0, 0x1ACE, 159),
+    (0x1B00, 0x1B4C, 7),
+    (0x1B50, 0x1B7E, 7),
+    (0x1B80, 0x1BBF, 130),
+    (0x1BC0, 0x1BF3, 10),
+    (0x1BFC, 0x1BFF, 10),
+    (0x1C00, 0x1C37, 69),
+    (0x1C3B, 0x1C49, 69),
+    (0x1C4D, 0x1C4F, 69),
+    (0x1C50, 0x1C7F, 99),
+    (0x1C80, 0x1C88, 27),
+    (0x1C90, 0x1CBA, 37),
+    (0x1CBD, 0x1CBF, 37),
+    (0x1CC0, 0x1CC7, 130),
+    (0x1CD0, 0x1CD2, 159),
+    (0x1CD3, 0x1CD3, 160),
+    (0x1CD4, 0x1CE0, 159),
+    (0x1CE1, 0x1CE1, 160),
+    (0x1CE2, 0x1CE8, 159),
+    (0x1CE9, 0x1CEC, 160),
+    (0x1CED, 0x1CED, 159),
+    (0x1CEE, 0x1CF3, 160),
+    (0x1CF4, 0x1CF4, 159),
+    (0x1CF5, 0x1CF7, 160),
+    (0x1CF8, 0x1CF9, 159),
+    (0x1CFA, 0x1CFA, 160),
+    (0x1D00, 0x1D25, 68),
+    (0x1D26, 0x1D2A, 43),
+    (0x1D2B, 0x1D2B, 27),
+    (0x1D2C, 0x1D5C, 68),
+    (0x1D5D, 0x1D61, 43),
+    (0x1D62, 0x1D65, 68),
+    (0x1D66, 0x1D6A, 43),
+    (0x1D6B, 0x1D77, 68),
+    (0x1D78, 0x1D78, 27),
+    (0x1D79, 0x1DBE, 68),
+    (0x1DBF, 0x1DBF, 43),
+    (0x1DC0, 0x1DFF, 159),
+    (0x1E00, 0x1EFF, 68),
+    (0x1F00, 0x1F15, 43),
+    (0x1F18, 0x1F1D, 43),
+    (0x1F20, 0x1F45, 43),
+    (0x1F48, 0x1F4D, 43),
+    (0x1F50, 0x1F57, 43),
+    (0x1F59, 0x1F59, 43),
+    (0x1F5B, 0x1F5B, 43),
+    (0x1F5D, 0x1F5D, 43),
+    (0x1F5F, 0x1F7D, 43),
+    (0x1F80, 0x1FB4, 43),
+    (0x1FB6, 0x1FC4, 43),
+    (0x1FC6, 0x1FD3, 43),
+    (0x1FD6, 0x1FDB, 43),
+    (0x1FDD, 0x1FEF, 43),
+    (0x1FF2, 0x1FF4, 43),
+    (0x1FF6, 0x1FFE, 43),
+    (0x2000, 0x200B, 160),
+    (0x200C, 0x200D, 159),
+    (0x200E, 0x2064, 160),
+    (0x2066, 0x2070, 160),
+    (0x2071, 0x2071, 68),
+    (0x2074, 0x207E, 160),
+    (0x207F, 0x207F, 68),
+    (0x2080, 0x208E, 160),
+    (0x2090, 0x209C, 68),
+    (0x20A0, 0x20C0, 160),
+    (0x20D0, 0x20F0, 159),
+    (0x2100, 0x2125, 160),
+    (0x2126, 0x2126, 43),
+    (0x2127, 0x2129, 160),
+    (0x212A, 0x212B, 68),
+    (0x212C, 0x2131, 160),
+    (0x2132, 0x2132, 68),
+    (0x2133, 0x214D, 160),
+    (0x214E, 0x214E, 68),
+    (0x214F, 0x215F, 160),
+    (0x2160, 0x2188, 68),
+    (0x2189, 0x218B, 160),
+    (0x2190, 0x2426, 160),
+    (0x2440, 0x244A, 160),
+    (0x2460, 0x27FF, 160),
+    (0x2800, 0x28FF, 15),
+    (0x2900, 0x2B73, 160),
+    (0x2B76, 0x2B95, 160),
+    (0x2B97, 0x2BFF, 160),
+    (0x2C00, 0x2C5F, 38),
+    (0x2C60, 0x2C7F, 68),
+    (0x2C80, 0x2CF3, 24),
+    (0x2CF9, 0x2CFF, 24),
+    (0x2D00, 0x2D25, 37),
+    (0x2D27, 0x2D27, 37),
+    (0x2D2D, 0x2D2D, 37),
+    (0x2D30, 0x2D67, 141),
+    (0x2D6F, 0x2D70, 141),
+    (0x2D7F, 0x2D7F, 141),
+    (0x2D80, 0x2D96, 36),
+    (0x2DA0, 0x2DA6, 36),
+    (0x2DA8, 0x2DAE, 36),
+    (0x2DB0, 0x2DB6, 36),
+    (0x2DB8, 0x2DBE, 36),
+    (0x2DC0, 0x2DC6, 36),
+    (0x2DC8, 0x2DCE, 36),
+    (0x2DD0, 0x2DD6, 36),
+    (0x2DD8, 0x2DDE, 36),
+    (0x2DE0, 0x2DFF, 27),
+    (0x2E00, 0x2E5D, 160),
+    (0x2E80, 0x2E99, 47),
+    (0x2E9B, 0x2EF3, 47),
+    (0x2F00, 0x2FD5, 47),
+    (0x2FF0, 0x2FFB, 160),
+    (0x3000, 0x3004, 160),
+    (0x3005, 0x3005, 47),
+    (0x3006, 0x3006, 160),
+    (0x3007, 0x3007, 47),
+    (0x3008, 0x3020, 160),
+    (0x3021, 0x3029, 47),
+    (0x302A, 0x302D, 159),
+    (0x302E, 0x302F, 46),
+    (0x3030, 0x3037, 160),
+    (0x3038, 0x303B, 47),
+    (0x303C, 0x303F, 160),
+    (0x3041, 0x3096, 51),
+    (0x3099, 0x309A, 159),
+    (0x309B, 0x309C, 160),
+    (0x309D, 0x309F, 51),
+    (0x30A0, 0x30A0, 160),
+    (0x30A1, 0x30FA, 59),
+    (0x30FB, 0x30FC, 160),
+    (0x30FD, 0x30FF, 59),
+    (0x3105, 0x312F, 13),
+    (0x3131, 0x318E, 46),
+    (0x3190, 0x319F, 160),
+    (0x31A0, 0x31BF, 13),
+    (0x31C0, 0x31E3, 160),
+    (0x31F0, 0x31FF, 59),
+    (0x3200, 0x321E, 46),
+    (0x3220, 0x325F, 160),
+    (0x3260, 0x327E, 46),
+    (0x327F, 0x32CF, 160),
+    (0x32D0, 0x32FE, 59),
+    (0x32FF, 0x32FF, 160),
+    (0x3300, 0x3357, 59),
+    (0x3358, 0x33FF, 160),
+    (0x3400, 0x4DBF, 47),
+    (0x4DC0, 0x4DFF, 160),
+    (0x4E00, 0x9FFF, 47),
+    (0xA000, 0xA48C, 157),
+    (0xA490, 0xA4C6, 157),
+    (0xA4D0, 0xA4FF, 73),
+    (0xA500, 0xA62B, 150),
+    (0xA640, 0xA69F, 27),
+    (0xA6A0, 0xA6F7, 8),
+    (0xA700, 0xA721, 160),
+    (0xA722, 0xA787, 68),
+    (0xA788, 0xA78A, 160),
+    (0xA78B, 0xA7CA, 68),
+    (0xA7D0, 0xA7D1, 68),
+    (0xA7D3, 0xA7D3, 68),
+    (0xA7D5, 0xA7D9, 68),
+    (0xA7F2, 0xA7FF, 68),
+    (0xA800, 0xA82C, 131),
+    (0xA830, 0xA839, 160),
+    (0xA840, 0xA877, 108),
+    (0xA880, 0xA8C5, 119),
+    (0xA8CE, 0xA8D9, 119),
+    (0xA8E0, 0xA8FF, 28),
+    (0xA900, 0xA92D, 58),
+    (0xA92E, 0xA92E, 160),
+    (0xA92F, 0xA92F, 58),
+    (0xA930, 0xA953, 114),
+    (0xA95F, 0xA95F, 114),
+    (0xA960, 0xA97C, 46),
+    (0xA980, 0xA9CD, 57),
+    (0xA9CF, 0xA9CF, 160),
+    (0xA9D0, 0xA9D9, 57),
+    (0xA9DE, 0xA9DF, 57),
+    (0xA9E0, 0xA9FE, 91),
+    (0xAA00, 0xAA36, 21),
+    (0xAA40, 0xAA4D, 21),
+    (0xAA50, 0xAA59, 21),
+    (0xAA5C, 0xAA5F, 21),
+    (0xAA60, 0xAA7F, 91),
+    (0xAA80, 0xAAC2, 139),
+    (0xAADB, 0xAADF, 139),
+    (0xAAE0, 0xAAF6, 89),
+    (0xAB01, 0xAB06, 36),
+    (0xAB09, 0xAB0E, 36),
+    (0xAB11, 0xAB16, 36),
+    (0xAB20, 0xAB26, 36),
+    (0xAB28, 0xAB2E, 36),
+    (0xAB30, 0xAB5A, 68),
+    (0xAB5B, 0xAB5B, 160),
+    (0xAB5C, 0xAB64, 68),
+    (0xAB65, 0xAB65, 43),
+    (0xAB66, 0xAB69, 68),
+    (0xAB6A, 0xAB6B, 160),
+    (0xAB70, 0xABBF, 22),
+    (0xABC0, 0xABED, 89),
+    (0xABF0, 0xABF9, 89),
+    (0xAC00, 0xD7A3, 46),
+    (0xD7B0, 0xD7C6, 46),
+    (0xD7CB, 0xD7FB, 46),
+    (0xF900, 0xFA6D, 47),
+    (0xFA70, 0xFAD9, 47),
+    (0xFB00, 0xFB06, 68),
+    (0xFB13, 0xFB17, 5),
+    (0xFB1D, 0xFB36, 50),
+    (0xFB38, 0xFB3C, 50),
+    (0xFB3E, 0xFB3E, 50),
+    (0xFB40, 0xFB41, 50),
+    (0xFB43, 0xFB44, 50),
+    (0xFB46, 0xFB4F, 50),
+    (0xFB50, 0xFBC2, 3),
+    (0xFBD3, 0xFD3D, 3),
+    (0xFD3E, 0xFD3F, 160),
+    (0xFD40, 0xFD8F, 3),
+    (0xFD92, 0xFDC7, 3),
+    (0xFDCF, 0xFDCF, 3),
+    (0xFDF0, 0xFDFF, 3),
+    (0xFE00, 0xFE0F, 159),
+    (0xFE10, 0xFE19, 160),
+    (0xFE20, 0xFE2D, 159),
+    (0xFE2E, 0xFE2F, 27),
+    (0xFE30, 0xFE52, 160),
+    (0xFE54, 0xFE66, 160),
+    (0xFE68, 0xFE6B, 160),
+    (0xFE70, 0xFE74, 3),
+    (0xFE76, 0xFEFC, 3),
+    (0xFEFF, 0xFEFF, 160),
+    (0xFF01, 0xFF20, 160),
+    (0xFF21, 0xFF3A, 68),
+    (0xFF3B, 0xFF40, 160),
+    (0xFF41, 0xFF5A, 68),
+    (0xFF5B, 0xFF65, 160),
+    (0xFF66, 0xFF6F, 59),
+    (0xFF70, 0xFF70, 160),
+    (0xFF71, 0xFF9D, 59),
+    (0xFF9E, 0xFF9F, 160),
+    (0xFFA0, 0xFFBE, 46),
+    (0xFFC2, 0xFFC7, 46),
+    (0xFFCA, 0xFFCF, 46),
+    (0xFFD2, 0xFFD7, 46),
+    (0xFFDA, 0xFFDC, 46),
+    (0xFFE0, 0xFFE6, 160),
+    (0xFFE8, 0xFFEE, 160),
+    (0xFFF9, 0xFFFD, 160),
+    (0x10000, 0x1000B, 72),
+    (0x1000D, 0x10026, 72),
+    (0x10028, 0x1003A, 72),
+    (0x1003C, 0x1003D, 72),
+    (0x1003F, 0x1004D, 72),
+    (0x10050, 0x1005D, 72),
+    (0x10080, 0x100FA, 72),
+    (0x10100, 0x10102, 160),
+    (0x10107, 0x10133, 160),
+    (0x10137, 0x1013F, 160),
+    (0x10140, 0x1018E, 43),
+    (0x10190, 0x1019C, 160),
+    (0x101A0, 0x101A0, 43),
+    (0x101D0, 0x101FC, 160),
+    (0x101FD, 0x101FD, 159),
+    (0x10280, 0x1029C, 74),
+    (0x102A0, 0x102D0, 20),
+    (0x102E0, 0x102E0, 159),
+    (0x102E1, 0x102FB, 160),
+    (0x10300, 0x10323, 56),
+    (0x1032D, 0x1032F, 56),
+    (0x10330, 0x1034A, 41),
+    (0x10350, 0x1037A, 107),
+    (0x10380, 0x1039D, 149),
+    (0x1039F, 0x1039F, 149),
+    (0x103A0, 0x103C3, 154),
+    (0x103C8, 0x103D5, 154),
+    (0x10400, 0x1044F, 31),
+    (0x10450, 0x1047F, 121),
+    (0x10480, 0x1049D, 103),
+    (0x104A0, 0x104A9, 103),
+    (0x104B0, 0x104D3, 102),
+    (0x104D8, 0x104FB, 102),
+    (0x10500, 0x10527, 34),
+    (0x10530, 0x10563, 1),
+    (0x1056F, 0x1056F, 1),
+    (0x10570, 0x1057A, 151),
+    (0x1057C, 0x1058A, 151),
+    (0x1058C, 0x10592, 151),
+    (0x10594, 0x10595, 151),
+    (0x10597, 0x105A1, 151),
+    (0x105A3, 0x105B1, 151),
+    (0x105B3, 0x105B9, 151),
+    (0x105BB, 0x105BC, 151),
+    (0x10600, 0x10736, 71),
+    (0x10740, 0x10755, 71),
+    (0x10760, 0x10767, 71),
+    (0x10780, 0x10785, 68),
+    (0x10787, 0x107B0, 68),
+    (0x107B2, 0x107BA, 68),
+    (0x10800, 0x10805, 26),
+    (0x10808, 0x10808, 26),
+    (0x1080A, 0x10835, 26),
+    (0x10837, 0x10838, 26),
+    (0x1083C, 0x1083C, 26),
+    (0x1083F, 0x1083F, 26),
+    (0x10840, 0x10855, 4),
+    (0x10857, 0x1085F, 4),
+    (0x10860, 0x1087F, 105),
+    (0x10880, 0x1089E, 94),
+    (0x108A7, 0x108AF, 94),
+    (0x108E0, 0x108F2, 49),
+    (0x108F4, 0x108F5, 49),
+    (0x108FB, 0x108FF, 49),
+    (0x10900, 0x1091B, 111),
+    (0x1091F, 0x1091F, 111),
+    (0x10920, 0x10939, 75),
+    (0x1093F, 0x1093F, 75),
+    (0x10980, 0x1099F, 84),
+    (0x109A0, 0x109B7, 83),
+    (0x109BC, 0x109CF, 83),
+    (0x109D2, 0x109FF, 83),
+    (0x10A00, 0x10A03, 60),
+    (0x10A05, 0x10A06, 60),
+    (0x10A0C, 0x10A13, 60),
+    (0x10A15, 0x10A17, 60),
+    (0x10A19, 0x10A35, 60),
+    (0x10A38, 0x10A3A, 60),
+    (0x10A3F, 0x10A48, 60),
+    (0x10A50, 0x10A58, 60),
+    (0x10A60, 0x10A7F, 118),
+    (0x10A80, 0x10A9F, 93),
+    (0x10AC0, 0x10AE6, 79),
+    (0x10AEB, 0x10AF6, 79),
+    (0x10B00, 0x10B35, 6),
+    (0x10B39, 0x10B3F, 6),
+    (0x10B40, 0x10B55, 113),
+    (0x10B58, 0x10B5F, 113),
+    (0x10B60, 0x10B72, 109),
+    (0x10B78, 0x10B7F, 109),
+    (0x10B80, 0x10B91, 110),
+    (0x10B99, 0x10B9C, 110),
+    (0x10BA9, 0x10BAF, 110),
+    (0x10C00, 0x10C48, 100),
+    (0x10C80, 0x10CB2, 55),
+    (0x10CC0, 0x10CF2, 55),
+    (0x10CFA, 0x10CFF, 55),
+    (0x10D00, 0x10D27, 115),
+    (0x10D30, 0x10D39, 115),
+    (0x10E60, 0x10E7E, 3),
+    (0x10E80, 0x10EA9, 156),
+    (0x10EAB, 0x10EAD, 156),
+    (0x10EB0, 0x10EB1, 156),
+    (0x10F00, 0x10F27, 127),
+    (0x10F30, 0x10F59, 126),
+    (0x10F70, 0x10F89, 104),
+    (0x10FB0, 0x10FCB, 23),
+    (0x10FE0, 0x10FF6, 35),
+    (0x11000, 0x1104D, 14),
+    (0x11052, 0x11075, 14),
+    (0x1107F, 0x1107F, 14),
+    (0x11080, 0x110C2, 65),
+    (0x110CD, 0x110CD, 65),
+    (0x110D0, 0x110E8, 128),
+    (0x110F0, 0x110F9, 128),
+    (0x11100, 0x11134, 18),
+    (0x11136, 0x11147, 18),
+    (0x11150, 0x11176, 76),
+    (0x11180, 0x111DF, 122),
+    (0x111E1, 0x111F4, 125),
+    (0x11200, 0x11211, 62),
+    (0x11213, 0x1123E, 62),
+    (0x11280, 0x11286, 90),
+    (0x11288, 0x11288, 90),
+    (0x1128A, 0x1128D, 90),
+    (0x1128F, 0x1129D, 90),
+    (0x1129F, 0x112A9, 90),
+    (0x112B0, 0x112EA, 124),
+    (0x112F0, 0x112F9, 124),
+    (0x11300, 0x11303, 42),
+    (0x11305, 0x1130C, 42),
+    (0x1130F, 0x11310, 42),
+    (0x11313, 0x11328, 42),
+    (0x1132A, 0x11330, 42),
+    (0x11332, 0x11333, 42),
+    (0x11335, 0x11339, 42),
+    (0x1133B, 0x1133B, 159),
+    (0x1133C, 0x11344, 42),
+    (0x11347, 0x11348, 42),
+    (0x1134B, 0x1134D, 42),
+    (0x11350, 0x11350, 42),
+    (0x11357, 0x11357, 42),
+    (0x1135D, 0x11363, 42),
+    (0x11366, 0x1136C, 42),
+    (0x11370, 0x11374, 42),
+    (0x11400, 0x1145B, 95),
+    (0x1145D, 0x11461, 95),
+    (0x11480, 0x114C7, 146),
+    (0x114D0, 0x114D9, 146),
+    (0x11580, 0x115B5, 123),
+    (0x115B8, 0x115DD, 123),
+    (0x11600, 0x11644, 86),
+    (0x11650, 0x11659, 86),
+    (0x11660, 0x1166C, 87),
+    (0x11680, 0x116B9, 134),
+    (0x116C0, 0x116C9, 134),
+    (0x11700, 0x1171A, 2),
+    (0x1171D, 0x1172B, 2),
+    (0x11730, 0x11746, 2),
+    (0x11800, 0x1183B, 30),
+    (0x118A0, 0x118F2, 152),
+    (0x118FF, 0x118FF, 152),
+    (0x11900, 0x11906, 29),
+    (0x11909, 0x11909, 29),
+    (0x1190C, 0x11913, 29),
+    (0x11915, 0x11916, 29),
+    (0x11918, 0x11935, 29),
+    (0x11937, 0x11938, 29),
+    (0x1193B, 0x11946, 29),
+    (0x11950, 0x11959, 29),
+    (0x119A0, 0x119A7, 92),
+    (0x119AA, 0x119D7, 92),
+    (0x119DA, 0x119E4, 92),
+    (0x11A00, 0x11A47, 158),
+    (0x11A50, 0x11AA2, 129),
+    (0x11AB0, 0x11ABF, 19),
+    (0x11AC0, 0x11AF8, 106),
+    (0x11C00, 0x11C08, 12),
+    (0x11C0A, 0x11C36, 12),
+    (0x11C38, 0x11C45, 12),
+    (0x11C50, 0x11C6C, 12),
+    (0x11C70, 0x11C8F, 80),
+    (0x11C92, 0x11CA7, 80),
+    (0x11CA9, 0x11CB6, 80),
+    (0x11D00, 0x11D06, 40),
+    (0x11D08, 0x11D09, 40),
+    (0x11D0B, 0x11D36, 40),
+    (0x11D3A, 0x11D3A, 40),
+    (0x11D3C, 0x11D3D, 40),
+    (0x11D3F, 0x11D47, 40),
+    (0x11D50, 0x11D59, 40),
+    (0x11D60, 0x11D65, 39),
+    (0x11D67, 0x11D68, 39),
+    (0x11D6A, 0x11D8E, 39),
+    (0x11D90, 0x11D91, 39),
+    (0x11D93, 0x11D98, 39),
+    (0x11DA0, 0x11DA9, 39),
+    (0x11EE0, 0x11EF8, 77),
+    (0x11FB0, 0x11FB0, 73),
+    (0x11FC0, 0x11FF1, 137),
+    (0x11FFF, 0x11FFF, 137),
+    (0x12000, 0x12399, 155),
+    (0x12400, 0x1246E, 155),
+    (0x12470, 0x12474, 155),
+    (0x12480, 0x12543, 155),
+    (0x12F90, 0x12FF2, 25),
+    (0x13000, 0x1342E, 33),
+    (0x13430, 0x13438, 33),
+    (0x14400, 0x14646, 52),
+    (0x16800, 0x16A38, 8),
+    (0x16A40, 0x16A5E, 88),
+    (0x16A60, 0x16A69, 88),
+    (0x16A6E, 0x16A6F, 88),
+    (0x16A70, 0x16ABE, 147),
+    (0x16AC0, 0x16AC9, 147),
+    (0x16AD0, 0x16AED, 9),
+    (0x16AF0, 0x16AF5, 9),
+    (0x16B00, 0x16B45, 53),
+    (0x16B50, 0x16B59, 53),
+    (0x16B5B, 0x16B61, 53),
+    (0x16B63, 0x16B77, 53),
+    (0x16B7D, 0x16B8F, 53),
+    (0x16E40, 0x16E9A, 81),
+    (0x16F00, 0x16F4A, 112),
+    (0x16F4F, 0x16F87, 112),
+    (0x16F8F, 0x16F9F, 112),
+    (0x16FE0, 0x16FE0, 138),
+    (0x16FE1, 0x16FE1, 97),
+    (0x16FE2, 0x16FE3, 47),
+    (0x16FE4, 0x16FE4, 63),
+    (0x16FF0, 0x16FF1, 47),
+    (0x17000, 0x187F7, 138),
+    (0x18800, 0x18AFF, 138),
+    (0x18B00, 0x18CD5, 63),
+    (0x18D00, 0x18D08, 138),
+    (0x1AFF0, 0x1AFF3, 59),
+    (0x1AFF5, 0x1AFFB, 59),
+    (0x1AFFD, 0x1AFFE, 59),
+    (0x1B000, 0x1B000, 59),
+    (0x1B001, 0x1B11F, 51),
+    (0x1B120, 0x1B122, 59),
+    (0x1B150, 0x1B152, 51),
+    (0x1B164, 0x1B167, 59),
+    (0x1B170, 0x1B2FB, 97),
+    (0x1BC00, 0x1BC6A, 32),
+    (0x1BC70, 0x1BC7C, 32),
+    (0x1BC80, 0x1BC88, 32),
+    (0x1BC90, 0x1BC99, 32),
+    (0x1BC9C, 0x1BC9F, 32),
+    (0x1BCA0, 0x1BCA3, 160),
+    (0x1CF00, 0x1CF2D, 159),
+    (0x1CF30, 0x1CF46, 159),
+    (0x1CF50, 0x1CFC3, 160),
+    (0x1D000, 0x1D0F5, 160),
+    (0x1D100, 0x1D126, 160),
+    (0x1D129, 0x1D166, 160),
+    (0x1D167, 0x1D169, 159),
+    (0x1D16A, 0x1D17A, 160),
+    (0x1D17B, 0x1D182, 159),
+    (0x1D183, 0x1D184, 160),
+    (0x1D185, 0x1D18B, 159),
+    (0x1D18C, 0x1D1A9, 160),
+    (0x1D1AA, 0x1D1AD, 159),
+    (0x1D1AE, 0x1D1EA, 160),
+    (0x1D200, 0x1D245, 43),
+    (0x1D2E0, 0x1D2F3, 160),
+    (0x1D300, 0x1D356, 160),
+    (0x1D360, 0x1D378, 160),
+    (0x1D400, 0x1D454, 160),
+    (0x1D456, 0x1D49C, 160),
+    (0x1D49E, 0x1D49F, 160),
+    (0x1D4A2, 0x1D4A2, 160),
+    (0x1D4A5, 0x1D4A6, 160),
+    (0x1D4A9, 0x1D4AC, 160),
+    (0x1D4AE, 0x1D4B9, 160),
+    (0x1D4BB, 0x1D4BB, 160),
+    (0x1D4BD, 0x1D4C3, 160),
+    (0x1D4C5, 0x1D505, 160),
+    (0x1D507, 0x1D50A, 160),
+    (0x1D50D, 0x1D514, 160),
+    (0x1D516, 0x1D51C, 160),
+    (0x1D51E, 0x1D539, 160),
+    (0x1D53B, 0x1D53E, 160),
+    (0x1D540, 0x1D544, 160),
+    (0x1D546, 0x1D546, 160),
+    (0x1D54A, 0x1D550, 160),
+    (0x1D552, 0x1D6A5, 160),
+    (0x1D6A8, 0x1D7CB, 160),
+    (0x1D7CE, 0x1D7FF, 160),
+    (0x1D800, 0x1DA8B, 120),
+    (0x1DA9B, 0x1DA9F, 120),
+    (0x1DAA1, 0x1DAAF, 120),
+    (0x1DF00, 0x1DF1E, 68),
+    (0x1E000, 0x1E006, 38),
+    (0x1E008, 0x1E018, 38),
+    (0x1E01B, 0x1E021, 38),
+    (0x1E023, 0x1E024, 38),
+    (0x1E026, 0x1E02A, 38),
+    (0x1E100, 0x1E12C, 54),
+    (0x1E130, 0x1E13D, 54),
+    (0x1E140, 0x1E149, 54),
+    (0x1E14E, 0x1E14F, 54),
+    (0x1E290, 0x1E2AE, 148),
+    (0x1E2C0, 0x1E2F9, 153),
+    (0x1E2FF, 0x1E2FF, 153),
+    (0x1E7E0, 0x1E7E6, 36),
+    (0x1E7E8, 0x1E7EB, 36),
+    (0x1E7ED, 0x1E7EE, 36),
+    (0x1E7F0, 0x1E7FE, 36),
+    (0x1E800, 0x1E8C4, 82),
+    (0x1E8C7, 0x1E8D6, 82),
+    (0x1E900, 0x1E94B, 0),
+    (0x1E950, 0x1E959, 0),
+    (0x1E95E, 0x1E95F, 0),
+    (0x1EC71, 0x1ECB4, 160),
+    (0x1ED01, 0x1ED3D, 160),
+    (0x1EE00, 0x1EE03, 3),
+    (0x1EE05, 0x1EE1F, 3),
+    (0x1EE21, 0x1EE22, 3),
+    (0x1EE24, 0x1EE24, 3),
+    (0x1EE27, 0x1EE27, 3),
+    (0x1EE29, 0x1EE32, 3),
+    (0x1EE34, 0x1EE37, 3),
+    (0x1EE39, 0x1EE39, 3),
+    (0x1EE3B, 0x1EE3B, 3),
+    (0x1EE42, 0x1EE42, 3),
+    (0x1EE47, 0x1EE47, 3),
+    (0x1EE49, 0x1EE49, 3),
+    (0x1EE4B, 0x1EE4B, 3),
+    (0x1EE4D, 0x1EE4F, 3),
+    (0x1EE51, 0x1EE52, 3),
+    (0x1EE54, 0x1EE54, 3),
+    (0x1EE57, 0x1EE57, 3),
+    (0x1EE59, 0x1EE59, 3),
+    (0x1EE5B, 0x1EE5B, 3),
+    (0x1EE5D, 0x1EE5D, 3),
+    (0x1EE5F, 0x1EE5F, 3),
+    (0x1EE61, 0x1EE62, 3),
+    (0x1EE64, 0x1EE64, 3),
+    (0x1EE67, 0x1EE6A, 3),
+    (0x1EE6C, 0x1EE72, 3),
+    (0x1EE74, 0x1EE77, 3),
+    (0x1EE79, 0x1EE7C, 3),
+    (0x1EE7E, 0x1EE7E, 3),
+    (0x1EE80, 0x1EE89, 3),
+    (0x1EE8B, 0x1EE9B, 3),
+    (0x1EEA1, 0x1EEA3, 3),
+    (0x1EEA5, 0x1EEA9, 3),
+    (0x1EEAB, 0x1EEBB, 3),
+    (0x1EEF0, 0x1EEF1, 3),
+    (0x1F000, 0x1F02B, 160),
+    (0x1F030, 0x1F093, 160),
+    (0x1F0A0, 0x1F0AE, 160),
+    (0x1F0B1, 0x1F0BF, 160),
+    (0x1F0C1, 0x1F0CF, 160),
+    (0x1F0D1, 0x1F0F5, 160),
+    (0x1F100, 0x1F1AD, 160),
+    (0x1F1E6, 0x1F1FF, 160),
+    (0x1F200, 0x1F200, 51),
+    (0x1F201, 0x1F202, 160),
+    (0x1F210, 0x1F23B, 160),
+    (0x1F240, 0x1F248, 160),
+    (0x1F250, 0x1F251, 160),
+    (0x1F260, 0x1F265, 160),
+    (0x1F300, 0x1F6D7, 160),
+    (0x1F6DD, 0x1F6EC, 160),
+    (0x1F6F0, 0x1F6FC, 160),
+    (0x1F700, 0x1F773, 160),
+    (0x1F780, 0x1F7D8, 160),
+    (0x1F7E0, 0x1F7EB, 160),
+    (0x1F7F0, 0x1F7F0, 160),
+    (0x1F800, 0x1F80B, 160),
+    (0x1F810, 0x1F847, 160),
+    (0x1F850, 0x1F859, 160),
+    (0x1F860, 0x1F887, 160),
+    (0x1F890, 0x1F8AD, 160),
+    (0x1F8B0, 0x1F8B1, 160),
+    (0x1F900, 0x1FA53, 160),
+    (0x1FA60, 0x1FA6D, 160),
+    (0x1FA70, 0x1FA74, 160),
+    (0x1FA78, 0x1FA7C, 160),
+    (0x1FA80, 0x1FA86, 160),
+    (0x1FA90, 0x1FAAC, 160),
+    (0x1FAB0, 0x1FABA, 160),
+    (0x1FAC0, 0x1FAC5, 160),
+    (0x1FAD0, 0x1FAD9, 160),
+    (0x1FAE0, 0x1FAE7, 160),
+    (0x1FAF0, 0x1FAF6, 160),
+    (0x1FB00, 0x1FB92, 160),
+    (0x1FB94, 0x1FBCA, 160),
+    (0x1FBF0, 0x1FBF9, 160),
+    (0x20000, 0x2A6DF, 47),
+    (0x2A700, 0x2B738, 47),
+    (0x2B740, 0x2B81D, 47),
+    (0x2B820, 0x2CEA1, 47),
+    (0x2CEB0, 0x2EBE0, 47),
+    (0x2F800, 0x2FA1D, 47),
+    (0x30000, 0x3134A, 47),
+    (0xE0001, 0xE0001, 160),
+    (0xE0020, 0xE007F, 160),
+    (0xE0100, 0xE01EF, 159),
+];
+
+// Basic Latin lowercase letters followed by language-specific ones.
+macro_rules! latin {
+    ($s:expr) => {
+        concat!("abcdefghijklmnopqrstuvwxyz", $s)
+    };
+}
+
+#[rustfmt::skip]
+static LANGUAGES: &[(&str, bool, &str)] = &[
+    ("af", true, latin!("áâéèêëîïôöû")),
+    ("ar", false, "ءآأؤإئابةتثجحخدذرزسشصضطظعغفقكلمنهوىي"),
+    ("az", true, latin!("çəğıöşü")),
+    ("be", true, "абвгдеёжзійклмнопрстуўфхцчшыьэюя"),
+    ("bg", true, "абвгдежзийклмнопрстуфхцчшщъьюя"),
+    ("bn", false, "ঁংঃঅআইঈউঊঋএঐওঔকখগঘঙচছজঝঞটঠডঢণতথদধনপফবভমযরলশষসহ়ািীুূৃেৈোৌ্ৎ"),
+    ("ca", true, latin!("àçèéíïòóúü")),
+    ("cs", true, latin!("áčďéěíňóřšťúůýž")),
+    ("cy", true, latin!("áàâäéèêëíìîïóòôöúùûüẃẁŵẅýỳŷÿ")),
+    ("da", true, latin!("æøå")),
+    ("de", true, latin!("äöüß")),
+    ("el", true, "αάβγδεέζηήθιίϊΐκλμνξοόπρσςτυύϋΰφχψωώ"),
+    ("en", true, latin!("")),
+    ("eo", true, latin!("ĉĝĥĵŝŭ")),
+    ("es", true, latin!("áéíñóúü")),
+    ("et", true, latin!("äõöüšž")),
+    ("fa", false, "ءآأؤئابپتثجچحخدذرزژسشصضطظعغفقکگلمنوهی"),
+    ("fi", true, latin!("åäö")),
+    ("fr", true, latin!("àâæçéèêëîïôœùûüÿ")),
+    ("ga", true, latin!("áéíóú")),
+    ("gl", true, latin!("áéíñóú")),
+    ("gu", false, "ઁંઃઅઆઇઈઉઊઋઍએઐઑઓઔકખગઘઙચછજઝઞટઠડઢણતથદધનપફબભમયરલળવશષસહ઼ાિીુૂૃૅેૈૉોૌ્"),
+    ("he", false, "אבגדהוזחטיכךלמםנןסעפףצץקרשת"),
+    ("hi", false, "ँंःअआइईउऊऋएऐऑओऔकखगघङचछजझञटठडढणतथदधनपफबभमयरलवशषसह़ािीुूृॅेैॉोौ्"),
+    ("hr", true, latin!("čćđšž")),
+    ("hu", true, latin!("áéíóöőúüű")),
+    ("hy", true, "աբգդեզէըթժիլխծկհձղճմյնշոչպջռսվտրցւփքօֆ"),
+    ("id", true, latin!("")),
+    ("is", true, latin!("áðéíóúýþæö")),
+    ("it", true, latin!("àéèìóòù")),
+    ("ja", false, "あいうえおかきくけこさしすせそたちつてとなにぬねのはひふへほまみむめもやゆよらりるれろわをんがぎぐげござじずぜぞだぢづでどばびぶべぼぱぴぷぺぽぁぃぅぇぉっゃゅょアイウエオカキクケコサシスセソタチツテトナニヌネノハヒフヘホマミムメモヤユヨラリルレロワヲンガギグゲゴザジズゼゾダヂヅデドバビブベボパピプペポァィゥェォッャュョー日本人一二三四五六七八九十百千万円年月時分上下中大小学生先出入見行来国語電車私何今"),
+    ("ka", false, "აბგდევზთიკლმნოპჟრსტუფქღყშჩცძწჭხჯჰ"),
+    ("kk", true, "аәбвгғдеёжзийкқлмнңоөпрстуұүфхһцчшщъыіьэюя"),
+    ("kn", false, "ಂಃಅಆಇಈಉಊಋಎಏಐಒಓಔಕಖಗಘಙಚಛಜಝಞಟಠಡಢಣತಥದಧನಪಫಬಭಮಯರಱಲವಶಷಸಹಳಾಿೀುೂೃೆೇೈೊೋೌ್"),
+    ("ko", false, "가각간갈감강개거건것게겠결경고공과관교구국귀그근글기길김까나남내너년노는니다단달당대더데도동되된될두드들등디따때또라래러려력로록루류르를리마만많말매며면명모무문물미민바반받발방배버번법변보본부분불비사산살상새생서선설성세소속수스습시식신실심아안않알야약양어언얼없었에여역연열영예오와완요용우운원월위유으은을음의이인일임입있자작장재저적전정제조좋주중즈지직진질집차참처천체초추치카커케코크타터토통트파퍼페포표프하학한할함합해했행향허현형호화확활회후히"),
+    ("lt", true, latin!("ąčęėįšųūž")),
+    ("lv", true, latin!("āčēģīķļņšūž")),
+    ("mk", true, "абвгдѓежзѕијклљмнњопрстќуфхцчџш"),
+    ("ml", false, "ംഃഅആഇഈഉഊഋഎഏഐഒഓഔകഖഗഘങചഛജഝഞടഠഡഢണതഥദധനപഫബഭമയരറലളഴവശഷസഹാിീുൂൃെേൈൊോൌ്"),
+    ("mn", true, "абвгдеёжзийклмноөпрстуүфхцчшщъыьэюя"),
+    ("mr", false, "ँंःअआइईउऊऋएऐऑओऔकखगघङचछजझञटठडढणतथदधनपफबभमयरलवशषसह़ािीुूृॅेैॉोौ्ळ"),
+    ("ms", true, latin!("")),
+    ("mt", true, latin!("àċèġħìòùż")),
+    ("nb", true, latin!("æøå")),
+    ("ne", false, "ँंःअआइईउऊऋएऐऑओऔकखगघङचछजझञटठडढणतथदधनपफबभमयरलवशषसह़ािीुूृॅेैॉोौ्"),
+    ("nl", true, latin!("áäéëíïóöúü")),
+    ("nn", true, latin!("æøå")),
+    ("pa", false, "ਂਅਆਇਈਉਊਏਐਓਔਕਖਗਘਙਚਛਜਝਞਟਠਡਢਣਤਥਦਧਨਪਫਬਭਮਯਰਲਵਸਹ਼ਾਿੀੁੂੇੈੋੌ੍ੜੰੱ"),
+    ("pl", true, latin!("ąćęłńóśźż")),
+    ("pt", true, latin!("áàâãçéêíóôõú")),
+    ("ro", true, latin!("ăâîșț")),
+    ("ru", true, "абвгдеёжзийклмнопрстуфхцчшщъыьэюя"),
+    ("sk", true, latin!("áäčďéíĺľňóôŕšťúýž")),
+    ("sl", true, latin!("čšž")),
+    ("sq", true, latin!("çë")),
+    ("sr", true, "абвгдђежзијклљмнњопрстћуфхцчџш"),
+    ("sr-Latn", true, latin!("čćđšž")),
+    ("sv", true, latin!("åäö")),
+    ("sw", true, latin!("")),
+    ("ta", false, "அஆஇஈஉஊஎஏஐஒஓஔஃகஙசஞடணதநபமயரலவழளறனஜஷஸஹாிீுூெேைொோௌ்"),
+    ("te", false, "ఁంఃఅఆఇఈఉఊఋఎఏఐఒఓఔకఖగఘఙచఛజఝఞటఠడఢణతథదధనపఫబభమయరఱలళవశషసహాిీుూృెేైొోౌ్"),
+    ("th", false, "กขฃคฅฆงจฉชซฌญฎฏฐฑฒณดตถทธนบปผฝพฟภมยรฤลฦวศษสหฬอฮฯะัาำิีึืฺุูเแโใไๅๆ็่้๊๋์ํ"),
+    ("tr", true, latin!("çğıİöşü")),
+    ("uk", true, "абвгґдеєжзиіїйклмнопрстуфхцчшщьюя"),
+    ("ur", false, "ءآابپتٹثجچحخدڈذرڑزژسشصضطظعغفقکگلمنںوؤہھیئے"),
+    ("vi", true, latin!("àảãáạăằẳẵắặâầẩẫấậđèẻẽéẹêềểễếệìỉĩíịòỏõóọôồổỗốộơờởỡớợùủũúụưừửữứựỳỷỹýỵ")),
+    ("zh-Hans", false, "的一是不了人我在有他这中大来上国个到说们为子和你地出道也时年得就那要下以生会自着去之过家学对可她里后小么心多天而能好都然没日于起还发成事只作当想看文无开手十用主行方又如前所本见经头面公同三已老从动两长知民样现分将外但身些与高意进把法此实回二理美点月明其种声全工己话"),
+    ("zh-Hant", false, "的一是不了人我在有他這中大來上國個到說們為子和你地出道也時年得就那要下以生會自著去之過家學對可她裡後小麼心多天而能好都然沒日於起還發成事只作當想看文無開手十用主行方又如前所本見經頭面公同三已老從動兩長知民樣現分將外但身些與高意進把法此實回二理美點月明其種聲全工己話"),
+];
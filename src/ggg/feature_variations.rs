@@ -38,14 +38,18 @@ impl<'a> FeatureVariations<'a> {
     }
 
     /// Returns a [`Feature`] at specified indices.
+    ///
+    /// `feature_tag` is the tag of the substituted feature.
+    /// It's required to parse feature parameters, since the substitute has no tag of its own.
     pub fn find_substitute(
         &self,
         feature_index: FeatureIndex,
         variation_index: VariationIndex,
+        feature_tag: Tag,
     ) -> Option<Feature<'a>> {
         let offset = self.records.get(variation_index)?.substitutions.to_usize();
         let subst = FeatureTableSubstitution::parse(self.data.get(offset..)?)?;
-        subst.find_substitute(feature_index)
+        subst.find_substitute(feature_index, feature_tag)
     }
 }
 
@@ -154,12 +158,15 @@ impl<'a> FeatureTableSubstitution<'a> {
         Some(Self { data, records })
     }
 
-    fn find_substitute(&self, feature_index: FeatureIndex) -> Option<Feature<'a>> {
+    fn find_substitute(
+        &self,
+        feature_index: FeatureIndex,
+        feature_tag: Tag,
+    ) -> Option<Feature<'a>> {
         for record in self.records {
             if record.feature_index == feature_index {
                 let offset = record.feature.to_usize();
-                // TODO: set tag
-                return Feature::parse(Tag::from_bytes(b"DFLT"), self.data.get(offset..)?);
+                return Feature::parse(feature_tag, self.data.get(offset..)?);
             }
         }
         None
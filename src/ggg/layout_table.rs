@@ -4,9 +4,12 @@
 #[cfg(feature = "variable-fonts")]
 use super::FeatureVariations;
 use super::LookupList;
+use crate::opentype_tags;
 #[cfg(feature = "variable-fonts")]
 use crate::parser::Offset32;
-use crate::parser::{FromData, LazyArray16, Offset, Offset16, Stream};
+use crate::parser::{FromData, LazyArray16, Offset, Offset16, Stream, U24};
+#[cfg(feature = "variable-fonts")]
+use crate::NormalizedCoordinate;
 use crate::Tag;
 
 /// A [Layout Table](https://docs.microsoft.com/en-us/typography/opentype/spec/chapter2#table-organization).
@@ -25,7 +28,8 @@ pub struct LayoutTable<'a> {
 }
 
 impl<'a> LayoutTable<'a> {
-    pub(crate) fn parse(data: &'a [u8]) -> Option<Self> {
+    /// Parses a table from raw data.
+    pub fn parse(data: &'a [u8]) -> Option<Self> {
         let mut s = Stream::new(data);
 
         let major_version = s.read::<u16>()?;
@@ -71,6 +75,199 @@ impl<'a> LayoutTable<'a> {
     }
 }
 
+impl<'a> LayoutTable<'a> {
+    /// Returns features available for the specified script and language.
+    ///
+    /// When `language` is not set or not present in the script,
+    /// features of the default language system are returned.
+    /// The required feature, if any, goes first.
+    ///
+    /// Returns `None` when the script or its default language system are not present.
+    pub fn language_features(
+        &self,
+        script: Tag,
+        language: Option<Tag>,
+    ) -> Option<LanguageFeatures<'a>> {
+        let script = self.scripts.find(script)?;
+        let language = match language.and_then(|tag| script.languages.find(tag)) {
+            Some(language) => language,
+            None => script.default_language?,
+        };
+
+        Some(LanguageFeatures {
+            table: *self,
+            #[cfg(feature = "variable-fonts")]
+            variation_index: None,
+            required_feature: language.required_feature,
+            feature_indices: language.feature_indices,
+            index: 0,
+        })
+    }
+
+    /// Returns the best matching script and language system for a BCP 47 language tag.
+    ///
+    /// Script tags are resolved via [`opentype_tags::default_script`]
+    /// with `DFLT`, `dflt` and `latn` scripts as a fallback, just like shapers do.
+    /// Language system tags are resolved via [`opentype_tags::language_tags`]
+    /// with the default language system of the script as a fallback.
+    ///
+    /// Returns `None` when neither of the scripts is present
+    /// or a present script has no matching or default language system.
+    pub fn select_language_system(&self, locale: &str) -> Option<(Script<'a>, LanguageSystem<'a>)> {
+        let script_tags = opentype_tags::default_script(locale)
+            .map(opentype_tags::script_tags)
+            .unwrap_or_default();
+        let fallback = [
+            opentype_tags::DEFAULT_SCRIPT,
+            Tag::from_bytes(b"dflt"),
+            Tag::from_bytes(b"latn"),
+        ];
+
+        for tag in script_tags.chain(fallback.iter().cloned()) {
+            let script = match self.scripts.find(tag) {
+                Some(v) => v,
+                None => continue,
+            };
+
+            let language = opentype_tags::language_tags(locale)
+                .find_map(|tag| script.languages.find(tag))
+                .or(script.default_language);
+            if let Some(language) = language {
+                return Some((script, language));
+            }
+        }
+
+        None
+    }
+
+    /// Returns features available for the specified script and language
+    /// with feature variations applied.
+    ///
+    /// Like [`LayoutTable::language_features`], but features are substituted
+    /// according to [`FeatureVariations`] for the specified variation coordinates.
+    #[cfg(feature = "variable-fonts")]
+    pub fn language_feature_variations(
+        &self,
+        script: Tag,
+        language: Option<Tag>,
+        coordinates: &[NormalizedCoordinate],
+    ) -> Option<LanguageFeatures<'a>> {
+        let mut features = self.language_features(script, language)?;
+        features.variation_index = self.variation_index(coordinates);
+        Some(features)
+    }
+
+    /// Returns an index of the first [`FeatureVariations`] record
+    /// matching the specified variation coordinates.
+    ///
+    /// Returns `None` when the table has no feature variations
+    /// or none of them match.
+    #[cfg(feature = "variable-fonts")]
+    #[inline]
+    pub fn variation_index(&self, coordinates: &[NormalizedCoordinate]) -> Option<VariationIndex> {
+        self.variations?.find_index(coordinates)
+    }
+
+    /// Returns a feature at index with feature variations applied.
+    ///
+    /// When `variation_index` is set and the feature has a substitute,
+    /// the substitute is returned instead.
+    /// It keeps the original feature tag and,
+    /// when it has no parameters of its own, the original parameters.
+    #[cfg(feature = "variable-fonts")]
+    pub fn feature_variation(
+        &self,
+        index: FeatureIndex,
+        variation_index: Option<VariationIndex>,
+    ) -> Option<Feature<'a>> {
+        let feature = self.features.get(index)?;
+        let substitute = variation_index.and_then(|variation_index| {
+            self.variations?
+                .find_substitute(index, variation_index, feature.tag)
+        });
+
+        match substitute {
+            Some(substitute) => Some(Feature {
+                params: substitute.params.or(feature.params),
+                ..substitute
+            }),
+            None => Some(feature),
+        }
+    }
+}
+
+impl<'a> LayoutTable<'a> {
+    /// Returns a feature at index with feature variations applied, when supported.
+    #[inline]
+    pub(crate) fn resolve_feature(
+        &self,
+        index: FeatureIndex,
+        variation_index: Option<VariationIndex>,
+    ) -> Option<Feature<'a>> {
+        #[cfg(feature = "variable-fonts")]
+        {
+            self.feature_variation(index, variation_index)
+        }
+
+        #[cfg(not(feature = "variable-fonts"))]
+        {
+            self.features.get(index)
+        }
+    }
+}
+
+/// An iterator over features of a [`LanguageSystem`].
+///
+/// Yields features along with their indices in [`FeatureList`].
+/// Features that cannot be parsed are skipped.
+#[derive(Clone, Copy)]
+#[allow(missing_debug_implementations)]
+pub struct LanguageFeatures<'a> {
+    table: LayoutTable<'a>,
+    #[cfg(feature = "variable-fonts")]
+    variation_index: Option<VariationIndex>,
+    required_feature: Option<FeatureIndex>,
+    feature_indices: LazyArray16<'a, FeatureIndex>,
+    index: u16,
+}
+
+impl LanguageFeatures<'_> {
+    #[inline]
+    fn variation_index(&self) -> Option<VariationIndex> {
+        #[cfg(feature = "variable-fonts")]
+        {
+            self.variation_index
+        }
+
+        #[cfg(not(feature = "variable-fonts"))]
+        {
+            None
+        }
+    }
+}
+
+impl<'a> Iterator for LanguageFeatures<'a> {
+    type Item = (FeatureIndex, Feature<'a>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(index) = self.required_feature.take() {
+            if let Some(feature) = self.table.resolve_feature(index, self.variation_index()) {
+                return Some((index, feature));
+            }
+        }
+
+        while self.index < self.feature_indices.len() {
+            self.index += 1;
+            let index = self.feature_indices.get(self.index - 1)?;
+            if let Some(feature) = self.table.resolve_feature(index, self.variation_index()) {
+                return Some((index, feature));
+            }
+        }
+
+        None
+    }
+}
+
 /// An index in [`ScriptList`].
 pub type ScriptIndex = u16;
 /// An index in [`LanguageSystemList`].
@@ -128,6 +325,12 @@ impl<'a, T: RecordListItem<'a>> RecordList<'a, T> {
             .and_then(|data| T::parse(record.tag, data))
     }
 
+    /// Returns a tag of the record at index without parsing the value.
+    #[inline]
+    pub(crate) fn tag(&self, index: u16) -> Option<Tag> {
+        self.records.get(index).map(|record| record.tag)
+    }
+
     /// Returns RecordList value by [`Tag`].
     pub fn find(&self, tag: Tag) -> Option<T> {
         let record = self
@@ -271,17 +474,209 @@ impl<'a> RecordListItem<'a> for LanguageSystem<'a> {
 pub struct Feature<'a> {
     pub tag: Tag,
     pub lookup_indices: LazyArray16<'a, LookupIndex>,
+    pub params: Option<FeatureParams<'a>>,
 }
 
 impl<'a> RecordListItem<'a> for Feature<'a> {
     fn parse(tag: Tag, data: &'a [u8]) -> Option<Self> {
         let mut s = Stream::new(data);
-        let _params_offset = s.read::<Offset16>()?; // Unsupported.
+        let params_offset = s.read::<Option<Offset16>>()?;
         let count = s.read::<u16>()?;
         let lookup_indices = s.read_array16(count)?;
+        // Malformed parameters should not prevent the feature itself from being used.
+        let params = params_offset
+            .and_then(|offset| data.get(offset.to_usize()..))
+            .and_then(|data| FeatureParams::parse(tag, data));
         Some(Self {
             tag,
             lookup_indices,
+            params,
         })
     }
 }
+
+/// [Feature parameters](https://docs.microsoft.com/en-us/typography/opentype/spec/chapter2#feature-table).
+///
+/// The parameters format is defined by the feature tag.
+#[derive(Clone, Copy, Debug)]
+pub enum FeatureParams<'a> {
+    /// Parameters of a `ss01`-`ss20` feature.
+    StylisticSet(StylisticSetParams),
+    /// Parameters of a `cv01`-`cv99` feature.
+    CharacterVariant(CharacterVariantParams<'a>),
+    /// Parameters of a `size` feature.
+    Size(SizeParams),
+}
+
+impl<'a> FeatureParams<'a> {
+    fn parse(tag: Tag, data: &'a [u8]) -> Option<Self> {
+        let bytes = tag.to_bytes();
+        match (bytes[0], bytes[1], feature_number(bytes[2], bytes[3])) {
+            (b's', b's', Some(1..=20)) => StylisticSetParams::parse(data).map(Self::StylisticSet),
+            (b'c', b'v', Some(1..=99)) => {
+                CharacterVariantParams::parse(data).map(Self::CharacterVariant)
+            }
+            _ if tag == Tag::from_bytes(b"size") => SizeParams::parse(data).map(Self::Size),
+            _ => None,
+        }
+    }
+
+    /// Returns a `name` table ID of a user-interface string for this feature.
+    ///
+    /// This is a stylistic set name, a character variant label
+    /// or an optical size subfamily name.
+    pub fn ui_name_id(&self) -> Option<u16> {
+        match self {
+            Self::StylisticSet(params) => Some(params.ui_name_id),
+            Self::CharacterVariant(params) => params.feature_ui_label_name_id,
+            Self::Size(params) => params.subfamily_name_id,
+        }
+    }
+}
+
+fn feature_number(a: u8, b: u8) -> Option<u8> {
+    if a.is_ascii_digit() && b.is_ascii_digit() {
+        Some((a - b'0') * 10 + (b - b'0'))
+    } else {
+        None
+    }
+}
+
+fn name_id(id: u16) -> Option<u16> {
+    // Zero is used as NULL.
+    if id != 0 {
+        Some(id)
+    } else {
+        None
+    }
+}
+
+/// [Stylistic set parameters](
+/// https://docs.microsoft.com/en-us/typography/opentype/spec/features_pt#ss01---ss20).
+#[derive(Clone, Copy, Debug)]
+pub struct StylisticSetParams {
+    /// A `name` table ID of a user-interface string for this stylistic set.
+    pub ui_name_id: u16,
+}
+
+impl StylisticSetParams {
+    fn parse(data: &[u8]) -> Option<Self> {
+        let mut s = Stream::new(data);
+        let version = s.read::<u16>()?;
+        if version != 0 {
+            return None;
+        }
+
+        Some(StylisticSetParams {
+            ui_name_id: s.read::<u16>()?,
+        })
+    }
+}
+
+/// [Character variant parameters](
+/// https://docs.microsoft.com/en-us/typography/opentype/spec/features_ae#cv01-cv99).
+#[derive(Clone, Copy, Debug)]
+pub struct CharacterVariantParams<'a> {
+    /// A `name` table ID of a user-interface label for this feature.
+    pub feature_ui_label_name_id: Option<u16>,
+    /// A `name` table ID of a tooltip text for this feature.
+    pub feature_ui_tooltip_text_name_id: Option<u16>,
+    /// A `name` table ID of a sample text that illustrates this feature.
+    pub sample_text_name_id: Option<u16>,
+    /// A number of named parameters.
+    pub num_named_parameters: u16,
+    /// A `name` table ID of the first named parameter label.
+    ///
+    /// Labels of the following parameters use consecutive IDs.
+    pub first_param_ui_label_name_id: Option<u16>,
+    /// A list of Unicode code points for which this feature provides glyph variants.
+    pub characters: LazyArray16<'a, U24>,
+}
+
+impl<'a> CharacterVariantParams<'a> {
+    fn parse(data: &'a [u8]) -> Option<Self> {
+        let mut s = Stream::new(data);
+        let format = s.read::<u16>()?;
+        if format != 0 {
+            return None;
+        }
+
+        let feature_ui_label_name_id = name_id(s.read::<u16>()?);
+        let feature_ui_tooltip_text_name_id = name_id(s.read::<u16>()?);
+        let sample_text_name_id = name_id(s.read::<u16>()?);
+        let num_named_parameters = s.read::<u16>()?;
+        let first_param_ui_label_name_id = name_id(s.read::<u16>()?);
+        let count = s.read::<u16>()?;
+        let characters = s.read_array16(count)?;
+        Some(CharacterVariantParams {
+            feature_ui_label_name_id,
+            feature_ui_tooltip_text_name_id,
+            sample_text_name_id,
+            num_named_parameters,
+            first_param_ui_label_name_id,
+            characters,
+        })
+    }
+
+    /// Returns a `name` table ID of a named parameter label.
+    pub fn param_ui_label_name_id(&self, index: u16) -> Option<u16> {
+        if index < self.num_named_parameters {
+            self.first_param_ui_label_name_id?.checked_add(index)
+        } else {
+            None
+        }
+    }
+}
+
+/// [Optical size parameters](
+/// https://docs.microsoft.com/en-us/typography/opentype/spec/features_pt#size).
+///
+/// All sizes are in decipoints (tenths of a point).
+#[derive(Clone, Copy, Debug)]
+pub struct SizeParams {
+    /// A design size.
+    pub design_size: u16,
+    /// An identifier shared by all fonts of a family that differ only by optical size.
+    ///
+    /// Zero when the font is not a part of such subfamily.
+    pub subfamily_id: u16,
+    /// A `name` table ID of a subfamily name shared by such fonts.
+    pub subfamily_name_id: Option<u16>,
+    /// The small end of the recommended usage range (exclusive).
+    pub range_start: u16,
+    /// The large end of the recommended usage range (inclusive).
+    pub range_end: u16,
+}
+
+impl SizeParams {
+    fn parse(data: &[u8]) -> Option<Self> {
+        let mut s = Stream::new(data);
+        let design_size = s.read::<u16>()?;
+        let subfamily_id = s.read::<u16>()?;
+        let subfamily_name_id = name_id(s.read::<u16>()?);
+        let range_start = s.read::<u16>()?;
+        let range_end = s.read::<u16>()?;
+        if design_size == 0 || range_start > range_end {
+            return None;
+        }
+
+        Some(SizeParams {
+            design_size,
+            subfamily_id,
+            subfamily_name_id,
+            range_start,
+            range_end,
+        })
+    }
+
+    /// Checks that the specified size, in decipoints, is in the recommended usage range.
+    ///
+    /// Always `true` when the range is not set.
+    pub fn contains(&self, size: u16) -> bool {
+        if self.range_start == 0 && self.range_end == 0 {
+            true
+        } else {
+            size > self.range_start && size <= self.range_end
+        }
+    }
+}
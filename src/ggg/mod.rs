@@ -5,6 +5,8 @@
 // A heavily modified port of https://github.com/RazrFalcon/rustybuzz implementation
 // originally written by https://github.com/laurmaedje
 
+use core::convert::TryFrom;
+
 use crate::parser::{FromData, FromSlice, LazyArray16, Stream};
 use crate::GlyphId;
 
@@ -79,7 +81,15 @@ pub enum Coverage<'a> {
 }
 
 impl<'a> FromSlice<'a> for Coverage<'a> {
+    #[inline]
     fn parse(data: &'a [u8]) -> Option<Self> {
+        Coverage::parse(data)
+    }
+}
+
+impl<'a> Coverage<'a> {
+    /// Parses a coverage table from raw data.
+    pub fn parse(data: &'a [u8]) -> Option<Self> {
         let mut s = Stream::new(data);
         match s.read::<u16>()? {
             1 => {
@@ -95,9 +105,7 @@ impl<'a> FromSlice<'a> for Coverage<'a> {
             _ => None,
         }
     }
-}
 
-impl<'a> Coverage<'a> {
     /// Checks that glyph is present.
     pub fn contains(&self, glyph: GlyphId) -> bool {
         self.get(glyph).is_some()
@@ -114,6 +122,116 @@ impl<'a> Coverage<'a> {
             }
         }
     }
+
+    /// Returns an iterator over covered glyphs and their coverage indices.
+    ///
+    /// Glyphs are returned in the coverage index order.
+    #[inline]
+    pub fn iter(&self) -> CoverageIter<'a> {
+        let records = match self {
+            Self::Format1 { .. } => LazyArray16::default(),
+            Self::Format2 { records } => *records,
+        };
+
+        CoverageIter {
+            coverage: *self,
+            index: 0,
+            ranges: RangeRecordsIter::new(records),
+        }
+    }
+}
+
+impl<'a> IntoIterator for Coverage<'a> {
+    type Item = (GlyphId, u16);
+    type IntoIter = CoverageIter<'a>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over [`Coverage`] glyphs.
+///
+/// Yields a glyph ID and its coverage index.
+#[derive(Clone, Copy)]
+#[allow(missing_debug_implementations)]
+pub struct CoverageIter<'a> {
+    coverage: Coverage<'a>,
+    index: u16,
+    ranges: RangeRecordsIter<'a>,
+}
+
+impl Iterator for CoverageIter<'_> {
+    type Item = (GlyphId, u16);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.coverage {
+            Coverage::Format1 { glyphs } => {
+                let glyph = glyphs.get(self.index)?;
+                let index = self.index;
+                self.index = self.index.checked_add(1)?;
+                Some((glyph, index))
+            }
+            Coverage::Format2 { .. } => loop {
+                let (glyph, record, offset) = self.ranges.next()?;
+                // Skip glyphs with an overflowing coverage index,
+                // just like `Coverage::get` does.
+                if let Some(index) = record.value.checked_add(offset) {
+                    return Some((glyph, index));
+                }
+            },
+        }
+    }
+}
+
+/// An iterator over glyphs in range records.
+///
+/// Yields a glyph, its record and an offset of the glyph from the record start.
+#[derive(Clone, Copy)]
+struct RangeRecordsIter<'a> {
+    records: LazyArray16<'a, RangeRecord>,
+    index: u16,
+    offset: u16,
+}
+
+impl<'a> RangeRecordsIter<'a> {
+    fn new(records: LazyArray16<'a, RangeRecord>) -> Self {
+        RangeRecordsIter {
+            records,
+            index: 0,
+            offset: 0,
+        }
+    }
+}
+
+impl Iterator for RangeRecordsIter<'_> {
+    type Item = (GlyphId, RangeRecord, u16);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let record = self.records.get(self.index)?;
+            let glyph = record.start.0.checked_add(self.offset);
+            match glyph {
+                Some(glyph) if glyph <= record.end.0 => {
+                    let offset = self.offset;
+                    if glyph == record.end.0 {
+                        self.index = self.index.checked_add(1)?;
+                        self.offset = 0;
+                    } else {
+                        self.offset += 1;
+                    }
+
+                    return Some((GlyphId(glyph), record, offset));
+                }
+                _ => {
+                    // A malformed record. Skip it.
+                    self.index = self.index.checked_add(1)?;
+                    self.offset = 0;
+                }
+            }
+        }
+    }
 }
 
 /// A value of [Class Definition Table](
@@ -135,8 +253,9 @@ pub enum ClassDefinition<'a> {
 }
 
 impl<'a> ClassDefinition<'a> {
+    /// Parses a class definition table from raw data.
     #[inline]
-    pub(crate) fn parse(data: &'a [u8]) -> Option<Self> {
+    pub fn parse(data: &'a [u8]) -> Option<Self> {
         let mut s = Stream::new(data);
         match s.read::<u16>()? {
             1 => {
@@ -165,4 +284,152 @@ impl<'a> ClassDefinition<'a> {
         }
         .unwrap_or(0)
     }
+
+    /// Returns an iterator over glyphs with an assigned class.
+    ///
+    /// Glyphs of class zero are omitted, since it contains
+    /// all glyphs that are not explicitly assigned to any other class.
+    #[inline]
+    pub fn iter(&self) -> ClassDefinitionIter<'a> {
+        let records = match self {
+            Self::Format1 { .. } => LazyArray16::default(),
+            Self::Format2 { records } => *records,
+        };
+
+        ClassDefinitionIter {
+            classes: *self,
+            index: 0,
+            ranges: RangeRecordsIter::new(records),
+        }
+    }
+
+    /// Returns an iterator over glyphs of the specified class.
+    ///
+    /// Class zero is not enumerable and will always produce an empty iterator.
+    #[inline]
+    pub fn class_glyphs(&self, class: Class) -> ClassGlyphsIter<'a> {
+        ClassGlyphsIter {
+            iter: self.iter(),
+            class,
+        }
+    }
+}
+
+impl<'a> IntoIterator for ClassDefinition<'a> {
+    type Item = (GlyphId, Class);
+    type IntoIter = ClassDefinitionIter<'a>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over [`ClassDefinition`] glyphs.
+///
+/// Yields a glyph ID and its class in the glyph ID order.
+#[derive(Clone, Copy)]
+#[allow(missing_debug_implementations)]
+pub struct ClassDefinitionIter<'a> {
+    classes: ClassDefinition<'a>,
+    index: u16,
+    ranges: RangeRecordsIter<'a>,
+}
+
+impl Iterator for ClassDefinitionIter<'_> {
+    type Item = (GlyphId, Class);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.classes {
+            ClassDefinition::Format1 { start, classes } => loop {
+                let class = classes.get(self.index)?;
+                let glyph = start.0.checked_add(self.index)?;
+                self.index = self.index.checked_add(1)?;
+                if class != 0 {
+                    return Some((GlyphId(glyph), class));
+                }
+            },
+            ClassDefinition::Format2 { .. } => loop {
+                let (glyph, record, _) = self.ranges.next()?;
+                if record.value != 0 {
+                    return Some((glyph, record.value));
+                }
+            },
+        }
+    }
+}
+
+/// An iterator over glyphs of a single class in a [`ClassDefinition`].
+#[derive(Clone, Copy)]
+#[allow(missing_debug_implementations)]
+pub struct ClassGlyphsIter<'a> {
+    iter: ClassDefinitionIter<'a>,
+    class: Class,
+}
+
+impl Iterator for ClassGlyphsIter<'_> {
+    type Item = GlyphId;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let class = self.class;
+        self.iter
+            .by_ref()
+            .find(|(_, c)| *c == class)
+            .map(|(glyph, _)| glyph)
+    }
+}
+
+/// A set of 16-bit indices, backed by a fixed-size bitset.
+#[derive(Clone)]
+pub(crate) struct BitSet {
+    words: [u32; 2048],
+}
+
+impl BitSet {
+    #[inline]
+    pub(crate) fn new() -> Self {
+        BitSet { words: [0; 2048] }
+    }
+
+    #[inline]
+    pub(crate) fn clear(&mut self) {
+        self.words = [0; 2048];
+    }
+
+    #[inline]
+    pub(crate) fn insert(&mut self, index: u16) -> bool {
+        let word = &mut self.words[usize::from(index >> 5)];
+        let mask = 1 << (index & 31);
+        let is_new = *word & mask == 0;
+        *word |= mask;
+        is_new
+    }
+
+    #[inline]
+    pub(crate) fn contains(&self, index: u16) -> bool {
+        self.words[usize::from(index >> 5)] & (1 << (index & 31)) != 0
+    }
+
+    /// Returns the first index that is greater than or equal to `start`.
+    pub(crate) fn next_from(&self, start: u32) -> Option<u16> {
+        let mut word_index = usize::try_from(start >> 5).ok()?;
+        let mut word = *self.words.get(word_index)? & (!0 << (start & 31));
+        loop {
+            if word != 0 {
+                let index = word_index as u32 * 32 + word.trailing_zeros();
+                return u16::try_from(index).ok();
+            }
+
+            word_index += 1;
+            word = *self.words.get(word_index)?;
+        }
+    }
+
+    /// Checks that any index in the inclusive range is set.
+    pub(crate) fn intersects_range(&self, start: u16, end: u16) -> bool {
+        match self.next_from(u32::from(start)) {
+            Some(index) => index <= end,
+            None => false,
+        }
+    }
 }
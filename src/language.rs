@@ -1,211 +1,491 @@
 #[rustfmt::skip]
-static TABLE: &[(u16, Language, &str, &str)] = &[
-    (0x0000, Language::Unknown, "Unknown", "Unknown"),
-    (0x0436, Language::Afrikaans_SouthAfrica, "Afrikaans", "South Africa"),
-    (0x041C, Language::Albanian_Albania, "Albanian", "Albania"),
-    (0x0484, Language::Alsatian_France, "Alsatian", "France"),
-    (0x045E, Language::Amharic_Ethiopia, "Amharic", "Ethiopia"),
-    (0x1401, Language::Arabic_Algeria, "Arabic", "Algeria"),
-    (0x3C01, Language::Arabic_Bahrain, "Arabic", "Bahrain"),
-    (0x0C01, Language::Arabic_Egypt, "Arabic", "Egypt"),
-    (0x0801, Language::Arabic_Iraq, "Arabic", "Iraq"),
-    (0x2C01, Language::Arabic_Jordan, "Arabic", "Jordan"),
-    (0x3401, Language::Arabic_Kuwait, "Arabic", "Kuwait"),
-    (0x3001, Language::Arabic_Lebanon, "Arabic", "Lebanon"),
-    (0x1001, Language::Arabic_Libya, "Arabic", "Libya"),
-    (0x1801, Language::Arabic_Morocco, "Arabic", "Morocco"),
-    (0x2001, Language::Arabic_Oman, "Arabic", "Oman"),
-    (0x4001, Language::Arabic_Qatar, "Arabic", "Qatar"),
-    (0x0401, Language::Arabic_SaudiArabia, "Arabic", "Saudi Arabia"),
-    (0x2801, Language::Arabic_Syria, "Arabic", "Syria"),
-    (0x1C01, Language::Arabic_Tunisia, "Arabic", "Tunisia"),
-    (0x3801, Language::Arabic_UAE, "Arabic", "U.A.E."),
-    (0x2401, Language::Arabic_Yemen, "Arabic", "Yemen"),
-    (0x042B, Language::Armenian_Armenia, "Armenian", "Armenia"),
-    (0x044D, Language::Assamese_India, "Assamese", "India"),
-    (0x082C, Language::Azeri_Cyrillic_Azerbaijan, "Azeri (Cyrillic)", "Azerbaijan"),
-    (0x042C, Language::Azeri_Latin_Azerbaijan, "Azeri (Latin)", "Azerbaijan"),
-    (0x046D, Language::Bashkir_Russia, "Bashkir", "Russia"),
-    (0x042D, Language::Basque_Basque, "Basque", "Basque"),
-    (0x0423, Language::Belarusian_Belarus, "Belarusian", "Belarus"),
-    (0x0845, Language::Bengali_Bangladesh, "Bengali", "Bangladesh"),
-    (0x0445, Language::Bengali_India, "Bengali", "India"),
-    (0x201A, Language::Bosnian_Cyrillic_BosniaAndHerzegovina, "Bosnian (Cyrillic)", "Bosnia and Herzegovina"),
-    (0x141A, Language::Bosnian_Latin_BosniaAndHerzegovina, "Bosnian (Latin)", "Bosnia and Herzegovina"),
-    (0x047E, Language::Breton_France, "Breton", "France"),
-    (0x0402, Language::Bulgarian_Bulgaria, "Bulgarian", "Bulgaria"),
-    (0x0403, Language::Catalan_Catalan, "Catalan", "Catalan"),
-    (0x0C04, Language::Chinese_HongKongSAR, "Chinese", "Hong Kong S.A.R."),
-    (0x1404, Language::Chinese_MacaoSAR, "Chinese", "Macao S.A.R."),
-    (0x0804, Language::Chinese_PeoplesRepublicOfChina, "Chinese", "People's Republic of China"),
-    (0x1004, Language::Chinese_Singapore, "Chinese", "Singapore"),
-    (0x0404, Language::Chinese_Taiwan, "Chinese", "Taiwan"),
-    (0x0483, Language::Corsican_France, "Corsican", "France"),
-    (0x041A, Language::Croatian_Croatia, "Croatian", "Croatia"),
-    (0x101A, Language::Croatian_Latin_BosniaAndHerzegovina, "Croatian (Latin)", "Bosnia and Herzegovina"),
-    (0x0405, Language::Czech_CzechRepublic, "Czech", "Czech Republic"),
-    (0x0406, Language::Danish_Denmark, "Danish", "Denmark"),
-    (0x048C, Language::Dari_Afghanistan, "Dari", "Afghanistan"),
-    (0x0465, Language::Divehi_Maldives, "Divehi", "Maldives"),
-    (0x0813, Language::Dutch_Belgium, "Dutch", "Belgium"),
-    (0x0413, Language::Dutch_Netherlands, "Dutch", "Netherlands"),
-    (0x0C09, Language::English_Australia, "English", "Australia"),
-    (0x2809, Language::English_Belize, "English", "Belize"),
-    (0x1009, Language::English_Canada, "English", "Canada"),
-    (0x2409, Language::English_Caribbean, "English", "Caribbean"),
-    (0x4009, Language::English_India, "English", "India"),
-    (0x1809, Language::English_Ireland, "English", "Ireland"),
-    (0x2009, Language::English_Jamaica, "English", "Jamaica"),
-    (0x4409, Language::English_Malaysia, "English", "Malaysia"),
-    (0x1409, Language::English_NewZealand, "English", "New Zealand"),
-    (0x3409, Language::English_RepublicOfThePhilippines, "English", "Republic of the Philippines"),
-    (0x4809, Language::English_Singapore, "English", "Singapore"),
-    (0x1C09, Language::English_SouthAfrica, "English", "South Africa"),
-    (0x2C09, Language::English_TrinidadAndTobago, "English", "Trinidad and Tobago"),
-    (0x0809, Language::English_UnitedKingdom, "English", "United Kingdom"),
-    (0x0409, Language::English_UnitedStates, "English", "United States"),
-    (0x3009, Language::English_Zimbabwe, "English", "Zimbabwe"),
-    (0x0425, Language::Estonian_Estonia, "Estonian", "Estonia"),
-    (0x0438, Language::Faroese_FaroeIslands, "Faroese", "Faroe Islands"),
-    (0x0464, Language::Filipino_Philippines, "Filipino", "Philippines"),
-    (0x040B, Language::Finnish_Finland, "Finnish", "Finland"),
-    (0x080C, Language::French_Belgium, "French", "Belgium"),
-    (0x0C0C, Language::French_Canada, "French", "Canada"),
-    (0x040C, Language::French_France, "French", "France"),
-    (0x140c, Language::French_Luxembourg, "French", "Luxembourg"),
-    (0x180C, Language::French_PrincipalityOfMonaco, "French", "Principality of Monaco"),
-    (0x100C, Language::French_Switzerland, "French", "Switzerland"),
-    (0x0462, Language::Frisian_Netherlands, "Frisian", "Netherlands"),
-    (0x0456, Language::Galician_Galician, "Galician", "Galician"),
-    (0x0437, Language::Georgian_Georgia, "Georgian", "Georgia"),
-    (0x0C07, Language::German_Austria, "German", "Austria"),
-    (0x0407, Language::German_Germany, "German", "Germany"),
-    (0x1407, Language::German_Liechtenstein, "German", "Liechtenstein"),
-    (0x1007, Language::German_Luxembourg, "German", "Luxembourg"),
-    (0x0807, Language::German_Switzerland, "German", "Switzerland"),
-    (0x0408, Language::Greek_Greece, "Greek", "Greece"),
-    (0x046F, Language::Greenlandic_Greenland, "Greenlandic", "Greenland"),
-    (0x0447, Language::Gujarati_India, "Gujarati", "India"),
-    (0x0468, Language::Hausa_Latin_Nigeria, "Hausa (Latin)", "Nigeria"),
-    (0x040D, Language::Hebrew_Israel, "Hebrew", "Israel"),
-    (0x0439, Language::Hindi_India, "Hindi", "India"),
-    (0x040E, Language::Hungarian_Hungary, "Hungarian", "Hungary"),
-    (0x040F, Language::Icelandic_Iceland, "Icelandic", "Iceland"),
-    (0x0470, Language::Igbo_Nigeria, "Igbo", "Nigeria"),
-    (0x0421, Language::Indonesian_Indonesia, "Indonesian", "Indonesia"),
-    (0x045D, Language::Inuktitut_Canada, "Inuktitut", "Canada"),
-    (0x085D, Language::Inuktitut_Latin_Canada, "Inuktitut (Latin)", "Canada"),
-    (0x083C, Language::Irish_Ireland, "Irish", "Ireland"),
-    (0x0434, Language::isiXhosa_SouthAfrica, "isiXhosa", "South Africa"),
-    (0x0435, Language::isiZulu_SouthAfrica, "isiZulu", "South Africa"),
-    (0x0410, Language::Italian_Italy, "Italian", "Italy"),
-    (0x0810, Language::Italian_Switzerland, "Italian", "Switzerland"),
-    (0x0411, Language::Japanese_Japan, "Japanese", "Japan"),
-    (0x044B, Language::Kannada_India, "Kannada", "India"),
-    (0x043F, Language::Kazakh_Kazakhstan, "Kazakh", "Kazakhstan"),
-    (0x0453, Language::Khmer_Cambodia, "Khmer", "Cambodia"),
-    (0x0486, Language::Kiche_Guatemala, "K'iche", "Guatemala"),
-    (0x0487, Language::Kinyarwanda_Rwanda, "Kinyarwanda", "Rwanda"),
-    (0x0441, Language::Kiswahili_Kenya, "Kiswahili", "Kenya"),
-    (0x0457, Language::Konkani_India, "Konkani", "India"),
-    (0x0412, Language::Korean_Korea, "Korean", "Korea"),
-    (0x0440, Language::Kyrgyz_Kyrgyzstan, "Kyrgyz", "Kyrgyzstan"),
-    (0x0454, Language::Lao_LaoPDR, "Lao", "Lao P.D.R."),
-    (0x0426, Language::Latvian_Latvia, "Latvian", "Latvia"),
-    (0x0427, Language::Lithuanian_Lithuania, "Lithuanian", "Lithuania"),
-    (0x082E, Language::LowerSorbian_Germany, "Lower Sorbian", "Germany"),
-    (0x046E, Language::Luxembourgish_Luxembourg, "Luxembourgish", "Luxembourg"),
-    (0x042F, Language::Macedonian_NorthMacedonia, "Macedonian", "North Macedonia"),
-    (0x083E, Language::Malay_BruneiDarussalam, "Malay", "Brunei Darussalam"),
-    (0x043E, Language::Malay_Malaysia, "Malay", "Malaysia"),
-    (0x044C, Language::Malayalam_India, "Malayalam", "India"),
-    (0x043A, Language::Maltese_Malta, "Maltese", "Malta"),
-    (0x0481, Language::Maori_NewZealand, "Maori", "New Zealand"),
-    (0x047A, Language::Mapudungun_Chile, "Mapudungun", "Chile"),
-    (0x044E, Language::Marathi_India, "Marathi", "India"),
-    (0x047C, Language::Mohawk_Mohawk, "Mohawk", "Mohawk"),
-    (0x0450, Language::Mongolian_Cyrillic_Mongolia, "Mongolian (Cyrillic)", "Mongolia"),
-    (0x0850, Language::Mongolian_Traditional_PeoplesRepublicOfChina, "Mongolian (Traditional)", "People's Republic of China"),
-    (0x0461, Language::Nepali_Nepal, "Nepali", "Nepal"),
-    (0x0414, Language::Norwegian_Bokmal_Norway, "Norwegian (Bokmal)", "Norway"),
-    (0x0814, Language::Norwegian_Nynorsk_Norway, "Norwegian (Nynorsk)", "Norway"),
-    (0x0482, Language::Occitan_France, "Occitan", "France"),
-    (0x0448, Language::Odia_India, "Odia (formerly Oriya)", "India"),
-    (0x0463, Language::Pashto_Afghanistan, "Pashto", "Afghanistan"),
-    (0x0415, Language::Polish_Poland, "Polish", "Poland"),
-    (0x0416, Language::Portuguese_Brazil, "Portuguese", "Brazil"),
-    (0x0816, Language::Portuguese_Portugal, "Portuguese", "Portugal"),
-    (0x0446, Language::Punjabi_India, "Punjabi", "India"),
-    (0x046B, Language::Quechua_Bolivia, "Quechua", "Bolivia"),
-    (0x086B, Language::Quechua_Ecuador, "Quechua", "Ecuador"),
-    (0x0C6B, Language::Quechua_Peru, "Quechua", "Peru"),
-    (0x0418, Language::Romanian_Romania, "Romanian", "Romania"),
-    (0x0417, Language::Romansh_Switzerland, "Romansh", "Switzerland"),
-    (0x0419, Language::Russian_Russia, "Russian", "Russia"),
-    (0x243B, Language::Sami_Inari_Finland, "Sami (Inari)", "Finland"),
-    (0x103B, Language::Sami_Lule_Norway, "Sami (Lule)", "Norway"),
-    (0x143B, Language::Sami_Lule_Sweden, "Sami (Lule)", "Sweden"),
-    (0x0C3B, Language::Sami_Northern_Finland, "Sami (Northern)", "Finland"),
-    (0x043B, Language::Sami_Northern_Norway, "Sami (Northern)", "Norway"),
-    (0x083B, Language::Sami_Northern_Sweden, "Sami (Northern)", "Sweden"),
-    (0x203B, Language::Sami_Skolt_Finland, "Sami (Skolt)", "Finland"),
-    (0x183B, Language::Sami_Southern_Norway, "Sami (Southern)", "Norway"),
-    (0x1C3B, Language::Sami_Southern_Sweden, "Sami (Southern)", "Sweden"),
-    (0x044F, Language::Sanskrit_India, "Sanskrit", "India"),
-    (0x1C1A, Language::Serbian_Cyrillic_BosniaAndHerzegovina, "Serbian (Cyrillic)", "Bosnia and Herzegovina"),
-    (0x0C1A, Language::Serbian_Cyrillic_Serbia, "Serbian (Cyrillic)", "Serbia"),
-    (0x181A, Language::Serbian_Latin_BosniaAndHerzegovina, "Serbian (Latin)", "Bosnia and Herzegovina"),
-    (0x081A, Language::Serbian_Latin_Serbia, "Serbian (Latin)", "Serbia"),
-    (0x046C, Language::SesothoSaLeboa_SouthAfrica, "Sesotho sa Leboa", "South Africa"),
-    (0x0432, Language::Setswana_SouthAfrica, "Setswana", "South Africa"),
-    (0x045B, Language::Sinhala_SriLanka, "Sinhala", "Sri Lanka"),
-    (0x041B, Language::Slovak_Slovakia, "Slovak", "Slovakia"),
-    (0x0424, Language::Slovenian_Slovenia, "Slovenian", "Slovenia"),
-    (0x2C0A, Language::Spanish_Argentina, "Spanish", "Argentina"),
-    (0x400A, Language::Spanish_Bolivia, "Spanish", "Bolivia"),
-    (0x340A, Language::Spanish_Chile, "Spanish", "Chile"),
-    (0x240A, Language::Spanish_Colombia, "Spanish", "Colombia"),
-    (0x140A, Language::Spanish_CostaRica, "Spanish", "Costa Rica"),
-    (0x1C0A, Language::Spanish_DominicanRepublic, "Spanish", "Dominican Republic"),
-    (0x300A, Language::Spanish_Ecuador, "Spanish", "Ecuador"),
-    (0x440A, Language::Spanish_ElSalvador, "Spanish", "El Salvador"),
-    (0x100A, Language::Spanish_Guatemala, "Spanish", "Guatemala"),
-    (0x480A, Language::Spanish_Honduras, "Spanish", "Honduras"),
-    (0x080A, Language::Spanish_Mexico, "Spanish", "Mexico"),
-    (0x4C0A, Language::Spanish_Nicaragua, "Spanish", "Nicaragua"),
-    (0x180A, Language::Spanish_Panama, "Spanish", "Panama"),
-    (0x3C0A, Language::Spanish_Paraguay, "Spanish", "Paraguay"),
-    (0x280A, Language::Spanish_Peru, "Spanish", "Peru"),
-    (0x500A, Language::Spanish_PuertoRico, "Spanish", "Puerto Rico"),
-    (0x0C0A, Language::Spanish_ModernSort_Spain, "Spanish (Modern Sort)", "Spain"),
-    (0x040A, Language::Spanish_TraditionalSort_Spain, "Spanish (Traditional Sort)", "Spain"),
-    (0x540A, Language::Spanish_UnitedStates, "Spanish", "United States"),
-    (0x380A, Language::Spanish_Uruguay, "Spanish", "Uruguay"),
-    (0x200A, Language::Spanish_Venezuela, "Spanish", "Venezuela"),
-    (0x081D, Language::Swedish_Finland, "Swedish", "Finland"),
-    (0x041D, Language::Swedish_Sweden, "Swedish", "Sweden"),
-    (0x045A, Language::Syriac_Syria, "Syriac", "Syria"),
-    (0x0428, Language::Tajik_Cyrillic_Tajikistan, "Tajik (Cyrillic)", "Tajikistan"),
-    (0x085F, Language::Tamazight_Latin_Algeria, "Tamazight (Latin)", "Algeria"),
-    (0x0449, Language::Tamil_India, "Tamil", "India"),
-    (0x0444, Language::Tatar_Russia, "Tatar", "Russia"),
-    (0x044A, Language::Telugu_India, "Telugu", "India"),
-    (0x041E, Language::Thai_Thailand, "Thai", "Thailand"),
-    (0x0451, Language::Tibetan_PRC, "Tibetan", "PRC"),
-    (0x041F, Language::Turkish_Turkey, "Turkish", "Turkey"),
-    (0x0442, Language::Turkmen_Turkmenistan, "Turkmen", "Turkmenistan"),
-    (0x0480, Language::Uighur_PRC, "Uighur", "PRC"),
-    (0x0422, Language::Ukrainian_Ukraine, "Ukrainian", "Ukraine"),
-    (0x042E, Language::UpperSorbian_Germany, "Upper Sorbian", "Germany"),
-    (0x0420, Language::Urdu_IslamicRepublicOfPakistan, "Urdu", "Islamic Republic of Pakistan"),
-    (0x0843, Language::Uzbek_Cyrillic_Uzbekistan, "Uzbek (Cyrillic)", "Uzbekistan"),
-    (0x0443, Language::Uzbek_Latin_Uzbekistan, "Uzbek (Latin)", "Uzbekistan"),
-    (0x042A, Language::Vietnamese_Vietnam, "Vietnamese", "Vietnam"),
-    (0x0452, Language::Welsh_UnitedKingdom, "Welsh", "United Kingdom"),
-    (0x0488, Language::Wolof_Senegal, "Wolof", "Senegal"),
-    (0x0485, Language::Yakut_Russia, "Yakut", "Russia"),
-    (0x0478, Language::Yi_PRC, "Yi", "PRC"),
-    (0x046A, Language::Yoruba_Nigeria, "Yoruba", "Nigeria"),
+static TABLE: &[(u16, Language, &str, &str, &str)] = &[
+    (0x0000, Language::Unknown, "Unknown", "Unknown", "und"),
+    (0x0436, Language::Afrikaans_SouthAfrica, "Afrikaans", "South Africa", "af-ZA"),
+    (0x041C, Language::Albanian_Albania, "Albanian", "Albania", "sq-AL"),
+    (0x0484, Language::Alsatian_France, "Alsatian", "France", "gsw-FR"),
+    (0x045E, Language::Amharic_Ethiopia, "Amharic", "Ethiopia", "am-ET"),
+    (0x1401, Language::Arabic_Algeria, "Arabic", "Algeria", "ar-DZ"),
+    (0x3C01, Language::Arabic_Bahrain, "Arabic", "Bahrain", "ar-BH"),
+    (0x0C01, Language::Arabic_Egypt, "Arabic", "Egypt", "ar-EG"),
+    (0x0801, Language::Arabic_Iraq, "Arabic", "Iraq", "ar-IQ"),
+    (0x2C01, Language::Arabic_Jordan, "Arabic", "Jordan", "ar-JO"),
+    (0x3401, Language::Arabic_Kuwait, "Arabic", "Kuwait", "ar-KW"),
+    (0x3001, Language::Arabic_Lebanon, "Arabic", "Lebanon", "ar-LB"),
+    (0x1001, Language::Arabic_Libya, "Arabic", "Libya", "ar-LY"),
+    (0x1801, Language::Arabic_Morocco, "Arabic", "Morocco", "ar-MA"),
+    (0x2001, Language::Arabic_Oman, "Arabic", "Oman", "ar-OM"),
+    (0x4001, Language::Arabic_Qatar, "Arabic", "Qatar", "ar-QA"),
+    (0x0401, Language::Arabic_SaudiArabia, "Arabic", "Saudi Arabia", "ar-SA"),
+    (0x2801, Language::Arabic_Syria, "Arabic", "Syria", "ar-SY"),
+    (0x1C01, Language::Arabic_Tunisia, "Arabic", "Tunisia", "ar-TN"),
+    (0x3801, Language::Arabic_UAE, "Arabic", "U.A.E.", "ar-AE"),
+    (0x2401, Language::Arabic_Yemen, "Arabic", "Yemen", "ar-YE"),
+    (0x042B, Language::Armenian_Armenia, "Armenian", "Armenia", "hy-AM"),
+    (0x044D, Language::Assamese_India, "Assamese", "India", "as-IN"),
+    (0x082C, Language::Azeri_Cyrillic_Azerbaijan, "Azeri (Cyrillic)", "Azerbaijan", "az-Cyrl-AZ"),
+    (0x042C, Language::Azeri_Latin_Azerbaijan, "Azeri (Latin)", "Azerbaijan", "az-Latn-AZ"),
+    (0x046D, Language::Bashkir_Russia, "Bashkir", "Russia", "ba-RU"),
+    (0x042D, Language::Basque_Basque, "Basque", "Basque", "eu-ES"),
+    (0x0423, Language::Belarusian_Belarus, "Belarusian", "Belarus", "be-BY"),
+    (0x0845, Language::Bengali_Bangladesh, "Bengali", "Bangladesh", "bn-BD"),
+    (0x0445, Language::Bengali_India, "Bengali", "India", "bn-IN"),
+    (0x201A, Language::Bosnian_Cyrillic_BosniaAndHerzegovina, "Bosnian (Cyrillic)", "Bosnia and Herzegovina", "bs-Cyrl-BA"),
+    (0x141A, Language::Bosnian_Latin_BosniaAndHerzegovina, "Bosnian (Latin)", "Bosnia and Herzegovina", "bs-Latn-BA"),
+    (0x047E, Language::Breton_France, "Breton", "France", "br-FR"),
+    (0x0402, Language::Bulgarian_Bulgaria, "Bulgarian", "Bulgaria", "bg-BG"),
+    (0x0403, Language::Catalan_Catalan, "Catalan", "Catalan", "ca-ES"),
+    (0x0C04, Language::Chinese_HongKongSAR, "Chinese", "Hong Kong S.A.R.", "zh-HK"),
+    (0x1404, Language::Chinese_MacaoSAR, "Chinese", "Macao S.A.R.", "zh-MO"),
+    (0x0804, Language::Chinese_PeoplesRepublicOfChina, "Chinese", "People's Republic of China", "zh-CN"),
+    (0x1004, Language::Chinese_Singapore, "Chinese", "Singapore", "zh-SG"),
+    (0x0404, Language::Chinese_Taiwan, "Chinese", "Taiwan", "zh-TW"),
+    (0x0483, Language::Corsican_France, "Corsican", "France", "co-FR"),
+    (0x041A, Language::Croatian_Croatia, "Croatian", "Croatia", "hr-HR"),
+    (0x101A, Language::Croatian_Latin_BosniaAndHerzegovina, "Croatian (Latin)", "Bosnia and Herzegovina", "hr-BA"),
+    (0x0405, Language::Czech_CzechRepublic, "Czech", "Czech Republic", "cs-CZ"),
+    (0x0406, Language::Danish_Denmark, "Danish", "Denmark", "da-DK"),
+    (0x048C, Language::Dari_Afghanistan, "Dari", "Afghanistan", "prs-AF"),
+    (0x0465, Language::Divehi_Maldives, "Divehi", "Maldives", "dv-MV"),
+    (0x0813, Language::Dutch_Belgium, "Dutch", "Belgium", "nl-BE"),
+    (0x0413, Language::Dutch_Netherlands, "Dutch", "Netherlands", "nl-NL"),
+    (0x0C09, Language::English_Australia, "English", "Australia", "en-AU"),
+    (0x2809, Language::English_Belize, "English", "Belize", "en-BZ"),
+    (0x1009, Language::English_Canada, "English", "Canada", "en-CA"),
+    (0x2409, Language::English_Caribbean, "English", "Caribbean", "en-029"),
+    (0x4009, Language::English_India, "English", "India", "en-IN"),
+    (0x1809, Language::English_Ireland, "English", "Ireland", "en-IE"),
+    (0x2009, Language::English_Jamaica, "English", "Jamaica", "en-JM"),
+    (0x4409, Language::English_Malaysia, "English", "Malaysia", "en-MY"),
+    (0x1409, Language::English_NewZealand, "English", "New Zealand", "en-NZ"),
+    (0x3409, Language::English_RepublicOfThePhilippines, "English", "Republic of the Philippines", "en-PH"),
+    (0x4809, Language::English_Singapore, "English", "Singapore", "en-SG"),
+    (0x1C09, Language::English_SouthAfrica, "English", "South Africa", "en-ZA"),
+    (0x2C09, Language::English_TrinidadAndTobago, "English", "Trinidad and Tobago", "en-TT"),
+    (0x0809, Language::English_UnitedKingdom, "English", "United Kingdom", "en-GB"),
+    (0x0409, Language::English_UnitedStates, "English", "United States", "en-US"),
+    (0x3009, Language::English_Zimbabwe, "English", "Zimbabwe", "en-ZW"),
+    (0x0425, Language::Estonian_Estonia, "Estonian", "Estonia", "et-EE"),
+    (0x0438, Language::Faroese_FaroeIslands, "Faroese", "Faroe Islands", "fo-FO"),
+    (0x0464, Language::Filipino_Philippines, "Filipino", "Philippines", "fil-PH"),
+    (0x040B, Language::Finnish_Finland, "Finnish", "Finland", "fi-FI"),
+    (0x080C, Language::French_Belgium, "French", "Belgium", "fr-BE"),
+    (0x0C0C, Language::French_Canada, "French", "Canada", "fr-CA"),
+    (0x040C, Language::French_France, "French", "France", "fr-FR"),
+    (0x140c, Language::French_Luxembourg, "French", "Luxembourg", "fr-LU"),
+    (0x180C, Language::French_PrincipalityOfMonaco, "French", "Principality of Monaco", "fr-MC"),
+    (0x100C, Language::French_Switzerland, "French", "Switzerland", "fr-CH"),
+    (0x0462, Language::Frisian_Netherlands, "Frisian", "Netherlands", "fy-NL"),
+    (0x0456, Language::Galician_Galician, "Galician", "Galician", "gl-ES"),
+    (0x0437, Language::Georgian_Georgia, "Georgian", "Georgia", "ka-GE"),
+    (0x0C07, Language::German_Austria, "German", "Austria", "de-AT"),
+    (0x0407, Language::German_Germany, "German", "Germany", "de-DE"),
+    (0x1407, Language::German_Liechtenstein, "German", "Liechtenstein", "de-LI"),
+    (0x1007, Language::German_Luxembourg, "German", "Luxembourg", "de-LU"),
+    (0x0807, Language::German_Switzerland, "German", "Switzerland", "de-CH"),
+    (0x0408, Language::Greek_Greece, "Greek", "Greece", "el-GR"),
+    (0x046F, Language::Greenlandic_Greenland, "Greenlandic", "Greenland", "kl-GL"),
+    (0x0447, Language::Gujarati_India, "Gujarati", "India", "gu-IN"),
+    (0x0468, Language::Hausa_Latin_Nigeria, "Hausa (Latin)", "Nigeria", "ha-Latn-NG"),
+    (0x040D, Language::Hebrew_Israel, "Hebrew", "Israel", "he-IL"),
+    (0x0439, Language::Hindi_India, "Hindi", "India", "hi-IN"),
+    (0x040E, Language::Hungarian_Hungary, "Hungarian", "Hungary", "hu-HU"),
+    (0x040F, Language::Icelandic_Iceland, "Icelandic", "Iceland", "is-IS"),
+    (0x0470, Language::Igbo_Nigeria, "Igbo", "Nigeria", "ig-NG"),
+    (0x0421, Language::Indonesian_Indonesia, "Indonesian", "Indonesia", "id-ID"),
+    (0x045D, Language::Inuktitut_Canada, "Inuktitut", "Canada", "iu-Cans-CA"),
+    (0x085D, Language::Inuktitut_Latin_Canada, "Inuktitut (Latin)", "Canada", "iu-Latn-CA"),
+    (0x083C, Language::Irish_Ireland, "Irish", "Ireland", "ga-IE"),
+    (0x0434, Language::isiXhosa_SouthAfrica, "isiXhosa", "South Africa", "xh-ZA"),
+    (0x0435, Language::isiZulu_SouthAfrica, "isiZulu", "South Africa", "zu-ZA"),
+    (0x0410, Language::Italian_Italy, "Italian", "Italy", "it-IT"),
+    (0x0810, Language::Italian_Switzerland, "Italian", "Switzerland", "it-CH"),
+    (0x0411, Language::Japanese_Japan, "Japanese", "Japan", "ja-JP"),
+    (0x044B, Language::Kannada_India, "Kannada", "India", "kn-IN"),
+    (0x043F, Language::Kazakh_Kazakhstan, "Kazakh", "Kazakhstan", "kk-KZ"),
+    (0x0453, Language::Khmer_Cambodia, "Khmer", "Cambodia", "km-KH"),
+    (0x0486, Language::Kiche_Guatemala, "K'iche", "Guatemala", "qut-GT"),
+    (0x0487, Language::Kinyarwanda_Rwanda, "Kinyarwanda", "Rwanda", "rw-RW"),
+    (0x0441, Language::Kiswahili_Kenya, "Kiswahili", "Kenya", "sw-KE"),
+    (0x0457, Language::Konkani_India, "Konkani", "India", "kok-IN"),
+    (0x0412, Language::Korean_Korea, "Korean", "Korea", "ko-KR"),
+    (0x0440, Language::Kyrgyz_Kyrgyzstan, "Kyrgyz", "Kyrgyzstan", "ky-KG"),
+    (0x0454, Language::Lao_LaoPDR, "Lao", "Lao P.D.R.", "lo-LA"),
+    (0x0426, Language::Latvian_Latvia, "Latvian", "Latvia", "lv-LV"),
+    (0x0427, Language::Lithuanian_Lithuania, "Lithuanian", "Lithuania", "lt-LT"),
+    (0x082E, Language::LowerSorbian_Germany, "Lower Sorbian", "Germany", "dsb-DE"),
+    (0x046E, Language::Luxembourgish_Luxembourg, "Luxembourgish", "Luxembourg", "lb-LU"),
+    (0x042F, Language::Macedonian_NorthMacedonia, "Macedonian", "North Macedonia", "mk-MK"),
+    (0x083E, Language::Malay_BruneiDarussalam, "Malay", "Brunei Darussalam", "ms-BN"),
+    (0x043E, Language::Malay_Malaysia, "Malay", "Malaysia", "ms-MY"),
+    (0x044C, Language::Malayalam_India, "Malayalam", "India", "ml-IN"),
+    (0x043A, Language::Maltese_Malta, "Maltese", "Malta", "mt-MT"),
+    (0x0481, Language::Maori_NewZealand, "Maori", "New Zealand", "mi-NZ"),
+    (0x047A, Language::Mapudungun_Chile, "Mapudungun", "Chile", "arn-CL"),
+    (0x044E, Language::Marathi_India, "Marathi", "India", "mr-IN"),
+    (0x047C, Language::Mohawk_Mohawk, "Mohawk", "Mohawk", "moh-CA"),
+    (0x0450, Language::Mongolian_Cyrillic_Mongolia, "Mongolian (Cyrillic)", "Mongolia", "mn-MN"),
+    (0x0850, Language::Mongolian_Traditional_PeoplesRepublicOfChina, "Mongolian (Traditional)", "People's Republic of China", "mn-Mong-CN"),
+    (0x0461, Language::Nepali_Nepal, "Nepali", "Nepal", "ne-NP"),
+    (0x0414, Language::Norwegian_Bokmal_Norway, "Norwegian (Bokmal)", "Norway", "nb-NO"),
+    (0x0814, Language::Norwegian_Nynorsk_Norway, "Norwegian (Nynorsk)", "Norway", "nn-NO"),
+    (0x0482, Language::Occitan_France, "Occitan", "France", "oc-FR"),
+    (0x0448, Language::Odia_India, "Odia (formerly Oriya)", "India", "or-IN"),
+    (0x0463, Language::Pashto_Afghanistan, "Pashto", "Afghanistan", "ps-AF"),
+    (0x0415, Language::Polish_Poland, "Polish", "Poland", "pl-PL"),
+    (0x0416, Language::Portuguese_Brazil, "Portuguese", "Brazil", "pt-BR"),
+    (0x0816, Language::Portuguese_Portugal, "Portuguese", "Portugal", "pt-PT"),
+    (0x0446, Language::Punjabi_India, "Punjabi", "India", "pa-IN"),
+    (0x046B, Language::Quechua_Bolivia, "Quechua", "Bolivia", "quz-BO"),
+    (0x086B, Language::Quechua_Ecuador, "Quechua", "Ecuador", "quz-EC"),
+    (0x0C6B, Language::Quechua_Peru, "Quechua", "Peru", "quz-PE"),
+    (0x0418, Language::Romanian_Romania, "Romanian", "Romania", "ro-RO"),
+    (0x0417, Language::Romansh_Switzerland, "Romansh", "Switzerland", "rm-CH"),
+    (0x0419, Language::Russian_Russia, "Russian", "Russia", "ru-RU"),
+    (0x243B, Language::Sami_Inari_Finland, "Sami (Inari)", "Finland", "smn-FI"),
+    (0x103B, Language::Sami_Lule_Norway, "Sami (Lule)", "Norway", "smj-NO"),
+    (0x143B, Language::Sami_Lule_Sweden, "Sami (Lule)", "Sweden", "smj-SE"),
+    (0x0C3B, Language::Sami_Northern_Finland, "Sami (Northern)", "Finland", "se-FI"),
+    (0x043B, Language::Sami_Northern_Norway, "Sami (Northern)", "Norway", "se-NO"),
+    (0x083B, Language::Sami_Northern_Sweden, "Sami (Northern)", "Sweden", "se-SE"),
+    (0x203B, Language::Sami_Skolt_Finland, "Sami (Skolt)", "Finland", "sms-FI"),
+    (0x183B, Language::Sami_Southern_Norway, "Sami (Southern)", "Norway", "sma-NO"),
+    (0x1C3B, Language::Sami_Southern_Sweden, "Sami (Southern)", "Sweden", "sma-SE"),
+    (0x044F, Language::Sanskrit_India, "Sanskrit", "India", "sa-IN"),
+    (0x1C1A, Language::Serbian_Cyrillic_BosniaAndHerzegovina, "Serbian (Cyrillic)", "Bosnia and Herzegovina", "sr-Cyrl-BA"),
+    (0x0C1A, Language::Serbian_Cyrillic_Serbia, "Serbian (Cyrillic)", "Serbia", "sr-Cyrl-CS"),
+    (0x181A, Language::Serbian_Latin_BosniaAndHerzegovina, "Serbian (Latin)", "Bosnia and Herzegovina", "sr-Latn-BA"),
+    (0x081A, Language::Serbian_Latin_Serbia, "Serbian (Latin)", "Serbia", "sr-Latn-CS"),
+    (0x046C, Language::SesothoSaLeboa_SouthAfrica, "Sesotho sa Leboa", "South Africa", "nso-ZA"),
+    (0x0432, Language::Setswana_SouthAfrica, "Setswana", "South Africa", "tn-ZA"),
+    (0x045B, Language::Sinhala_SriLanka, "Sinhala", "Sri Lanka", "si-LK"),
+    (0x041B, Language::Slovak_Slovakia, "Slovak", "Slovakia", "sk-SK"),
+    (0x0424, Language::Slovenian_Slovenia, "Slovenian", "Slovenia", "sl-SI"),
+    (0x2C0A, Language::Spanish_Argentina, "Spanish", "Argentina", "es-AR"),
+    (0x400A, Language::Spanish_Bolivia, "Spanish", "Bolivia", "es-BO"),
+    (0x340A, Language::Spanish_Chile, "Spanish", "Chile", "es-CL"),
+    (0x240A, Language::Spanish_Colombia, "Spanish", "Colombia", "es-CO"),
+    (0x140A, Language::Spanish_CostaRica, "Spanish", "Costa Rica", "es-CR"),
+    (0x1C0A, Language::Spanish_DominicanRepublic, "Spanish", "Dominican Republic", "es-DO"),
+    (0x300A, Language::Spanish_Ecuador, "Spanish", "Ecuador", "es-EC"),
+    (0x440A, Language::Spanish_ElSalvador, "Spanish", "El Salvador", "es-SV"),
+    (0x100A, Language::Spanish_Guatemala, "Spanish", "Guatemala", "es-GT"),
+    (0x480A, Language::Spanish_Honduras, "Spanish", "Honduras", "es-HN"),
+    (0x080A, Language::Spanish_Mexico, "Spanish", "Mexico", "es-MX"),
+    (0x4C0A, Language::Spanish_Nicaragua, "Spanish", "Nicaragua", "es-NI"),
+    (0x180A, Language::Spanish_Panama, "Spanish", "Panama", "es-PA"),
+    (0x3C0A, Language::Spanish_Paraguay, "Spanish", "Paraguay", "es-PY"),
+    (0x280A, Language::Spanish_Peru, "Spanish", "Peru", "es-PE"),
+    (0x500A, Language::Spanish_PuertoRico, "Spanish", "Puerto Rico", "es-PR"),
+    (0x0C0A, Language::Spanish_ModernSort_Spain, "Spanish (Modern Sort)", "Spain", "es-ES"),
+    (0x040A, Language::Spanish_TraditionalSort_Spain, "Spanish (Traditional Sort)", "Spain", "es-ES"),
+    (0x540A, Language::Spanish_UnitedStates, "Spanish", "United States", "es-US"),
+    (0x380A, Language::Spanish_Uruguay, "Spanish", "Uruguay", "es-UY"),
+    (0x200A, Language::Spanish_Venezuela, "Spanish", "Venezuela", "es-VE"),
+    (0x081D, Language::Swedish_Finland, "Swedish", "Finland", "sv-FI"),
+    (0x041D, Language::Swedish_Sweden, "Swedish", "Sweden", "sv-SE"),
+    (0x045A, Language::Syriac_Syria, "Syriac", "Syria", "syr-SY"),
+    (0x0428, Language::Tajik_Cyrillic_Tajikistan, "Tajik (Cyrillic)", "Tajikistan", "tg-Cyrl-TJ"),
+    (0x085F, Language::Tamazight_Latin_Algeria, "Tamazight (Latin)", "Algeria", "tzm-Latn-DZ"),
+    (0x0449, Language::Tamil_India, "Tamil", "India", "ta-IN"),
+    (0x0444, Language::Tatar_Russia, "Tatar", "Russia", "tt-RU"),
+    (0x044A, Language::Telugu_India, "Telugu", "India", "te-IN"),
+    (0x041E, Language::Thai_Thailand, "Thai", "Thailand", "th-TH"),
+    (0x0451, Language::Tibetan_PRC, "Tibetan", "PRC", "bo-CN"),
+    (0x041F, Language::Turkish_Turkey, "Turkish", "Turkey", "tr-TR"),
+    (0x0442, Language::Turkmen_Turkmenistan, "Turkmen", "Turkmenistan", "tk-TM"),
+    (0x0480, Language::Uighur_PRC, "Uighur", "PRC", "ug-CN"),
+    (0x0422, Language::Ukrainian_Ukraine, "Ukrainian", "Ukraine", "uk-UA"),
+    (0x042E, Language::UpperSorbian_Germany, "Upper Sorbian", "Germany", "hsb-DE"),
+    (0x0420, Language::Urdu_IslamicRepublicOfPakistan, "Urdu", "Islamic Republic of Pakistan", "ur-PK"),
+    (0x0843, Language::Uzbek_Cyrillic_Uzbekistan, "Uzbek (Cyrillic)", "Uzbekistan", "uz-Cyrl-UZ"),
+    (0x0443, Language::Uzbek_Latin_Uzbekistan, "Uzbek (Latin)", "Uzbekistan", "uz-Latn-UZ"),
+    (0x042A, Language::Vietnamese_Vietnam, "Vietnamese", "Vietnam", "vi-VN"),
+    (0x0452, Language::Welsh_UnitedKingdom, "Welsh", "United Kingdom", "cy-GB"),
+    (0x0488, Language::Wolof_Senegal, "Wolof", "Senegal", "wo-SN"),
+    (0x0485, Language::Yakut_Russia, "Yakut", "Russia", "sah-RU"),
+    (0x0478, Language::Yi_PRC, "Yi", "PRC", "ii-CN"),
+    (0x046A, Language::Yoruba_Nigeria, "Yoruba", "Nigeria", "yo-NG"),
+];
+
+// https://docs.microsoft.com/en-us/typography/opentype/spec/name#macintosh-language-ids
+#[rustfmt::skip]
+static MAC_TABLE: &[(u16, &str)] = &[
+    (0, "en"),
+    (1, "fr"),
+    (2, "de"),
+    (3, "it"),
+    (4, "nl"),
+    (5, "sv"),
+    (6, "es"),
+    (7, "da"),
+    (8, "pt"),
+    (9, "nb"),
+    (10, "he"),
+    (11, "ja"),
+    (12, "ar"),
+    (13, "fi"),
+    (14, "el"),
+    (15, "is"),
+    (16, "mt"),
+    (17, "tr"),
+    (18, "hr"),
+    (19, "zh-Hant"),
+    (20, "ur"),
+    (21, "hi"),
+    (22, "th"),
+    (23, "ko"),
+    (24, "lt"),
+    (25, "pl"),
+    (26, "hu"),
+    (27, "et"),
+    (28, "lv"),
+    (29, "se"),
+    (30, "fo"),
+    (31, "fa"),
+    (32, "ru"),
+    (33, "zh-Hans"),
+    (34, "nl-BE"),
+    (35, "ga"),
+    (36, "sq"),
+    (37, "ro"),
+    (38, "cs"),
+    (39, "sk"),
+    (40, "sl"),
+    (41, "yi"),
+    (42, "sr"),
+    (43, "mk"),
+    (44, "bg"),
+    (45, "uk"),
+    (46, "be"),
+    (47, "uz"),
+    (48, "kk"),
+    (49, "az-Cyrl"),
+    (50, "az-Arab"),
+    (51, "hy"),
+    (52, "ka"),
+    (53, "ro-MD"),
+    (54, "ky"),
+    (55, "tg"),
+    (56, "tk"),
+    (57, "mn-Mong"),
+    (58, "mn-Cyrl"),
+    (59, "ps"),
+    (60, "ku"),
+    (61, "ks"),
+    (62, "sd"),
+    (63, "bo"),
+    (64, "ne"),
+    (65, "sa"),
+    (66, "mr"),
+    (67, "bn"),
+    (68, "as"),
+    (69, "gu"),
+    (70, "pa"),
+    (71, "or"),
+    (72, "ml"),
+    (73, "kn"),
+    (74, "ta"),
+    (75, "te"),
+    (76, "si"),
+    (77, "my"),
+    (78, "km"),
+    (79, "lo"),
+    (80, "vi"),
+    (81, "id"),
+    (82, "tl"),
+    (83, "ms"),
+    (84, "ms-Arab"),
+    (85, "am"),
+    (86, "ti"),
+    (87, "om"),
+    (88, "so"),
+    (89, "sw"),
+    (90, "rw"),
+    (91, "rn"),
+    (92, "ny"),
+    (93, "mg"),
+    (94, "eo"),
+    (128, "cy"),
+    (129, "eu"),
+    (130, "ca"),
+    (131, "la"),
+    (132, "qu"),
+    (133, "gn"),
+    (134, "ay"),
+    (135, "tt"),
+    (136, "ug"),
+    (137, "dz"),
+    (138, "jv"),
+    (139, "su"),
+    (140, "gl"),
+    (141, "af"),
+    (142, "br"),
+    (143, "iu"),
+    (144, "gd"),
+    (145, "gv"),
+    (146, "ga"),
+    (147, "to"),
+    (148, "el-polyton"),
+    (149, "kl"),
+    (150, "az-Latn"),
+];
+
+// Default ISO 15924 scripts of languages. Sorted by the language subtag.
+#[rustfmt::skip]
+static LANGUAGE_SCRIPTS: &[(&str, &str)] = &[
+    ("af", "Latn"),
+    ("am", "Ethi"),
+    ("ar", "Arab"),
+    ("as", "Beng"),
+    ("az", "Latn"),
+    ("ba", "Cyrl"),
+    ("be", "Cyrl"),
+    ("bg", "Cyrl"),
+    ("bn", "Beng"),
+    ("bo", "Tibt"),
+    ("br", "Latn"),
+    ("bs", "Latn"),
+    ("ca", "Latn"),
+    ("chr", "Cher"),
+    ("crh", "Latn"),
+    ("cs", "Latn"),
+    ("cv", "Cyrl"),
+    ("cy", "Latn"),
+    ("da", "Latn"),
+    ("de", "Latn"),
+    ("dv", "Thaa"),
+    ("dz", "Tibt"),
+    ("ee", "Latn"),
+    ("el", "Grek"),
+    ("en", "Latn"),
+    ("eo", "Latn"),
+    ("es", "Latn"),
+    ("et", "Latn"),
+    ("eu", "Latn"),
+    ("fa", "Arab"),
+    ("ff", "Latn"),
+    ("fi", "Latn"),
+    ("fil", "Latn"),
+    ("fo", "Latn"),
+    ("fr", "Latn"),
+    ("fy", "Latn"),
+    ("ga", "Latn"),
+    ("gd", "Latn"),
+    ("gl", "Latn"),
+    ("gn", "Latn"),
+    ("gu", "Gujr"),
+    ("gv", "Latn"),
+    ("ha", "Latn"),
+    ("haw", "Latn"),
+    ("he", "Hebr"),
+    ("hi", "Deva"),
+    ("hr", "Latn"),
+    ("ht", "Latn"),
+    ("hu", "Latn"),
+    ("hy", "Armn"),
+    ("id", "Latn"),
+    ("ig", "Latn"),
+    ("is", "Latn"),
+    ("it", "Latn"),
+    ("iu", "Cans"),
+    ("ja", "Jpan"),
+    ("jv", "Latn"),
+    ("ka", "Geor"),
+    ("kab", "Latn"),
+    ("kk", "Cyrl"),
+    ("kl", "Latn"),
+    ("km", "Khmr"),
+    ("kn", "Knda"),
+    ("ko", "Kore"),
+    ("kok", "Deva"),
+    ("ks", "Arab"),
+    ("ku", "Latn"),
+    ("kw", "Latn"),
+    ("ky", "Cyrl"),
+    ("la", "Latn"),
+    ("lb", "Latn"),
+    ("lg", "Latn"),
+    ("ln", "Latn"),
+    ("lo", "Laoo"),
+    ("lt", "Latn"),
+    ("lv", "Latn"),
+    ("mai", "Deva"),
+    ("mg", "Latn"),
+    ("mi", "Latn"),
+    ("mk", "Cyrl"),
+    ("ml", "Mlym"),
+    ("mn", "Cyrl"),
+    ("mni", "Beng"),
+    ("moh", "Latn"),
+    ("mr", "Deva"),
+    ("ms", "Latn"),
+    ("mt", "Latn"),
+    ("my", "Mymr"),
+    ("nb", "Latn"),
+    ("ne", "Deva"),
+    ("nl", "Latn"),
+    ("nn", "Latn"),
+    ("no", "Latn"),
+    ("nv", "Latn"),
+    ("ny", "Latn"),
+    ("oc", "Latn"),
+    ("om", "Latn"),
+    ("or", "Orya"),
+    ("pa", "Guru"),
+    ("pl", "Latn"),
+    ("ps", "Arab"),
+    ("pt", "Latn"),
+    ("rm", "Latn"),
+    ("rn", "Latn"),
+    ("ro", "Latn"),
+    ("ru", "Cyrl"),
+    ("rw", "Latn"),
+    ("sa", "Deva"),
+    ("sah", "Cyrl"),
+    ("sat", "Olck"),
+    ("sd", "Arab"),
+    ("se", "Latn"),
+    ("sg", "Latn"),
+    ("si", "Sinh"),
+    ("sk", "Latn"),
+    ("sl", "Latn"),
+    ("sm", "Latn"),
+    ("sn", "Latn"),
+    ("so", "Latn"),
+    ("sq", "Latn"),
+    ("sr", "Cyrl"),
+    ("ss", "Latn"),
+    ("st", "Latn"),
+    ("sv", "Latn"),
+    ("sw", "Latn"),
+    ("syr", "Syrc"),
+    ("ta", "Taml"),
+    ("te", "Telu"),
+    ("tg", "Cyrl"),
+    ("th", "Thai"),
+    ("ti", "Ethi"),
+    ("tk", "Latn"),
+    ("tl", "Latn"),
+    ("tn", "Latn"),
+    ("to", "Latn"),
+    ("tr", "Latn"),
+    ("ts", "Latn"),
+    ("tt", "Cyrl"),
+    ("tzm", "Latn"),
+    ("ug", "Arab"),
+    ("uk", "Cyrl"),
+    ("ur", "Arab"),
+    ("uz", "Latn"),
+    ("ve", "Latn"),
+    ("vi", "Latn"),
+    ("wo", "Latn"),
+    ("xh", "Latn"),
+    ("yi", "Hebr"),
+    ("yo", "Latn"),
+    ("yue", "Hant"),
+    ("zh", "Hani"),
+    ("zu", "Latn"),
 ];
 
 /// A [`Name`](crate::name::Name) language.
@@ -443,6 +723,186 @@ impl Language {
     pub fn region(&self) -> &'static str {
         TABLE[*self as usize].3
     }
+
+    /// Returns a [BCP 47](https://www.rfc-editor.org/info/bcp47) language tag.
+    ///
+    /// Returns `und` for `Language::Unknown`.
+    pub fn bcp47_tag(&self) -> &'static str {
+        TABLE[*self as usize].4
+    }
+}
+
+/// Returns a BCP 47 language tag for a Macintosh language ID.
+pub(crate) fn mac_language_tag(id: u16) -> Option<&'static str> {
+    MAC_TABLE.iter().find(|v| v.0 == id).map(|v| v.1)
+}
+
+/// Parsed BCP 47 language tag subtags that are relevant for language matching.
+///
+/// All subtags are stored in lowercase. Unused bytes are zeros.
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub(crate) struct LanguageTagParts {
+    pub language: [u8; 8],
+    pub script: [u8; 4],
+    pub region: [u8; 3],
+}
+
+impl LanguageTagParts {
+    /// Parses language, script and region subtags.
+    ///
+    /// Both `-` and `_` are accepted as separators. Everything after the region is ignored.
+    pub fn parse(tag: impl Iterator<Item = char>) -> Self {
+        let mut parts = LanguageTagParts::default();
+        let mut subtag = [0u8; 8];
+        let mut len = 0;
+        let mut index = 0;
+        for c in tag.chain(core::iter::once('-')) {
+            if c == '-' || c == '_' {
+                if !parts.push_subtag(index, &subtag[..len]) {
+                    break;
+                }
+
+                index += 1;
+                len = 0;
+            } else if len < subtag.len() && c.is_ascii_alphanumeric() {
+                subtag[len] = c.to_ascii_lowercase() as u8;
+                len += 1;
+            } else {
+                // Invalid or too long subtag. Ignore everything after it.
+                parts.push_subtag(index, &subtag[..len]);
+                break;
+            }
+        }
+
+        parts
+    }
+
+    fn push_subtag(&mut self, index: usize, subtag: &[u8]) -> bool {
+        let is_alpha = subtag.iter().all(|c| c.is_ascii_alphabetic());
+        let is_digit = subtag.iter().all(|c| c.is_ascii_digit());
+        if index == 0 {
+            if subtag.len() < 2 || !is_alpha {
+                return false;
+            }
+
+            self.language[..subtag.len()].copy_from_slice(subtag);
+            true
+        } else if subtag.len() == 4 && is_alpha && self.script[0] == 0 && self.region[0] == 0 {
+            self.script.copy_from_slice(subtag);
+            true
+        } else if (subtag.len() == 2 && is_alpha) || (subtag.len() == 3 && is_digit) {
+            self.region[..subtag.len()].copy_from_slice(subtag);
+            // Region is the last subtag we're interested in.
+            false
+        } else {
+            false
+        }
+    }
+
+    /// Checks that the language subtag is set.
+    pub fn is_valid(&self) -> bool {
+        self.language[0] != 0
+    }
+
+    /// Returns an explicit script or the one implied by a language and a region.
+    ///
+    /// Only Chinese is handled, since this is the only language
+    /// with script-specific names being common in fonts.
+    pub fn script_or_implied(&self) -> Option<[u8; 4]> {
+        if self.script[0] != 0 {
+            return Some(self.script);
+        }
+
+        if &self.language[..3] != b"zh\0" || self.region[0] == 0 {
+            return None;
+        }
+
+        match &self.region {
+            b"tw\0" | b"hk\0" | b"mo\0" => Some(*b"hant"),
+            _ => Some(*b"hans"),
+        }
+    }
+
+    /// Parses a `meta` table ScriptLangTag, which can also be just a script subtag.
+    pub fn parse_script_lang_tag(tag: &str) -> Self {
+        if tag.len() == 4 && tag.bytes().all(|c| c.is_ascii_alphabetic()) {
+            let mut parts = LanguageTagParts::default();
+            parts.script.copy_from_slice(tag.as_bytes());
+            parts.script.make_ascii_lowercase();
+            parts
+        } else {
+            Self::parse(tag.chars())
+        }
+    }
+
+    /// Returns the default ISO 15924 script of the language, like `Latn` for `en`.
+    pub fn language_script(&self) -> Option<&'static str> {
+        let len = self.language.iter().position(|c| *c == 0);
+        let language = self.language.get(..len.unwrap_or(self.language.len()))?;
+        let language = core::str::from_utf8(language).ok()?;
+        LANGUAGE_SCRIPTS
+            .binary_search_by(|(l, _)| l.cmp(&language))
+            .ok()
+            .and_then(|index| LANGUAGE_SCRIPTS.get(index))
+            .map(|(_, script)| *script)
+    }
+
+    /// Returns an explicit, implied or default language script.
+    ///
+    /// The script is lowercased, like all subtags.
+    pub fn likely_script(&self) -> Option<[u8; 4]> {
+        if let Some(script) = self.script_or_implied() {
+            return Some(script);
+        }
+
+        let mut script = [0; 4];
+        script.copy_from_slice(self.language_script()?.as_bytes());
+        script.make_ascii_lowercase();
+        Some(script)
+    }
+
+    /// Checks that all subtags set in the current tag match the `locale` ones.
+    ///
+    /// The `und` language matches any language.
+    /// The script is matched against the [likely script](Self::likely_script) of the `locale`
+    /// and never matches when it's unknown.
+    pub fn covers(&self, locale: &Self) -> bool {
+        if !self.is_valid() && self.script[0] == 0 {
+            return false;
+        }
+
+        if self.is_valid() && &self.language[..4] != b"und\0" && self.language != locale.language {
+            return false;
+        }
+
+        if self.script[0] != 0 && locale.likely_script() != Some(self.script) {
+            return false;
+        }
+
+        self.region[0] == 0 || self.region == locale.region
+    }
+
+    /// Returns how well the `other` tag matches the current one.
+    ///
+    /// Returns 0 when languages are different.
+    pub fn match_score(&self, other: &Self) -> u8 {
+        if !self.is_valid() || self.language != other.language {
+            return 0;
+        }
+
+        let mut score = 1;
+        score += match (self.script_or_implied(), other.script_or_implied()) {
+            (Some(a), Some(b)) if a == b => 4,
+            (Some(_), Some(_)) => 0,
+            _ => 2,
+        };
+
+        if self.region[0] != 0 && self.region == other.region {
+            score += 1;
+        }
+
+        score
+    }
 }
 
 impl core::fmt::Display for Language {
@@ -59,16 +59,25 @@ macro_rules! try_opt_or {
 
 #[cfg(feature = "apple-layout")]
 mod aat;
+pub mod coverage;
+mod encoding;
 #[cfg(feature = "opentype-layout")]
 mod ggg;
 mod language;
+#[cfg(feature = "unicode-normalization")]
+pub mod normalization;
+pub mod opentype_tags;
 mod parser;
+#[cfg(feature = "rasterizer")]
+pub mod raster;
 mod tables;
 #[cfg(feature = "variable-fonts")]
-mod var_store;
+pub mod var_store;
 
 use head::IndexToLocationFormat;
-pub use parser::{Fixed, FromData, LazyArray16, LazyArray32, LazyArrayIter16, LazyArrayIter32};
+pub use parser::{
+    Fixed, FromData, LazyArray16, LazyArray32, LazyArrayIter16, LazyArrayIter32, U24,
+};
 use parser::{NumFrom, Offset, Offset32, Stream, TryNumFrom};
 
 #[cfg(feature = "variable-fonts")]
@@ -79,12 +88,13 @@ pub use name::{name_id, PlatformId};
 pub use os2::{Permissions, ScriptMetrics, Style, UnicodeRanges, Weight, Width};
 pub use tables::CFFError;
 #[cfg(feature = "apple-layout")]
-pub use tables::{ankr, feat, kerx, morx, trak};
+pub use tables::{ankr, feat, kerx, lcar, morx, trak};
 #[cfg(feature = "variable-fonts")]
 pub use tables::{avar, cff2, fvar, gvar, hvar, mvar};
 pub use tables::{cbdt, cblc, cff1 as cff, vhea};
 pub use tables::{
-    cmap, colr, cpal, glyf, head, hhea, hmtx, kern, loca, maxp, name, os2, post, sbix, svg, vorg,
+    cmap, colr, cpal, gasp, glyf, hdmx, head, hhea, hmtx, kern, loca, maxp, meta, name, os2, post,
+    sbix, svg, vdmx, vorg,
 };
 #[cfg(feature = "opentype-layout")]
 pub use tables::{gdef, gpos, gsub, math};
@@ -536,6 +546,44 @@ impl OutlineBuilder for DummyOutline {
     fn close(&mut self) {}
 }
 
+#[cfg(feature = "opentype-layout")]
+struct TranslatedOutline<'a> {
+    builder: &'a mut dyn OutlineBuilder,
+    dx: f32,
+    dy: f32,
+}
+
+#[cfg(feature = "opentype-layout")]
+impl OutlineBuilder for TranslatedOutline<'_> {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.builder.move_to(x + self.dx, y + self.dy);
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.builder.line_to(x + self.dx, y + self.dy);
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        self.builder
+            .quad_to(x1 + self.dx, y1 + self.dy, x + self.dx, y + self.dy);
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        self.builder.curve_to(
+            x1 + self.dx,
+            y1 + self.dy,
+            x2 + self.dx,
+            y2 + self.dy,
+            x + self.dx,
+            y + self.dy,
+        );
+    }
+
+    fn close(&mut self) {
+        self.builder.close();
+    }
+}
+
 /// A glyph raster image format.
 #[allow(missing_docs)]
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
@@ -853,15 +901,19 @@ pub struct RawFaceTables<'a> {
     pub cpal: Option<&'a [u8]>,
     pub ebdt: Option<&'a [u8]>,
     pub eblc: Option<&'a [u8]>,
+    pub gasp: Option<&'a [u8]>,
     pub glyf: Option<&'a [u8]>,
+    pub hdmx: Option<&'a [u8]>,
     pub hmtx: Option<&'a [u8]>,
     pub kern: Option<&'a [u8]>,
     pub loca: Option<&'a [u8]>,
+    pub meta: Option<&'a [u8]>,
     pub name: Option<&'a [u8]>,
     pub os2: Option<&'a [u8]>,
     pub post: Option<&'a [u8]>,
     pub sbix: Option<&'a [u8]>,
     pub svg: Option<&'a [u8]>,
+    pub vdmx: Option<&'a [u8]>,
     pub vhea: Option<&'a [u8]>,
     pub vmtx: Option<&'a [u8]>,
     pub vorg: Option<&'a [u8]>,
@@ -882,6 +934,8 @@ pub struct RawFaceTables<'a> {
     #[cfg(feature = "apple-layout")]
     pub kerx: Option<&'a [u8]>,
     #[cfg(feature = "apple-layout")]
+    pub lcar: Option<&'a [u8]>,
+    #[cfg(feature = "apple-layout")]
     pub morx: Option<&'a [u8]>,
     #[cfg(feature = "apple-layout")]
     pub trak: Option<&'a [u8]>,
@@ -924,14 +978,18 @@ pub struct FaceTables<'a> {
     pub cmap: Option<cmap::Table<'a>>,
     pub colr: Option<colr::Table<'a>>,
     pub ebdt: Option<cbdt::Table<'a>>,
+    pub gasp: Option<gasp::Table<'a>>,
     pub glyf: Option<glyf::Table<'a>>,
+    pub hdmx: Option<hdmx::Table<'a>>,
     pub hmtx: Option<hmtx::Table<'a>>,
     pub kern: Option<kern::Table<'a>>,
+    pub meta: Option<meta::Table<'a>>,
     pub name: Option<name::Table<'a>>,
     pub os2: Option<os2::Table<'a>>,
     pub post: Option<post::Table<'a>>,
     pub sbix: Option<sbix::Table<'a>>,
     pub svg: Option<svg::Table<'a>>,
+    pub vdmx: Option<vdmx::Table<'a>>,
     pub vhea: Option<vhea::Table>,
     pub vmtx: Option<hmtx::Table<'a>>,
     pub vorg: Option<vorg::Table<'a>>,
@@ -952,6 +1010,8 @@ pub struct FaceTables<'a> {
     #[cfg(feature = "apple-layout")]
     pub kerx: Option<kerx::Table<'a>>,
     #[cfg(feature = "apple-layout")]
+    pub lcar: Option<lcar::Table<'a>>,
+    #[cfg(feature = "apple-layout")]
     pub morx: Option<morx::Table<'a>>,
     #[cfg(feature = "apple-layout")]
     pub trak: Option<trak::Table<'a>>,
@@ -991,6 +1051,8 @@ pub struct FaceTables<'a> {
 pub struct Face<'a> {
     raw_face: RawFace<'a>,
     tables: FaceTables<'a>, // Parsed tables.
+    transcoder: Option<cmap::Transcoder>,
+    unicode_subtable: Option<u16>, // An index of the best Unicode `cmap` subtable.
     #[cfg(feature = "variable-fonts")]
     coordinates: VarCoords,
 }
@@ -1029,14 +1091,20 @@ impl<'a> Face<'a> {
         let raw_face = RawFace::parse(data, index)?;
         let raw_tables = Self::collect_tables(raw_face);
 
-        #[allow(unused_mut)]
         let mut face = Face {
             raw_face,
+            transcoder: None,
+            unicode_subtable: None,
             #[cfg(feature = "variable-fonts")]
             coordinates: VarCoords::default(),
             tables: Self::parse_tables(raw_tables)?,
         };
 
+        face.unicode_subtable = face
+            .tables
+            .cmap
+            .and_then(|cmap| cmap.best_unicode_subtable_index());
+
         #[cfg(feature = "variable-fonts")]
         {
             if let Some(ref fvar) = face.tables.fvar {
@@ -1084,6 +1152,7 @@ impl<'a> Face<'a> {
                 b"MVAR" => tables.mvar = table_data,
                 b"OS/2" => tables.os2 = table_data,
                 b"SVG " => tables.svg = table_data,
+                b"VDMX" => tables.vdmx = table_data,
                 b"VORG" => tables.vorg = table_data,
                 #[cfg(feature = "variable-fonts")]
                 b"VVAR" => tables.vvar = table_data,
@@ -1096,17 +1165,22 @@ impl<'a> Face<'a> {
                 b"feat" => tables.feat = table_data,
                 #[cfg(feature = "variable-fonts")]
                 b"fvar" => tables.fvar = table_data,
+                b"gasp" => tables.gasp = table_data,
                 b"glyf" => tables.glyf = table_data,
                 #[cfg(feature = "variable-fonts")]
                 b"gvar" => tables.gvar = table_data,
+                b"hdmx" => tables.hdmx = table_data,
                 b"head" => tables.head = table_data.unwrap_or_default(),
                 b"hhea" => tables.hhea = table_data.unwrap_or_default(),
                 b"hmtx" => tables.hmtx = table_data,
                 b"kern" => tables.kern = table_data,
                 #[cfg(feature = "apple-layout")]
                 b"kerx" => tables.kerx = table_data,
+                #[cfg(feature = "apple-layout")]
+                b"lcar" => tables.lcar = table_data,
                 b"loca" => tables.loca = table_data,
                 b"maxp" => tables.maxp = table_data.unwrap_or_default(),
+                b"meta" => tables.meta = table_data,
                 #[cfg(feature = "apple-layout")]
                 b"morx" => tables.morx = table_data,
                 b"name" => tables.name = table_data,
@@ -1125,17 +1199,23 @@ impl<'a> Face<'a> {
 
     /// Creates a new [`Face`] from provided [`RawFaceTables`].
     pub fn from_raw_tables(raw_tables: RawFaceTables<'a>) -> Result<Self, FaceParsingError> {
-        #[allow(unused_mut)]
         let mut face = Face {
             raw_face: RawFace {
                 data: &[],
                 table_records: LazyArray16::default(),
             },
+            transcoder: None,
+            unicode_subtable: None,
             #[cfg(feature = "variable-fonts")]
             coordinates: VarCoords::default(),
             tables: Self::parse_tables(raw_tables)?,
         };
 
+        face.unicode_subtable = face
+            .tables
+            .cmap
+            .and_then(|cmap| cmap.best_unicode_subtable_index());
+
         #[cfg(feature = "variable-fonts")]
         {
             if let Some(ref fvar) = face.tables.fvar {
@@ -1219,9 +1299,14 @@ impl<'a> Face<'a> {
             cmap: raw_tables.cmap.and_then(cmap::Table::parse),
             colr,
             ebdt,
+            gasp: raw_tables.gasp.and_then(gasp::Table::parse),
             glyf,
+            hdmx: raw_tables
+                .hdmx
+                .and_then(|data| hdmx::Table::parse(maxp.number_of_glyphs, data)),
             hmtx,
             kern: raw_tables.kern.and_then(kern::Table::parse),
+            meta: raw_tables.meta.and_then(meta::Table::parse),
             name: raw_tables.name.and_then(name::Table::parse),
             os2: raw_tables.os2.and_then(os2::Table::parse),
             post: raw_tables.post.and_then(post::Table::parse),
@@ -1229,6 +1314,7 @@ impl<'a> Face<'a> {
                 .sbix
                 .and_then(|data| sbix::Table::parse(maxp.number_of_glyphs, data)),
             svg: raw_tables.svg.and_then(svg::Table::parse),
+            vdmx: raw_tables.vdmx.and_then(vdmx::Table::parse),
             vhea: raw_tables.vhea.and_then(vhea::Table::parse),
             vmtx,
             vorg: raw_tables.vorg.and_then(vorg::Table::parse),
@@ -1257,6 +1343,10 @@ impl<'a> Face<'a> {
                 .kerx
                 .and_then(|data| kerx::Table::parse(maxp.number_of_glyphs, data)),
             #[cfg(feature = "apple-layout")]
+            lcar: raw_tables
+                .lcar
+                .and_then(|data| lcar::Table::parse(maxp.number_of_glyphs, data)),
+            #[cfg(feature = "apple-layout")]
             morx: raw_tables
                 .morx
                 .and_then(|data| morx::Table::parse(maxp.number_of_glyphs, data)),
@@ -1315,6 +1405,95 @@ impl<'a> Face<'a> {
         self.tables.name.unwrap_or_default().names
     }
 
+    /// Returns a family name for the specified locale.
+    ///
+    /// `locale` is a BCP 47 language tag, like `en-US`.
+    /// The typographic family name (ID 16) is preferred over the family name (ID 1).
+    /// A name with a different ID is used only when no name with a preferred ID can be decoded.
+    /// See [`name::Names::find_localized`] for how records are matched against the locale.
+    ///
+    /// Returns `None` when there are no decodable family names.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn family_name(&self, locale: &str) -> Option<std::string::String> {
+        self.localized_name(&[name_id::TYPOGRAPHIC_FAMILY, name_id::FAMILY], locale)
+    }
+
+    /// Returns a subfamily name for the specified locale.
+    ///
+    /// The typographic subfamily name (ID 17) is preferred over the subfamily name (ID 2).
+    /// Otherwise works like [`Face::family_name`].
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn subfamily_name(&self, locale: &str) -> Option<std::string::String> {
+        self.localized_name(
+            &[name_id::TYPOGRAPHIC_SUBFAMILY, name_id::SUBFAMILY],
+            locale,
+        )
+    }
+
+    /// Returns a WWS family name for the specified locale.
+    ///
+    /// Falls back to the typographic family name (ID 16) and then to the family name (ID 1).
+    /// Otherwise works like [`Face::family_name`].
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn wws_family_name(&self, locale: &str) -> Option<std::string::String> {
+        self.localized_name(
+            &[
+                name_id::WWS_FAMILY,
+                name_id::TYPOGRAPHIC_FAMILY,
+                name_id::FAMILY,
+            ],
+            locale,
+        )
+    }
+
+    /// Returns a full name for the specified locale.
+    ///
+    /// Otherwise works like [`Face::family_name`].
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn full_name(&self, locale: &str) -> Option<std::string::String> {
+        self.localized_name(&[name_id::FULL_NAME], locale)
+    }
+
+    /// Returns a PostScript name.
+    ///
+    /// PostScript names are not localized, so the US English one is preferred.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn postscript_name(&self) -> Option<std::string::String> {
+        self.localized_name(&[name_id::POST_SCRIPT_NAME], "en-US")
+    }
+
+    /// Returns a user-interface name of an OpenType layout feature for the specified locale.
+    ///
+    /// This is a stylistic set name, a character variant label
+    /// or an optical size subfamily name, depending on the feature.
+    /// Otherwise works like [`Face::family_name`].
+    ///
+    /// Returns `None` when the feature has no parameters or they do not reference a name.
+    #[cfg(all(feature = "std", feature = "opentype-layout"))]
+    #[inline]
+    pub fn feature_ui_name(
+        &self,
+        feature: &opentype_layout::Feature,
+        locale: &str,
+    ) -> Option<std::string::String> {
+        let name_id = feature.params?.ui_name_id()?;
+        self.localized_name(&[name_id], locale)
+    }
+
+    #[cfg(feature = "std")]
+    fn localized_name(&self, name_ids: &[u16], locale: &str) -> Option<std::string::String> {
+        let names = self.names();
+        name_ids
+            .iter()
+            .filter_map(|id| names.find_localized(*id, locale))
+            .find_map(|name| name.to_string())
+    }
+
     /// Checks that face is marked as *Regular*.
     ///
     /// Returns `false` when OS/2 table is not present.
@@ -1753,22 +1932,110 @@ impl<'a> Face<'a> {
     ///
     /// Returns `None` instead of `0` when glyph is not found.
     ///
-    /// All subtable formats except Mixed Coverage (8) are supported.
+    /// Checks the subtable returned by [`cmap::Table::best_unicode_subtable`] first,
+    /// followed by the remaining Unicode subtables in the table order.
+    /// When there are no Unicode subtables, falls back to legacy encoding subtables,
+    /// like Shift JIS or Mac Roman. See [`Face::set_transcoder`] for details.
     ///
     /// If you need a more low-level control, prefer `Face::tables().cmap`.
     #[inline]
     pub fn glyph_index(&self, code_point: char) -> Option<GlyphId> {
-        for subtable in self.tables.cmap?.subtables {
-            if !subtable.is_unicode() {
-                continue;
-            }
-
-            if let Some(id) = subtable.glyph_index(u32::from(code_point)) {
-                return Some(id);
-            }
+        let cmap = self.tables.cmap?;
+        if self.unicode_subtable.is_some() {
+            cmap.unicode_glyph_index_impl(self.unicode_subtable, u32::from(code_point))
+                .map(|(_, id)| id)
+        } else {
+            cmap.legacy_glyph_index(code_point, self.transcoder)
         }
+    }
 
-        None
+    /// Sets a function that converts characters into legacy `cmap` encodings.
+    ///
+    /// Used by [`Face::glyph_index`] for faces without Unicode `cmap` subtables.
+    /// Without a transcoder, only ASCII, Macintosh single-byte scripts
+    /// and Johab Hangul syllables are supported.
+    /// Other encodings, like Shift JIS or Big5, require large conversion tables,
+    /// which are out of scope of this library.
+    ///
+    /// See [`cmap::Subtable::legacy_glyph_index`] for details.
+    #[inline]
+    pub fn set_transcoder(&mut self, transcoder: Option<cmap::Transcoder>) {
+        self.transcoder = transcoder;
+    }
+
+    /// Returns an iterator over characters that resolve to a glyph.
+    ///
+    /// This is a reverse of [`Face::glyph_index`].
+    ///
+    /// This method iterates over all `cmap` mappings, so it's relatively expensive.
+    /// See [`cmap::Table::glyph_chars`] for details.
+    #[inline]
+    pub fn glyph_chars(&self, glyph_id: GlyphId) -> cmap::GlyphChars<'a> {
+        self.tables.cmap.unwrap_or_default().glyph_chars(glyph_id)
+    }
+
+    /// Resolves glyphs of a character, decomposing it when there is no precomposed glyph.
+    ///
+    /// When a character has no glyph, its canonical decomposition is used instead.
+    /// Characters are decomposed recursively, but only as deep as needed.
+    /// So `ǖ` resolves to `ü` and a combining macron glyphs when `ü` is present,
+    /// and to `u`, a combining diaeresis and a combining macron ones otherwise.
+    ///
+    /// Returns a single glyph when the character has a glyph.
+    /// Returns `None` when the character cannot be resolved.
+    #[cfg(feature = "unicode-normalization")]
+    #[inline]
+    pub fn glyph_index_decomposed(&self, code_point: char) -> Option<normalization::GlyphSequence> {
+        normalization::glyph_index_decomposed(self, code_point)
+    }
+
+    /// Resolves a glyph of a precomposed character for a character sequence.
+    ///
+    /// Characters are [composed](normalization::compose) one by one,
+    /// starting from the first one.
+    /// Returns a glyph of the longest composed prefix that has a glyph
+    /// and the number of characters it consumes.
+    /// So `['u', '\u{0308}', '\u{0304}']` resolves to `ǖ` when it's present
+    /// and to `ü` with 2 consumed characters otherwise.
+    ///
+    /// Returns `None` when none of the composed prefixes has a glyph.
+    #[cfg(feature = "unicode-normalization")]
+    pub fn glyph_index_composed(&self, chars: &[char]) -> Option<(GlyphId, usize)> {
+        normalization::glyph_index_composed(self, chars)
+    }
+
+    /// Calculates Unicode blocks and scripts coverage.
+    ///
+    /// Uses the subtable returned by [`cmap::Table::best_unicode_subtable`].
+    /// This method iterates over all `cmap` mappings, so it's relatively expensive.
+    #[inline]
+    pub fn coverage(&self) -> Option<coverage::Coverage> {
+        let subtable = self.tables.cmap?.subtables.get(self.unicode_subtable?)?;
+        Some(coverage::Coverage::from_subtable(subtable))
+    }
+
+    /// Checks that the face supports a language.
+    ///
+    /// `language` is a BCP 47 language tag, like `vi` or `zh-TW`.
+    /// A language is supported when all of its
+    /// [exemplar characters](coverage::language_exemplars) are mapped to glyphs.
+    /// For cased scripts, uppercase characters are checked as well.
+    ///
+    /// Returns `false` for unknown languages.
+    #[inline]
+    pub fn supports_language(&self, language: &str) -> bool {
+        coverage::supports_language(self, language)
+    }
+
+    /// Returns an iterator over supported languages.
+    ///
+    /// See [`Face::supports_language`] for details.
+    #[inline]
+    pub fn supported_languages(&self) -> coverage::SupportedLanguages<'_> {
+        coverage::SupportedLanguages {
+            face: self,
+            languages: coverage::languages(),
+        }
     }
 
     /// Resolves a Glyph ID for a glyph name.
@@ -1805,19 +2072,59 @@ impl<'a> Face<'a> {
     /// [Unicode Variation Sequences](
     /// https://docs.microsoft.com/en-us/typography/opentype/spec/cmap#format-14-unicode-variation-sequences).
     ///
-    /// Returns `None` instead of `0` when glyph is not found.
+    /// Returns `None` instead of `0` when glyph is not found
+    /// or when the variation sequence is not supported by the font.
+    /// Use [`Face::glyph_variation`] to fall back to the default glyph instead.
     #[inline]
     pub fn glyph_variation_index(&self, code_point: char, variation: char) -> Option<GlyphId> {
-        for subtable in self.tables.cmap?.subtables {
-            if let cmap::Format::UnicodeVariationSequences(ref table) = subtable.format {
-                return match table.glyph_index(u32::from(code_point), u32::from(variation))? {
-                    cmap::GlyphVariationResult::Found(v) => Some(v),
-                    cmap::GlyphVariationResult::UseDefault => self.glyph_index(code_point),
-                };
-            }
+        match self.glyph_variation(code_point, variation)? {
+            cmap::GlyphVariation::Fallback(_) => None,
+            v => Some(v.glyph_id()),
         }
+    }
 
-        None
+    /// Resolves a variation sequence, falling back to the default glyph.
+    ///
+    /// Unlike [`Face::glyph_variation_index`], reports whether the font maps the sequence
+    /// to a glyph explicitly, supports it using the default glyph or doesn't support it at all.
+    /// In the last case, the default glyph from [`Face::glyph_index`] is returned.
+    ///
+    /// Uses the subtable returned by [`cmap::Table::variation_subtable`].
+    ///
+    /// Returns `None` when the resolved glyph is not found.
+    pub fn glyph_variation(
+        &self,
+        code_point: char,
+        variation: char,
+    ) -> Option<cmap::GlyphVariation> {
+        let result = self
+            .tables
+            .cmap?
+            .variation_subtable()
+            .and_then(|table| table.glyph_index(u32::from(code_point), u32::from(variation)));
+
+        Some(match result {
+            Some(cmap::GlyphVariationResult::Found(id)) => cmap::GlyphVariation::Explicit(id),
+            Some(cmap::GlyphVariationResult::UseDefault) => {
+                cmap::GlyphVariation::Default(self.glyph_index(code_point)?)
+            }
+            None => cmap::GlyphVariation::Fallback(self.glyph_index(code_point)?),
+        })
+    }
+
+    /// Resolves a glyph for the specified emoji presentation.
+    ///
+    /// A shorthand for [`Face::glyph_variation`] with VS15 (`U+FE0E`)
+    /// or VS16 (`U+FE0F`) selector.
+    /// When the font doesn't support the requested presentation explicitly,
+    /// [`cmap::GlyphVariation::Fallback`] is returned.
+    #[inline]
+    pub fn glyph_presentation(
+        &self,
+        code_point: char,
+        presentation: cmap::Presentation,
+    ) -> Option<cmap::GlyphVariation> {
+        self.glyph_variation(code_point, presentation.selector())
     }
 
     /// Returns glyph's horizontal advance.
@@ -1938,6 +2245,41 @@ impl<'a> Face<'a> {
         self.tables.vorg.map(|vorg| vorg.glyph_y_origin(glyph_id))
     }
 
+    /// Returns glyph's horizontal advance in pixels for the specified pixels per EM.
+    ///
+    /// Uses the [Horizontal Device Metrics Table](
+    /// https://docs.microsoft.com/en-us/typography/opentype/spec/hdmx).
+    ///
+    /// Returns `None` when `hdmx` table is not present or doesn't have a record for this size.
+    #[inline]
+    pub fn device_advance(&self, glyph_id: GlyphId, pixels_per_em: u16) -> Option<u8> {
+        self.tables.hdmx?.advance(glyph_id, pixels_per_em)
+    }
+
+    /// Returns face's vertical extents in pixels for the specified pixels per EM.
+    ///
+    /// Uses the [Vertical Device Metrics Table](
+    /// https://docs.microsoft.com/en-us/typography/opentype/spec/vdmx)
+    /// with a 1:1 device aspect ratio.
+    /// Use `Face::tables().vdmx` for other aspect ratios.
+    ///
+    /// Returns `None` when `VDMX` table is not present or doesn't have a record for this size.
+    #[inline]
+    pub fn device_vertical_metrics(&self, pixels_per_em: u16) -> Option<vdmx::Metrics> {
+        self.tables.vdmx?.metrics(1, 1, pixels_per_em)
+    }
+
+    /// Returns preferred rasterizer behavior for the specified pixels per EM.
+    ///
+    /// Uses the [Grid-fitting And Scan-conversion Procedure Table](
+    /// https://docs.microsoft.com/en-us/typography/opentype/spec/gasp).
+    ///
+    /// Returns `None` when `gasp` table is not present.
+    #[inline]
+    pub fn rendering_flags(&self, pixels_per_em: u16) -> Option<gasp::RenderingFlags> {
+        self.tables.gasp?.flags(pixels_per_em)
+    }
+
     /// Returns glyph's name.
     ///
     /// Uses the `post` and `CFF` tables as sources.
@@ -2045,6 +2387,429 @@ impl<'a> Face<'a> {
         None
     }
 
+    /// Returns `GSUB` features available for the specified script and language.
+    ///
+    /// Features are substituted according to the `GSUB` feature variations
+    /// for the current [variation coordinates](Face::variation_coordinates).
+    /// See [`opentype_layout::LayoutTable::language_features`] for details.
+    #[cfg(feature = "opentype-layout")]
+    #[inline]
+    pub fn gsub_language_features(
+        &self,
+        script: Tag,
+        language: Option<Tag>,
+    ) -> Option<opentype_layout::LanguageFeatures<'a>> {
+        self.layout_language_features(self.tables.gsub?, script, language)
+    }
+
+    /// Returns `GPOS` features available for the specified script and language.
+    ///
+    /// Otherwise works like [`Face::gsub_language_features`].
+    #[cfg(feature = "opentype-layout")]
+    #[inline]
+    pub fn gpos_language_features(
+        &self,
+        script: Tag,
+        language: Option<Tag>,
+    ) -> Option<opentype_layout::LanguageFeatures<'a>> {
+        self.layout_language_features(self.tables.gpos?, script, language)
+    }
+
+    /// Returns a `GSUB` feature at index.
+    ///
+    /// The feature is substituted according to the `GSUB` feature variations
+    /// for the current [variation coordinates](Face::variation_coordinates).
+    /// So its lookups can be used as is.
+    #[cfg(feature = "opentype-layout")]
+    #[inline]
+    pub fn gsub_feature(
+        &self,
+        index: opentype_layout::FeatureIndex,
+    ) -> Option<opentype_layout::Feature<'a>> {
+        self.layout_feature(self.tables.gsub?, index)
+    }
+
+    /// Returns a `GPOS` feature at index.
+    ///
+    /// Otherwise works like [`Face::gsub_feature`].
+    #[cfg(feature = "opentype-layout")]
+    #[inline]
+    pub fn gpos_feature(
+        &self,
+        index: opentype_layout::FeatureIndex,
+    ) -> Option<opentype_layout::Feature<'a>> {
+        self.layout_feature(self.tables.gpos?, index)
+    }
+
+    /// Computes a `GSUB` glyph closure for the specified features.
+    ///
+    /// Adds to `glyphs` all glyphs reachable from them through lookups of features
+    /// with the specified tags, in any script and language.
+    /// Features are substituted according to the current
+    /// [variation coordinates](Face::variation_coordinates).
+    /// See [`gsub::closure`] for details.
+    ///
+    /// Does nothing when there is no `GSUB` table.
+    #[cfg(feature = "opentype-layout")]
+    pub fn gsub_closure(&self, features: &[Tag], glyphs: &mut gsub::GlyphSet) {
+        let table = match self.tables.gsub {
+            Some(v) => v,
+            None => return,
+        };
+
+        let lookup_indices = (0..table.features.len())
+            .filter_map(|index| self.layout_feature(table, index))
+            .filter(|feature| features.contains(&feature.tag))
+            .flat_map(|feature| feature.lookup_indices);
+        gsub::closure(table.lookups, lookup_indices, glyphs);
+    }
+
+    /// Returns an iterator over `GSUB` alternates of the glyph.
+    ///
+    /// Alternates are produced by single and alternate substitutions
+    /// in features like `aalt`, `salt`, `swsh`, `ssXX` or `cvXX`,
+    /// in any script and language.
+    /// Features are substituted according to the current
+    /// [variation coordinates](Face::variation_coordinates).
+    /// See [`gsub::GlyphAlternates`] for details.
+    ///
+    /// Returns `None` when there is no `GSUB` table.
+    #[cfg(feature = "opentype-layout")]
+    pub fn glyph_alternates(&self, glyph_id: GlyphId) -> Option<gsub::GlyphAlternates<'a>> {
+        let table = self.tables.gsub?;
+        Some(gsub::GlyphAlternates::new(
+            table,
+            self.layout_variation_index(table),
+            glyph_id,
+        ))
+    }
+
+    /// Returns an iterator over `GPOS` kerning pairs.
+    ///
+    /// Pairs are taken from pair adjustment lookups of the `kern` feature,
+    /// in any script and language.
+    /// Features are substituted according to the current
+    /// [variation coordinates](Face::variation_coordinates).
+    /// See [`gpos::KerningPairs`] for details.
+    ///
+    /// Returns `None` when there is no `GPOS` table
+    /// or when the `kern` feature references more than 256 lookups.
+    #[cfg(feature = "opentype-layout")]
+    pub fn kerning_pairs(&self) -> Option<gpos::KerningPairs<'a>> {
+        let table = self.tables.gpos?;
+        gpos::KerningPairs::new(
+            table,
+            self.tables.gdef,
+            self.number_of_glyphs(),
+            self.layout_variation_index(table),
+        )
+    }
+
+    #[cfg(feature = "opentype-layout")]
+    fn layout_language_features(
+        &self,
+        table: opentype_layout::LayoutTable<'a>,
+        script: Tag,
+        language: Option<Tag>,
+    ) -> Option<opentype_layout::LanguageFeatures<'a>> {
+        #[cfg(feature = "variable-fonts")]
+        {
+            table.language_feature_variations(script, language, self.coords())
+        }
+
+        #[cfg(not(feature = "variable-fonts"))]
+        {
+            table.language_features(script, language)
+        }
+    }
+
+    #[cfg(feature = "opentype-layout")]
+    fn layout_feature(
+        &self,
+        table: opentype_layout::LayoutTable<'a>,
+        index: opentype_layout::FeatureIndex,
+    ) -> Option<opentype_layout::Feature<'a>> {
+        table.resolve_feature(index, self.layout_variation_index(table))
+    }
+
+    #[cfg(feature = "opentype-layout")]
+    #[allow(unused_variables)]
+    fn layout_variation_index(
+        &self,
+        table: opentype_layout::LayoutTable<'a>,
+    ) -> Option<opentype_layout::VariationIndex> {
+        #[cfg(feature = "variable-fonts")]
+        {
+            table.variation_index(self.coords())
+        }
+
+        #[cfg(not(feature = "variable-fonts"))]
+        {
+            None
+        }
+    }
+
+    /// Returns the number of carets within a ligature glyph.
+    ///
+    /// Carets are taken from the `GDEF` table and, when the glyph is not listed there,
+    /// from the `lcar` table.
+    #[allow(unused_variables)]
+    pub fn ligature_caret_count(&self, glyph_id: GlyphId) -> u16 {
+        #[cfg(feature = "opentype-layout")]
+        {
+            if let Some(carets) = self
+                .tables
+                .gdef
+                .and_then(|gdef| gdef.ligature_carets(glyph_id))
+            {
+                return carets.len();
+            }
+        }
+
+        #[cfg(feature = "apple-layout")]
+        {
+            if let Some(carets) = self
+                .tables
+                .lcar
+                .as_ref()
+                .and_then(|lcar| lcar.carets(glyph_id))
+            {
+                return carets.len();
+            }
+        }
+
+        0
+    }
+
+    /// Returns a caret position within a ligature glyph, in design units.
+    ///
+    /// The position is along the text direction, relative to the glyph origin.
+    /// Device corrections of `GDEF` carets are applied for `pixels_per_em`, when set,
+    /// and for the current [variation coordinates](Face::variation_coordinates).
+    /// See [`Face::ligature_caret_count`] for how carets are looked up.
+    ///
+    /// Returns `None` for carets defined by a glyph outline point,
+    /// since they cannot be resolved without outline points access.
+    #[allow(unused_variables)]
+    pub fn ligature_caret(
+        &self,
+        glyph_id: GlyphId,
+        index: u16,
+        pixels_per_em: Option<u16>,
+    ) -> Option<f32> {
+        #[cfg(feature = "opentype-layout")]
+        {
+            if let Some(carets) = self
+                .tables
+                .gdef
+                .and_then(|gdef| gdef.ligature_carets(glyph_id))
+            {
+                return match carets.get(index)? {
+                    gdef::CaretValue::Coordinate(coordinate) => Some(f32::from(coordinate)),
+                    gdef::CaretValue::ContourPoint(_) => None,
+                    gdef::CaretValue::DeviceCoordinate { coordinate, device } => {
+                        Some(f32::from(coordinate) + self.device_delta(device, pixels_per_em))
+                    }
+                };
+            }
+        }
+
+        #[cfg(feature = "apple-layout")]
+        {
+            if let Some(carets) = self
+                .tables
+                .lcar
+                .as_ref()
+                .and_then(|lcar| lcar.carets(glyph_id))
+            {
+                return match carets {
+                    lcar::Carets::Distances(carets) => carets.get(index).map(f32::from),
+                    lcar::Carets::ControlPoints(_) => None,
+                };
+            }
+        }
+
+        None
+    }
+
+    /// Resolves a math value, in design units.
+    ///
+    /// Applies a hinting device correction for `pixels_per_em`, when set,
+    /// and a variation device correction for the current
+    /// [variation coordinates](Face::variation_coordinates).
+    /// Variation deltas are stored in the `GDEF` table.
+    #[cfg(feature = "opentype-layout")]
+    pub fn math_value(&self, value: math::MathValue, pixels_per_em: Option<u16>) -> f32 {
+        f32::from(value.value) + self.device_delta(value.device, pixels_per_em)
+    }
+
+    #[cfg(feature = "opentype-layout")]
+    fn device_delta(&self, device: Option<gpos::Device>, pixels_per_em: Option<u16>) -> f32 {
+        match device {
+            Some(gpos::Device::Hinting(device)) => {
+                let ppem = pixels_per_em.map(|ppem| (ppem, ppem));
+                device
+                    .x_delta(self.units_per_em(), ppem)
+                    .map_or(0.0, |delta| delta as f32)
+            }
+            #[cfg(feature = "variable-fonts")]
+            Some(gpos::Device::Variation(device)) => self
+                .tables
+                .gdef
+                .and_then(|gdef| {
+                    gdef.glyph_variation_delta(
+                        device.outer_index,
+                        device.inner_index,
+                        self.coords(),
+                    )
+                })
+                .unwrap_or(0.0),
+            _ => 0.0,
+        }
+    }
+
+    /// Returns a resolved math italics correction of a glyph, in design units.
+    ///
+    /// Returns `0` when the glyph has no italics correction.
+    /// See [`Face::math_value`] for details.
+    #[cfg(feature = "opentype-layout")]
+    pub fn math_italics_correction(&self, glyph_id: GlyphId, pixels_per_em: Option<u16>) -> f32 {
+        self.tables
+            .math
+            .and_then(|math| math.glyph_info?.italic_corrections?.get(glyph_id))
+            .map(|value| self.math_value(value, pixels_per_em))
+            .unwrap_or(0.0)
+    }
+
+    /// Returns a resolved math kern value of a glyph corner at the given height,
+    /// in design units.
+    ///
+    /// Both correction heights and kern values are resolved.
+    /// See [`Face::math_value`] for details.
+    ///
+    /// Returns `0` when the glyph corner has no kerning data.
+    #[cfg(feature = "opentype-layout")]
+    pub fn math_kern(
+        &self,
+        glyph_id: GlyphId,
+        corner: math::Corner,
+        height: f32,
+        pixels_per_em: Option<u16>,
+    ) -> f32 {
+        self.tables
+            .math
+            .and_then(|math| math.glyph_info?.kern_infos?.get(glyph_id)?.corner(corner))
+            .and_then(|kern| kern.kern_at_with(height, |v| self.math_value(v, pixels_per_em)))
+            .map(|value| self.math_value(value, pixels_per_em))
+            .unwrap_or(0.0)
+    }
+
+    /// Returns a cut-in kerning between a base glyph and a script, in design units.
+    ///
+    /// Implemented according to the
+    /// [MathKernInfo](https://learn.microsoft.com/en-us/typography/opentype/spec/math#mathkerninfo-table)
+    /// algorithm: kern values of the base and script corners are summed
+    /// at two correction heights and the larger sum is used.
+    /// For a superscript, these are the script bottom and the base top.
+    /// For a subscript, these are the script top and the base bottom.
+    ///
+    /// `shift` is a vertical distance between the base and script baselines,
+    /// in design units. It must be positive for both superscripts and subscripts.
+    ///
+    /// The returned value should be added to the horizontal distance between glyphs.
+    /// Returns `0` when there is no kerning data or glyphs have no bounding boxes.
+    #[cfg(feature = "opentype-layout")]
+    pub fn math_script_kern(
+        &self,
+        base: GlyphId,
+        script: GlyphId,
+        position: math::ScriptPosition,
+        shift: f32,
+        pixels_per_em: Option<u16>,
+    ) -> f32 {
+        let (base_bbox, script_bbox) = match (
+            self.glyph_bounding_box(base),
+            self.glyph_bounding_box(script),
+        ) {
+            (Some(a), Some(b)) => (a, b),
+            _ => return 0.0,
+        };
+
+        // Script baseline relative to the base baseline.
+        let script_baseline = if position.is_superscript() {
+            shift
+        } else {
+            -shift
+        };
+
+        let heights = if position.is_superscript() {
+            [
+                script_baseline + f32::from(script_bbox.y_min),
+                f32::from(base_bbox.y_max),
+            ]
+        } else {
+            [
+                script_baseline + f32::from(script_bbox.y_max),
+                f32::from(base_bbox.y_min),
+            ]
+        };
+
+        let kern_at = |height: f32| {
+            self.math_kern(base, position.base_corner(), height, pixels_per_em)
+                + self.math_kern(
+                    script,
+                    position.script_corner(),
+                    height - script_baseline,
+                    pixels_per_em,
+                )
+        };
+
+        kern_at(heights[0]).max(kern_at(heights[1]))
+    }
+
+    /// Outlines a stretched math glyph.
+    ///
+    /// Parts of an assembled glyph are placed along the `direction`,
+    /// starting at the origin.
+    ///
+    /// Parts without an outline, like empty extenders, are skipped.
+    ///
+    /// Returns a bounding box of the whole glyph.
+    /// Returns `None` when none of the glyphs has an outline.
+    #[cfg(feature = "opentype-layout")]
+    pub fn outline_stretched_glyph(
+        &self,
+        glyph: &math::StretchedGlyph,
+        direction: math::Direction,
+        builder: &mut dyn OutlineBuilder,
+    ) -> Option<Rect> {
+        let assembly = match glyph {
+            math::StretchedGlyph::Variant(variant) => {
+                return self.outline_glyph(variant.variant_glyph, builder);
+            }
+            math::StretchedGlyph::Assembly(assembly) => assembly,
+        };
+
+        let mut bbox = BBox::new();
+        for part in assembly.parts() {
+            let (dx, dy) = match direction {
+                math::Direction::Vertical => (0.0, part.offset),
+                math::Direction::Horizontal => (part.offset, 0.0),
+            };
+
+            let mut translated = TranslatedOutline { builder, dx, dy };
+            let rect = match self.outline_glyph(part.glyph_id, &mut translated) {
+                Some(rect) => rect,
+                None => continue,
+            };
+
+            bbox.extend_by(f32::from(rect.x_min) + dx, f32::from(rect.y_min) + dy);
+            bbox.extend_by(f32::from(rect.x_max) + dx, f32::from(rect.y_max) + dy);
+        }
+
+        bbox.to_rect()
+    }
+
     /// Returns a tight glyph bounding box.
     ///
     /// This is just a shorthand for `outline_glyph()` since only the `glyf` table stores
@@ -2066,6 +2831,26 @@ impl<'a> Face<'a> {
         self.outline_glyph(glyph_id, &mut DummyOutline)
     }
 
+    /// Rasterizes a glyph outline into an anti-aliased coverage mask.
+    ///
+    /// `pixels_per_em` sets the scale and `offset` is a sub-pixel position of the glyph origin,
+    /// usually in a 0..1 range. The mask is just large enough to enclose the outline.
+    ///
+    /// Returns `None` when the glyph has no outline
+    /// or the mask would have more than [`raster::MAX_PIXELS`] pixels.
+    ///
+    /// This method is affected by variation axes.
+    #[cfg(feature = "rasterizer")]
+    #[inline]
+    pub fn rasterize_glyph(
+        &self,
+        glyph_id: GlyphId,
+        pixels_per_em: f32,
+        offset: (f32, f32),
+    ) -> Option<raster::Mask> {
+        raster::rasterize_glyph(self, glyph_id, pixels_per_em, offset)
+    }
+
     /// Returns a bounding box that large enough to enclose any glyph from the face.
     #[inline]
     pub fn global_bounding_box(&self) -> Rect {
@@ -0,0 +1,392 @@
+//! Mapping between OpenType script and language system tags
+//! and [ISO 15924](https://unicode.org/iso15924/) script codes
+//! and [BCP 47](https://www.rfc-editor.org/info/bcp47) language tags.
+//!
+//! See [Script Tags](https://docs.microsoft.com/en-us/typography/opentype/spec/scripttags)
+//! and [Language System Tags](https://docs.microsoft.com/en-us/typography/opentype/spec/languagetags).
+
+use crate::language::LanguageTagParts;
+use crate::Tag;
+
+/// The default script tag.
+pub const DEFAULT_SCRIPT: Tag = Tag::from_bytes(b"DFLT");
+
+/// The default language system tag.
+pub const DEFAULT_LANGUAGE: Tag = Tag::from_bytes(b"dflt");
+
+/// An iterator over OpenType tags in the order of preference.
+#[derive(Clone, Copy)]
+#[allow(missing_debug_implementations)]
+pub struct Tags {
+    tags: [Tag; 3],
+    len: u8,
+    index: u8,
+}
+
+impl Default for Tags {
+    #[inline]
+    fn default() -> Self {
+        Tags {
+            tags: [Tag(0); 3],
+            len: 0,
+            index: 0,
+        }
+    }
+}
+
+impl Tags {
+    fn push(&mut self, tag: Tag) {
+        let len = usize::from(self.len);
+        if len < self.tags.len() && !self.tags[..len].contains(&tag) {
+            self.tags[len] = tag;
+            self.len += 1;
+        }
+    }
+}
+
+impl Iterator for Tags {
+    type Item = Tag;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index < self.len {
+            self.index += 1;
+            Some(self.tags[usize::from(self.index - 1)])
+        } else {
+            None
+        }
+    }
+}
+
+/// Returns OpenType script tags for an ISO 15924 script code, like `Deva`.
+///
+/// Newer tags go first, e.g. `dev3`, `dev2` and then `deva`.
+/// `Zyyy` (common), `Zinh` (inherited) and `Zzzz` (unknown) scripts
+/// are mapped to [`DEFAULT_SCRIPT`].
+/// The code is case-insensitive.
+pub fn script_tags(code: &str) -> Tags {
+    let mut tags = Tags::default();
+    if code.len() != 4 || !code.bytes().all(|c| c.is_ascii_alphabetic()) {
+        return tags;
+    }
+
+    for (script, tag) in SCRIPTS {
+        if script.eq_ignore_ascii_case(code) {
+            tags.push(Tag::from_bytes(tag));
+        }
+    }
+
+    if tags.len == 0 {
+        let mut tag = [0; 4];
+        tag.copy_from_slice(code.as_bytes());
+        tag.make_ascii_lowercase();
+        tags.push(Tag::from_bytes(&tag));
+    }
+
+    tags
+}
+
+/// Returns an ISO 15924 script code for an OpenType script tag.
+///
+/// [`DEFAULT_SCRIPT`] is mapped to `Zyyy`.
+/// Returns `None` for unknown tags.
+pub fn script_code(tag: Tag) -> Option<&'static str> {
+    let bytes = tag.to_bytes();
+    if let Some((script, _)) = SCRIPTS.iter().find(|(_, t)| **t == bytes) {
+        return Some(script);
+    }
+
+    let code = core::str::from_utf8(&bytes).ok()?;
+    crate::coverage::Script::from_code(code).map(|script| script.code)
+}
+
+/// Returns OpenType language system tags for a BCP 47 language tag, like `zh-HK`.
+///
+/// Tags are returned in the order of preference. For example, `zh-MO` produces
+/// `ZHTM`, `ZHH ` and `ZHT `. Script and region subtags are taken into account,
+/// with the script of Chinese being implied by the region.
+///
+/// Returns an empty iterator for unknown languages.
+pub fn language_tags(locale: &str) -> Tags {
+    let locale = LanguageTagParts::parse(locale.chars());
+    let mut tags = Tags::default();
+    if !locale.is_valid() {
+        return tags;
+    }
+
+    // Tags with a script or a region are more specific,
+    // therefore the plain language ones are used only as a fallback.
+    for (bcp47, tag) in LANGUAGES {
+        let parts = LanguageTagParts::parse(bcp47.chars());
+        if (parts.script[0] != 0 || parts.region[0] != 0) && parts.covers(&locale) {
+            tags.push(Tag::from_bytes(tag));
+        }
+    }
+
+    if tags.len == 0 {
+        for (bcp47, tag) in LANGUAGES {
+            if LanguageTagParts::parse(bcp47.chars()) == language_only(&locale) {
+                tags.push(Tag::from_bytes(tag));
+            }
+        }
+    }
+
+    tags
+}
+
+/// Returns a BCP 47 language tag for an OpenType language system tag.
+///
+/// Returns `None` for [`DEFAULT_LANGUAGE`] and unknown tags.
+pub fn language_code(tag: Tag) -> Option<&'static str> {
+    let bytes = tag.to_bytes();
+    LANGUAGES
+        .iter()
+        .find(|(_, t)| **t == bytes)
+        .map(|(bcp47, _)| *bcp47)
+}
+
+/// Returns an ISO 15924 code of the script used by a BCP 47 language tag.
+///
+/// An explicit script subtag has the highest priority.
+/// Otherwise, the script implied by the region is used, like `Hant` for `zh-TW`,
+/// and then the default script of the language.
+pub fn default_script(locale: &str) -> Option<&'static str> {
+    let locale = LanguageTagParts::parse(locale.chars());
+    if let Some(script) = locale.script_or_implied() {
+        // Codes like `Hans` are not in the Unicode scripts list.
+        let code = core::str::from_utf8(&script).ok()?;
+        return match SCRIPTS.iter().find(|(s, _)| s.eq_ignore_ascii_case(code)) {
+            Some((s, _)) => Some(s),
+            None => crate::coverage::Script::from_code(code).map(|s| s.code),
+        };
+    }
+
+    locale.language_script()
+}
+
+fn language_only(parts: &LanguageTagParts) -> LanguageTagParts {
+    LanguageTagParts {
+        language: parts.language,
+        ..LanguageTagParts::default()
+    }
+}
+
+// ISO 15924 codes that are not mapped to OpenType tags just by lowercasing.
+// Ordered by preference. The first entry of a tag is used for the reverse mapping.
+#[rustfmt::skip]
+static SCRIPTS: &[(&str, &[u8; 4])] = &[
+    ("Zyyy", b"DFLT"),
+    ("Zinh", b"DFLT"),
+    ("Zzzz", b"DFLT"),
+    ("Beng", b"bng3"),
+    ("Beng", b"bng2"),
+    ("Beng", b"beng"),
+    ("Deva", b"dev3"),
+    ("Deva", b"dev2"),
+    ("Deva", b"deva"),
+    ("Gujr", b"gjr3"),
+    ("Gujr", b"gjr2"),
+    ("Gujr", b"gujr"),
+    ("Guru", b"gur3"),
+    ("Guru", b"gur2"),
+    ("Guru", b"guru"),
+    ("Knda", b"knd3"),
+    ("Knda", b"knd2"),
+    ("Knda", b"knda"),
+    ("Mlym", b"mlm3"),
+    ("Mlym", b"mlm2"),
+    ("Mlym", b"mlym"),
+    ("Orya", b"ory3"),
+    ("Orya", b"ory2"),
+    ("Orya", b"orya"),
+    ("Taml", b"tml3"),
+    ("Taml", b"tml2"),
+    ("Taml", b"taml"),
+    ("Telu", b"tel3"),
+    ("Telu", b"tel2"),
+    ("Telu", b"telu"),
+    ("Mymr", b"mym2"),
+    ("Mymr", b"mymr"),
+    ("Hani", b"hani"),
+    ("Hans", b"hani"),
+    ("Hant", b"hani"),
+    ("Hang", b"hang"),
+    ("Kore", b"hang"),
+    ("Kore", b"hani"),
+    ("Kana", b"kana"),
+    ("Hira", b"kana"),
+    ("Hrkt", b"kana"),
+    ("Jpan", b"hani"),
+    ("Jpan", b"kana"),
+    ("Laoo", b"lao "),
+    ("Nkoo", b"nko "),
+    ("Vaii", b"vai "),
+    ("Yiii", b"yi  "),
+    ("Zmth", b"math"),
+];
+
+// BCP 47 tag and OpenType language system tag.
+// Ordered by preference. The first entry of a tag is used for the reverse mapping.
+#[rustfmt::skip]
+static LANGUAGES: &[(&str, &[u8; 4])] = &[
+    ("af", b"AFK "),
+    ("am", b"AMH "),
+    ("ar", b"ARA "),
+    ("as", b"ASM "),
+    ("az", b"AZE "),
+    ("ba", b"BSH "),
+    ("be", b"BEL "),
+    ("bg", b"BGR "),
+    ("bn", b"BEN "),
+    ("bo", b"TIB "),
+    ("br", b"BRE "),
+    ("bs", b"BOS "),
+    ("ca", b"CAT "),
+    ("chr", b"CHR "),
+    ("crh", b"CRT "),
+    ("cs", b"CSY "),
+    ("cv", b"CHU "),
+    ("cy", b"WEL "),
+    ("da", b"DAN "),
+    ("de", b"DEU "),
+    ("dv", b"DIV "),
+    ("dz", b"DZN "),
+    ("ee", b"EWE "),
+    ("el", b"ELL "),
+    ("en", b"ENG "),
+    ("eo", b"NTO "),
+    ("es", b"ESP "),
+    ("et", b"ETI "),
+    ("eu", b"EUQ "),
+    ("fa", b"FAR "),
+    ("ff", b"FUL "),
+    ("fi", b"FIN "),
+    ("fil", b"PIL "),
+    ("fo", b"FOS "),
+    ("fr", b"FRA "),
+    ("fy", b"FRI "),
+    ("ga", b"IRI "),
+    ("gd", b"GAE "),
+    ("gl", b"GAL "),
+    ("gn", b"GUA "),
+    ("gu", b"GUJ "),
+    ("gv", b"MNX "),
+    ("ha", b"HAU "),
+    ("haw", b"HAW "),
+    ("he", b"IWR "),
+    ("hi", b"HIN "),
+    ("hr", b"HRV "),
+    ("ht", b"HAI "),
+    ("hu", b"HUN "),
+    ("hy", b"HYE0"),
+    ("hy", b"HYE "),
+    ("id", b"IND "),
+    ("ig", b"IBO "),
+    ("is", b"ISL "),
+    ("it", b"ITA "),
+    ("iu", b"INU "),
+    ("ja", b"JAN "),
+    ("jv", b"JAV "),
+    ("ka", b"KAT "),
+    ("kab", b"KAB "),
+    ("kk", b"KAZ "),
+    ("kl", b"GRN "),
+    ("km", b"KHM "),
+    ("kn", b"KAN "),
+    ("ko", b"KOR "),
+    ("kok", b"KOK "),
+    ("ks", b"KSH "),
+    ("ku", b"KUR "),
+    ("kw", b"COR "),
+    ("ky", b"KIR "),
+    ("la", b"LAT "),
+    ("lb", b"LTZ "),
+    ("lg", b"LUG "),
+    ("ln", b"LIN "),
+    ("lo", b"LAO "),
+    ("lt", b"LTH "),
+    ("lv", b"LVI "),
+    ("mai", b"MTH "),
+    ("mg", b"MLG "),
+    ("mi", b"MRI "),
+    ("mk", b"MKD "),
+    ("ml", b"MAL "),
+    ("ml", b"MLR "),
+    ("mn", b"MNG "),
+    ("mni", b"MNI "),
+    ("moh", b"MOH "),
+    ("mr", b"MAR "),
+    ("ms", b"MLY "),
+    ("mt", b"MTS "),
+    ("my", b"BRM "),
+    ("nb", b"NOR "),
+    ("ne", b"NEP "),
+    ("nl", b"NLD "),
+    ("nn", b"NYN "),
+    ("nn", b"NOR "),
+    ("no", b"NOR "),
+    ("nv", b"NAV "),
+    ("ny", b"CHI "),
+    ("oc", b"OCI "),
+    ("om", b"ORO "),
+    ("or", b"ORI "),
+    ("pa", b"PAN "),
+    ("pl", b"PLK "),
+    ("ps", b"PAS "),
+    ("pt", b"PTG "),
+    ("rm", b"RMS "),
+    ("rn", b"RUN "),
+    ("ro", b"ROM "),
+    ("ru", b"RUS "),
+    ("rw", b"RUA "),
+    ("sa", b"SAN "),
+    ("sah", b"YAK "),
+    ("sat", b"SAT "),
+    ("sd", b"SND "),
+    ("se", b"NSM "),
+    ("sg", b"SGO "),
+    ("si", b"SNH "),
+    ("sk", b"SKY "),
+    ("sl", b"SLV "),
+    ("sm", b"SMO "),
+    ("sn", b"SNA "),
+    ("so", b"SML "),
+    ("sq", b"SQI "),
+    ("sr", b"SRB "),
+    ("ss", b"SWZ "),
+    ("st", b"SOT "),
+    ("sv", b"SVE "),
+    ("sw", b"SWK "),
+    ("syr", b"SYR "),
+    ("ta", b"TAM "),
+    ("te", b"TEL "),
+    ("tg", b"TAJ "),
+    ("th", b"THA "),
+    ("ti", b"TGY "),
+    ("tk", b"TKM "),
+    ("tl", b"PIL "),
+    ("tn", b"TNA "),
+    ("to", b"TGN "),
+    ("tr", b"TRK "),
+    ("ts", b"TSG "),
+    ("tt", b"TAT "),
+    ("tzm", b"TZM "),
+    ("ug", b"UYG "),
+    ("uk", b"UKR "),
+    ("ur", b"URD "),
+    ("uz", b"UZB "),
+    ("ve", b"VEN "),
+    ("vi", b"VIT "),
+    ("wo", b"WLF "),
+    ("xh", b"XHS "),
+    ("yi", b"JII "),
+    ("yo", b"YBA "),
+    ("zh-HK", b"ZHH "),
+    ("zh-MO", b"ZHTM"),
+    ("zh-MO", b"ZHH "),
+    ("zh-Hant", b"ZHT "),
+    ("zh-Hans", b"ZHS "),
+    ("zh", b"ZHS "),
+    ("yue", b"ZHH "),
+    ("zu", b"ZUL "),
+];
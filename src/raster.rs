@@ -0,0 +1,355 @@
+//! An anti-aliased glyph outlines rasterizer.
+//!
+//! Outlines are accepted via [`OutlineBuilder`] and converted into 8-bit coverage masks.
+//! Coverage is computed as an exact area of the flattened outline inside each pixel,
+//! by accumulating signed areas of line segments, like
+//! [font-rs](https://github.com/raphlinus/font-rs) does.
+//!
+//! Curves are flattened into lines with an error of at most 1/20 of a pixel.
+
+use core::convert::TryFrom;
+use std::vec::Vec;
+
+use crate::{DummyOutline, Face, GlyphId, OutlineBuilder};
+
+// Maximum distance between a curve and its flattened lines, in pixels.
+const FLATTENING_TOLERANCE: f32 = 0.05;
+
+// Maximum number of lines a single curve is flattened into.
+const MAX_CURVE_LINES: f32 = 1000.0;
+
+/// Maximum number of canvas pixels, which is 16 megapixels.
+///
+/// The accumulation buffer of such canvas takes 64MiB.
+pub const MAX_PIXELS: u32 = 1 << 24;
+
+/// An 8-bit coverage mask.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Mask {
+    /// A horizontal distance from the glyph origin to the left edge of the mask, in pixels.
+    pub left: i32,
+    /// A vertical distance from the baseline to the top edge of the mask, in pixels.
+    ///
+    /// Positive values are above the baseline.
+    pub top: i32,
+    /// Mask width in pixels.
+    pub width: u32,
+    /// Mask height in pixels.
+    pub height: u32,
+    /// Coverage values, row by row, starting from the top one.
+    ///
+    /// Zero is not covered and 255 is fully covered.
+    pub data: Vec<u8>,
+}
+
+impl Mask {
+    /// Returns a coverage value at the specified position.
+    #[inline]
+    pub fn get(&self, x: u32, y: u32) -> Option<u8> {
+        if x >= self.width {
+            return None;
+        }
+
+        let index = usize::try_from(u64::from(y) * u64::from(self.width) + u64::from(x)).ok()?;
+        self.data.get(index).copied()
+    }
+}
+
+#[derive(Clone, Copy, Default, Debug)]
+struct Point {
+    x: f32,
+    y: f32,
+}
+
+impl Point {
+    #[inline]
+    fn is_finite(self) -> bool {
+        self.x.is_finite() && self.y.is_finite()
+    }
+}
+
+/// An anti-aliased rasterizer.
+///
+/// Outline coordinates are mapped to a canvas as `(x * scale + dx, dy - y * scale)`.
+/// That is, the Y axis is flipped, since canvas rows go from top to bottom.
+///
+/// The non-zero winding rule is used, with the accumulated coverage being clamped.
+/// Which is exact, unless edges of differently wound contours intersect the same pixel.
+///
+/// Unclosed contours are closed implicitly.
+#[derive(Clone)]
+pub struct Rasterizer {
+    width: usize,
+    height: usize,
+    scale: f32,
+    dx: f32,
+    dy: f32,
+    // An extra cell after each row is shared with the next row,
+    // therefore the buffer has two more cells.
+    accumulation: Vec<f32>,
+    start: Point,
+    last: Point,
+}
+
+impl core::fmt::Debug for Rasterizer {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "Rasterizer {{ ... }}")
+    }
+}
+
+impl Rasterizer {
+    /// Creates a new rasterizer with a `width` x `height` canvas and an identity transform.
+    ///
+    /// A canvas with more than [`MAX_PIXELS`] pixels will be empty.
+    pub fn new(width: u32, height: u32) -> Self {
+        let (width, height) = match width.checked_mul(height) {
+            Some(n) if n <= MAX_PIXELS => (width as usize, height as usize),
+            _ => (0, 0),
+        };
+
+        Rasterizer {
+            width,
+            height,
+            scale: 1.0,
+            dx: 0.0,
+            dy: 0.0,
+            accumulation: vec![0.0; width * height + 2],
+            start: Point::default(),
+            last: Point::default(),
+        }
+    }
+
+    /// Sets a transform applied to all following outline coordinates.
+    #[inline]
+    pub fn set_transform(&mut self, scale: f32, dx: f32, dy: f32) {
+        self.scale = scale;
+        self.dx = dx;
+        self.dy = dy;
+    }
+
+    /// Returns the coverage mask, row by row, starting from the top one.
+    pub fn finish(mut self) -> Vec<u8> {
+        self.close();
+
+        let mut coverage = 0.0f32;
+        self.accumulation
+            .iter()
+            .take(self.width * self.height)
+            .map(|area| {
+                coverage += area;
+                (coverage.abs().min(1.0) * 255.0 + 0.5) as u8
+            })
+            .collect()
+    }
+
+    #[inline]
+    fn map(&self, x: f32, y: f32) -> Point {
+        Point {
+            x: x * self.scale + self.dx,
+            y: self.dy - y * self.scale,
+        }
+    }
+
+    #[inline]
+    fn add(&mut self, index: usize, area: f32) {
+        if let Some(cell) = self.accumulation.get_mut(index) {
+            *cell += area;
+        }
+    }
+
+    fn line(&mut self, p0: Point, p1: Point) {
+        if p0.y == p1.y || !p0.is_finite() || !p1.is_finite() {
+            return;
+        }
+
+        let (dir, p0, p1) = if p0.y < p1.y {
+            (1.0, p0, p1)
+        } else {
+            (-1.0, p1, p0)
+        };
+
+        let width = self.width as f32;
+        let height = self.height as f32;
+        let dxdy = (p1.x - p0.x) / (p1.y - p0.y);
+        let mut x = p0.x;
+        if p0.y < 0.0 {
+            x -= p0.y * dxdy;
+        }
+
+        let y_start = p0.y.max(0.0).min(height) as usize;
+        let y_end = p1.y.ceil().max(0.0).min(height) as usize;
+        for y in y_start..y_end {
+            let row = y * self.width;
+            let dy = ((y + 1) as f32).min(p1.y) - (y as f32).max(p0.y);
+            let x_next = x + dxdy * dy;
+            let area = dy * dir;
+
+            // Coverage outside the canvas is accumulated into the edge columns.
+            let xa = x.max(0.0).min(width);
+            let xb = x_next.max(0.0).min(width);
+            let (x0, x1) = if xa < xb { (xa, xb) } else { (xb, xa) };
+
+            let x0_floor = x0.floor();
+            let x0i = x0_floor as usize;
+            let x1_ceil = x1.ceil();
+            let x1i = x1_ceil as usize;
+            if x1i <= x0i + 1 {
+                // The segment is within a single pixel.
+                let xmf = 0.5 * (x0 + x1) - x0_floor;
+                self.add(row + x0i, area - area * xmf);
+                self.add(row + x0i + 1, area * xmf);
+            } else {
+                let s = (x1 - x0).recip();
+                let x0f = x0 - x0_floor;
+                let a0 = 0.5 * s * (1.0 - x0f) * (1.0 - x0f);
+                let x1f = x1 - x1_ceil + 1.0;
+                let am = 0.5 * s * x1f * x1f;
+                self.add(row + x0i, area * a0);
+                if x1i == x0i + 2 {
+                    self.add(row + x0i + 1, area * (1.0 - a0 - am));
+                } else {
+                    let a1 = s * (1.5 - x0f);
+                    self.add(row + x0i + 1, area * (a1 - a0));
+                    for xi in x0i + 2..x1i - 1 {
+                        self.add(row + xi, area * s);
+                    }
+
+                    let a2 = a1 + (x1i - x0i - 3) as f32 * s;
+                    self.add(row + x1i - 1, area * (1.0 - a2 - am));
+                }
+
+                self.add(row + x1i, area * am);
+            }
+
+            x = x_next;
+        }
+    }
+
+    // Splits a curve into lines of equal parametric length.
+    // The number of lines is derived from a maximum length of the curve's second derivative.
+    fn flatten(&mut self, p0: Point, p: Point, max_dd: f32, eval: impl Fn(f32) -> Point) {
+        let n = (max_dd / (8.0 * FLATTENING_TOLERANCE)).sqrt().ceil();
+        if n.is_nan() || n <= 1.0 {
+            self.line(p0, p);
+            return;
+        }
+
+        let n = n.min(MAX_CURVE_LINES) as usize;
+        let step = 1.0 / n as f32;
+        let mut prev = p0;
+        for i in 1..n {
+            let next = eval(i as f32 * step);
+            self.line(prev, next);
+            prev = next;
+        }
+
+        self.line(prev, p);
+    }
+}
+
+impl OutlineBuilder for Rasterizer {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.close();
+        self.start = self.map(x, y);
+        self.last = self.start;
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        let p = self.map(x, y);
+        self.line(self.last, p);
+        self.last = p;
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        let p0 = self.last;
+        let p1 = self.map(x1, y1);
+        let p = self.map(x, y);
+
+        let dev_x = p0.x - 2.0 * p1.x + p.x;
+        let dev_y = p0.y - 2.0 * p1.y + p.y;
+        let max_dd = 2.0 * (dev_x * dev_x + dev_y * dev_y).sqrt();
+        self.flatten(p0, p, max_dd, |t| {
+            let mt = 1.0 - t;
+            Point {
+                x: mt * mt * p0.x + 2.0 * mt * t * p1.x + t * t * p.x,
+                y: mt * mt * p0.y + 2.0 * mt * t * p1.y + t * t * p.y,
+            }
+        });
+
+        self.last = p;
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let p0 = self.last;
+        let p1 = self.map(x1, y1);
+        let p2 = self.map(x2, y2);
+        let p = self.map(x, y);
+
+        let dev1_x = p0.x - 2.0 * p1.x + p2.x;
+        let dev1_y = p0.y - 2.0 * p1.y + p2.y;
+        let dev2_x = p1.x - 2.0 * p2.x + p.x;
+        let dev2_y = p1.y - 2.0 * p2.y + p.y;
+        let dev = (dev1_x * dev1_x + dev1_y * dev1_y).max(dev2_x * dev2_x + dev2_y * dev2_y);
+        let max_dd = 6.0 * dev.sqrt();
+        self.flatten(p0, p, max_dd, |t| {
+            let mt = 1.0 - t;
+            let a = mt * mt * mt;
+            let b = 3.0 * mt * mt * t;
+            let c = 3.0 * mt * t * t;
+            let d = t * t * t;
+            Point {
+                x: a * p0.x + b * p1.x + c * p2.x + d * p.x,
+                y: a * p0.y + b * p1.y + c * p2.y + d * p.y,
+            }
+        });
+
+        self.last = p;
+    }
+
+    fn close(&mut self) {
+        self.line(self.last, self.start);
+        self.last = self.start;
+    }
+}
+
+pub(crate) fn rasterize_glyph(
+    face: &Face,
+    glyph_id: GlyphId,
+    pixels_per_em: f32,
+    offset: (f32, f32),
+) -> Option<Mask> {
+    let scale = pixels_per_em / f32::from(face.units_per_em());
+    if scale <= 0.0 || !scale.is_finite() || !offset.0.is_finite() || !offset.1.is_finite() {
+        return None;
+    }
+
+    let bbox = face.outline_glyph(glyph_id, &mut DummyOutline)?;
+    let left = (f32::from(bbox.x_min) * scale + offset.0).floor();
+    let right = (f32::from(bbox.x_max) * scale + offset.0).ceil();
+    let bottom = (f32::from(bbox.y_min) * scale + offset.1).floor();
+    let top = (f32::from(bbox.y_max) * scale + offset.1).ceil();
+
+    // Checked before casting, since out of range float casts are undefined before Rust 1.45.
+    let max = MAX_PIXELS as f32;
+    let fits = |v: f32| v.abs() <= max;
+    if !(fits(left) && fits(right) && fits(bottom) && fits(top)) {
+        return None;
+    }
+
+    let width = (right - left) as u32;
+    let height = (top - bottom) as u32;
+    if width.checked_mul(height)? > MAX_PIXELS {
+        return None;
+    }
+    let mut rasterizer = Rasterizer::new(width, height);
+    rasterizer.set_transform(scale, offset.0 - left, top - offset.1);
+    face.outline_glyph(glyph_id, &mut rasterizer)?;
+
+    Some(Mask {
+        left: left as i32,
+        top: top as i32,
+        width,
+        height,
+        data: rasterizer.finish(),
+    })
+}
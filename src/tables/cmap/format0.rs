@@ -1,3 +1,4 @@
+use super::{Format, MappingRanges, Mappings};
 use crate::parser::{NumFrom, Stream};
 use crate::GlyphId;
 
@@ -44,4 +45,24 @@ impl<'a> Subtable0<'a> {
             }
         }
     }
+
+    /// Returns an iterator over all code point to glyph ID mappings in this table.
+    #[inline]
+    pub fn mappings(&self) -> Mappings<'a> {
+        Mappings::new(Format::ByteEncodingTable(*self))
+    }
+}
+
+impl MappingRanges for Subtable0<'_> {
+    fn ranges_count(&self) -> u32 {
+        1
+    }
+
+    fn range(&self, _: u32) -> Option<(u32, u32)> {
+        Some((0, 255))
+    }
+
+    fn range_glyph_index(&self, _: u32, code_point: u32) -> Option<GlyphId> {
+        self.glyph_index(code_point)
+    }
 }
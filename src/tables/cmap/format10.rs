@@ -1,3 +1,4 @@
+use super::{Format, MappingRanges, Mappings, MAX_CODE_POINT};
 use crate::parser::{LazyArray32, Stream};
 use crate::GlyphId;
 
@@ -42,4 +43,26 @@ impl<'a> Subtable10<'a> {
             }
         }
     }
+
+    /// Returns an iterator over all code point to glyph ID mappings in this table.
+    #[inline]
+    pub fn mappings(&self) -> Mappings<'a> {
+        Mappings::new(Format::TrimmedArray(*self))
+    }
+}
+
+impl MappingRanges for Subtable10<'_> {
+    fn ranges_count(&self) -> u32 {
+        1
+    }
+
+    fn range(&self, _: u32) -> Option<(u32, u32)> {
+        let start = self.first_code_point;
+        let end = start.saturating_add(self.glyphs.len().checked_sub(1)?);
+        Some((start, end.min(MAX_CODE_POINT)))
+    }
+
+    fn range_glyph_index(&self, _: u32, code_point: u32) -> Option<GlyphId> {
+        self.glyph_index(code_point)
+    }
 }
@@ -1,5 +1,6 @@
 use core::convert::TryFrom;
 
+use super::{Format, MappingRanges, Mappings, MAX_CODE_POINT};
 use crate::parser::{FromData, LazyArray32, Stream};
 use crate::GlyphId;
 
@@ -28,7 +29,7 @@ impl FromData for SequentialMapGroup {
 /// subtable.
 #[derive(Clone, Copy)]
 pub struct Subtable12<'a> {
-    groups: LazyArray32<'a, SequentialMapGroup>,
+    pub(super) groups: LazyArray32<'a, SequentialMapGroup>,
 }
 
 impl<'a> Subtable12<'a> {
@@ -73,6 +74,35 @@ impl<'a> Subtable12<'a> {
             }
         }
     }
+
+    /// Returns an iterator over all code point to glyph ID mappings in this table.
+    #[inline]
+    pub fn mappings(&self) -> Mappings<'a> {
+        Mappings::new(Format::SegmentedCoverage(*self))
+    }
+}
+
+impl MappingRanges for Subtable12<'_> {
+    fn ranges_count(&self) -> u32 {
+        self.groups.len()
+    }
+
+    fn range(&self, index: u32) -> Option<(u32, u32)> {
+        let group = self.groups.get(index)?;
+        Some((
+            group.start_char_code,
+            group.end_char_code.min(MAX_CODE_POINT),
+        ))
+    }
+
+    fn range_glyph_index(&self, index: u32, code_point: u32) -> Option<GlyphId> {
+        let group = self.groups.get(index)?;
+        let id = group
+            .start_glyph_id
+            .checked_add(code_point)?
+            .checked_sub(group.start_char_code)?;
+        u16::try_from(id).ok().map(GlyphId)
+    }
 }
 
 impl core::fmt::Debug for Subtable12<'_> {
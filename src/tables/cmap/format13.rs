@@ -3,6 +3,7 @@
 use core::convert::TryFrom;
 
 use super::format12::SequentialMapGroup;
+use super::{Format, MappingRanges, Mappings, MAX_CODE_POINT};
 use crate::parser::{LazyArray32, Stream};
 use crate::GlyphId;
 
@@ -46,6 +47,31 @@ impl<'a> Subtable13<'a> {
             }
         }
     }
+
+    /// Returns an iterator over all code point to glyph ID mappings in this table.
+    #[inline]
+    pub fn mappings(&self) -> Mappings<'a> {
+        Mappings::new(Format::ManyToOneRangeMappings(*self))
+    }
+}
+
+impl MappingRanges for Subtable13<'_> {
+    fn ranges_count(&self) -> u32 {
+        self.groups.len()
+    }
+
+    fn range(&self, index: u32) -> Option<(u32, u32)> {
+        let group = self.groups.get(index)?;
+        Some((
+            group.start_char_code,
+            group.end_char_code.min(MAX_CODE_POINT),
+        ))
+    }
+
+    fn range_glyph_index(&self, index: u32, _: u32) -> Option<GlyphId> {
+        let group = self.groups.get(index)?;
+        u16::try_from(group.start_glyph_id).ok().map(GlyphId)
+    }
 }
 
 impl core::fmt::Debug for Subtable13<'_> {
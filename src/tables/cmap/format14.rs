@@ -22,52 +22,184 @@ impl FromData for VariationSelectorRecord {
     }
 }
 
-#[derive(Clone, Copy)]
-struct UVSMappingRecord {
-    unicode_value: u32,
-    glyph_id: GlyphId,
+/// A non-default UVS mapping.
+///
+/// A variation sequence of `code_point` and a variation selector
+/// is mapped to a glyph that is different from the `code_point` default glyph.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct UvsMapping {
+    /// A base character code point.
+    pub code_point: u32,
+    /// A glyph ID of the variation sequence.
+    pub glyph_id: GlyphId,
 }
 
-impl FromData for UVSMappingRecord {
+impl FromData for UvsMapping {
     const SIZE: usize = 5;
 
     #[inline]
     fn parse(data: &[u8]) -> Option<Self> {
         let mut s = Stream::new(data);
-        Some(UVSMappingRecord {
-            unicode_value: s.read::<U24>()?.0,
+        Some(UvsMapping {
+            code_point: s.read::<U24>()?.0,
             glyph_id: s.read::<GlyphId>()?,
         })
     }
 }
 
-#[derive(Clone, Copy)]
-struct UnicodeRangeRecord {
-    start_unicode_value: u32,
-    additional_count: u8,
+/// A default UVS range.
+///
+/// Variation sequences of base characters from this range
+/// use the default glyphs of these characters.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct UnicodeRange {
+    /// The first code point in the range.
+    pub start: u32,
+    /// The number of code points in the range after the first one.
+    pub additional_count: u8,
 }
 
-impl UnicodeRangeRecord {
-    fn contains(&self, c: u32) -> bool {
-        // Never overflows, since `start_unicode_value` is actually u24.
-        let end = self.start_unicode_value + u32::from(self.additional_count);
-        (self.start_unicode_value..=end).contains(&c)
+impl UnicodeRange {
+    /// Returns the last code point in the range.
+    #[inline]
+    pub fn end(&self) -> u32 {
+        // Never overflows, since `start` is actually u24.
+        self.start + u32::from(self.additional_count)
+    }
+
+    /// Checks that the range contains the specified code point.
+    #[inline]
+    pub fn contains(&self, code_point: u32) -> bool {
+        (self.start..=self.end()).contains(&code_point)
     }
 }
 
-impl FromData for UnicodeRangeRecord {
+impl FromData for UnicodeRange {
     const SIZE: usize = 4;
 
     #[inline]
     fn parse(data: &[u8]) -> Option<Self> {
         let mut s = Stream::new(data);
-        Some(UnicodeRangeRecord {
-            start_unicode_value: s.read::<U24>()?.0,
+        Some(UnicodeRange {
+            start: s.read::<U24>()?.0,
             additional_count: s.read::<u8>()?,
         })
     }
 }
 
+/// A variation selector with all its variation sequences.
+#[derive(Clone, Copy, Debug)]
+pub struct VariationSelector<'a> {
+    /// A variation selector code point, like `U+FE0F`.
+    pub var_selector: u32,
+    /// Base characters which sequences use default glyphs.
+    pub default_uvs: LazyArray32<'a, UnicodeRange>,
+    /// Base characters which sequences use non-default glyphs.
+    ///
+    /// Sorted by code point.
+    pub non_default_uvs: LazyArray32<'a, UvsMapping>,
+}
+
+impl<'a> VariationSelector<'a> {
+    /// Checks that the variation sequence of `code_point` and this selector is supported.
+    pub fn contains(&self, code_point: u32) -> bool {
+        self.default_uvs.into_iter().any(|r| r.contains(code_point))
+            || self
+                .non_default_uvs
+                .binary_search_by(|v| v.code_point.cmp(&code_point))
+                .is_some()
+    }
+}
+
+/// A list of [`VariationSelector`]s.
+#[derive(Clone, Copy)]
+pub struct VariationSelectors<'a> {
+    records: LazyArray32<'a, VariationSelectorRecord>,
+    // The whole subtable data.
+    data: &'a [u8],
+}
+
+impl<'a> VariationSelectors<'a> {
+    /// Returns a variation selector at index.
+    ///
+    /// Malformed UVS lists are returned as empty.
+    pub fn get(&self, index: u32) -> Option<VariationSelector<'a>> {
+        let record = self.records.get(index)?;
+        Some(VariationSelector {
+            var_selector: record.var_selector,
+            default_uvs: record
+                .default_uvs_offset
+                .and_then(|offset| parse_uvs_array(self.data, offset))
+                .unwrap_or_default(),
+            non_default_uvs: record
+                .non_default_uvs_offset
+                .and_then(|offset| parse_uvs_array(self.data, offset))
+                .unwrap_or_default(),
+        })
+    }
+
+    /// Returns a variation selector with the specified code point.
+    pub fn find(&self, var_selector: u32) -> Option<VariationSelector<'a>> {
+        let (index, _) = self
+            .records
+            .binary_search_by(|v| v.var_selector.cmp(&var_selector))?;
+        self.get(index)
+    }
+
+    /// Returns the number of variation selectors.
+    pub fn len(&self) -> u32 {
+        self.records.len()
+    }
+
+    /// Checks if there are any variation selectors.
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+}
+
+impl core::fmt::Debug for VariationSelectors<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "VariationSelectors {{ ... }}")
+    }
+}
+
+impl<'a> IntoIterator for VariationSelectors<'a> {
+    type Item = VariationSelector<'a>;
+    type IntoIter = VariationSelectorsIter<'a>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        VariationSelectorsIter {
+            selectors: self,
+            index: 0,
+        }
+    }
+}
+
+/// An iterator over [`VariationSelectors`].
+#[derive(Clone, Copy)]
+#[allow(missing_debug_implementations)]
+pub struct VariationSelectorsIter<'a> {
+    selectors: VariationSelectors<'a>,
+    index: u32,
+}
+
+impl<'a> Iterator for VariationSelectorsIter<'a> {
+    type Item = VariationSelector<'a>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.index = self.index.checked_add(1)?;
+        self.selectors.get(self.index - 1)
+    }
+}
+
+fn parse_uvs_array<T: FromData>(data: &[u8], offset: Offset32) -> Option<LazyArray32<'_, T>> {
+    let mut s = Stream::new_at(data, offset.to_usize())?;
+    let count = s.read::<u32>()?;
+    s.read_array32::<T>(count)
+}
+
 /// A result of a variation glyph mapping.
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum GlyphVariationResult {
@@ -80,6 +212,71 @@ pub enum GlyphVariationResult {
     UseDefault,
 }
 
+/// A result of a variation sequence lookup with the default glyph resolved.
+///
+/// Returned by [`Face::glyph_variation`](crate::Face::glyph_variation).
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum GlyphVariation {
+    /// The font maps the variation sequence to a glyph explicitly.
+    Explicit(GlyphId),
+    /// The font supports the variation sequence, but it uses
+    /// the default glyph of the base character.
+    Default(GlyphId),
+    /// The font doesn't support the variation sequence,
+    /// so the default glyph of the base character is used.
+    Fallback(GlyphId),
+}
+
+impl GlyphVariation {
+    /// Returns the resolved glyph ID.
+    #[inline]
+    pub fn glyph_id(&self) -> GlyphId {
+        match *self {
+            GlyphVariation::Explicit(id) => id,
+            GlyphVariation::Default(id) => id,
+            GlyphVariation::Fallback(id) => id,
+        }
+    }
+
+    /// Checks that the font supports the variation sequence.
+    #[inline]
+    pub fn is_supported(&self) -> bool {
+        !matches!(self, GlyphVariation::Fallback(_))
+    }
+}
+
+/// An emoji presentation style.
+///
+/// See [Unicode Technical Standard #51](https://unicode.org/reports/tr51/#Emoji_Variation_Sequences).
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Presentation {
+    /// A text presentation, requested by VS15 (`U+FE0E`).
+    Text,
+    /// An emoji presentation, requested by VS16 (`U+FE0F`).
+    Emoji,
+}
+
+impl Presentation {
+    /// Returns a presentation requested by a variation selector.
+    #[inline]
+    pub fn from_selector(c: char) -> Option<Self> {
+        match c {
+            '\u{FE0E}' => Some(Presentation::Text),
+            '\u{FE0F}' => Some(Presentation::Emoji),
+            _ => None,
+        }
+    }
+
+    /// Returns a variation selector of this presentation.
+    #[inline]
+    pub fn selector(self) -> char {
+        match self {
+            Presentation::Text => '\u{FE0E}',
+            Presentation::Emoji => '\u{FE0F}',
+        }
+    }
+}
+
 /// A [format 14](https://docs.microsoft.com/en-us/typography/opentype/spec/cmap#format-14-unicode-variation-sequences)
 /// subtable.
 #[derive(Clone, Copy)]
@@ -100,35 +297,33 @@ impl<'a> Subtable14<'a> {
         Some(Self { records, data })
     }
 
+    /// Returns a list of variation selectors.
+    ///
+    /// Variation selectors are sorted by code point.
+    #[inline]
+    pub fn variation_selectors(&self) -> VariationSelectors<'a> {
+        VariationSelectors {
+            records: self.records,
+            data: self.data,
+        }
+    }
+
     /// Returns a glyph index for a code point.
     pub fn glyph_index(&self, code_point: u32, variation: u32) -> Option<GlyphVariationResult> {
-        let (_, record) = self
-            .records
-            .binary_search_by(|v| v.var_selector.cmp(&variation))?;
-
-        if let Some(offset) = record.default_uvs_offset {
-            let data = self.data.get(offset.to_usize()..)?;
-            let mut s = Stream::new(data);
-            let count = s.read::<u32>()?;
-            let ranges = s.read_array32::<UnicodeRangeRecord>(count)?;
-            for range in ranges {
-                if range.contains(code_point) {
-                    return Some(GlyphVariationResult::UseDefault);
-                }
-            }
-        }
+        let selector = self.variation_selectors().find(variation)?;
 
-        if let Some(offset) = record.non_default_uvs_offset {
-            let data = self.data.get(offset.to_usize()..)?;
-            let mut s = Stream::new(data);
-            let count = s.read::<u32>()?;
-            let uvs_mappings = s.read_array32::<UVSMappingRecord>(count)?;
-            let (_, mapping) =
-                uvs_mappings.binary_search_by(|v| v.unicode_value.cmp(&code_point))?;
-            return Some(GlyphVariationResult::Found(mapping.glyph_id));
+        if selector
+            .default_uvs
+            .into_iter()
+            .any(|r| r.contains(code_point))
+        {
+            return Some(GlyphVariationResult::UseDefault);
         }
 
-        None
+        let (_, mapping) = selector
+            .non_default_uvs
+            .binary_search_by(|v| v.code_point.cmp(&code_point))?;
+        Some(GlyphVariationResult::Found(mapping.glyph_id))
     }
 }
 
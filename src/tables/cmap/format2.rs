@@ -6,6 +6,7 @@
 
 use core::convert::TryFrom;
 
+use super::{Format, MappingRanges, Mappings};
 use crate::parser::{FromData, LazyArray16, Stream};
 use crate::GlyphId;
 
@@ -149,6 +150,50 @@ impl<'a> Subtable2<'a> {
 
         Some(())
     }
+
+    /// Returns an iterator over all code point to glyph ID mappings in this table.
+    #[inline]
+    pub fn mappings(&self) -> Mappings<'a> {
+        Mappings::new(Format::HighByteMappingThroughTable(*self))
+    }
+}
+
+impl MappingRanges for Subtable2<'_> {
+    fn ranges_count(&self) -> u32 {
+        256
+    }
+
+    fn range(&self, index: u32) -> Option<(u32, u32)> {
+        // Each range represents a high byte. Same logic as in `codepoints`.
+        let first_byte = u16::try_from(index).ok()?;
+        let i = self.sub_header_keys.get(first_byte)? / 8;
+        let sub_header = self.sub_headers.get(i)?;
+        let first_code = sub_header.first_code;
+        let range_end = first_code.checked_add(sub_header.entry_count)?;
+
+        if i == 0 {
+            // This is a single byte code.
+            if first_byte >= first_code && first_byte < range_end {
+                Some((u32::from(first_byte), u32::from(first_byte)))
+            } else {
+                None
+            }
+        } else {
+            if sub_header.entry_count == 0 {
+                return None;
+            }
+
+            let base = u32::from(first_byte) << 8;
+            Some((
+                base + u32::from(first_code),
+                base + u32::from(range_end) - 1,
+            ))
+        }
+    }
+
+    fn range_glyph_index(&self, _: u32, code_point: u32) -> Option<GlyphId> {
+        self.glyph_index(code_point)
+    }
 }
 
 impl core::fmt::Debug for Subtable2<'_> {
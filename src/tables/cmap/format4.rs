@@ -1,5 +1,6 @@
 use core::convert::TryFrom;
 
+use super::{Format, MappingRanges, Mappings};
 use crate::parser::{LazyArray16, Stream};
 use crate::GlyphId;
 
@@ -64,33 +65,7 @@ impl<'a> Subtable4<'a> {
                 if start_value > code_point {
                     end = index;
                 } else {
-                    let id_range_offset = self.id_range_offsets.get(index)?;
-                    let id_delta = self.id_deltas.get(index)?;
-                    if id_range_offset == 0 {
-                        return Some(GlyphId(code_point.wrapping_add(id_delta as u16)));
-                    } else if id_range_offset == 0xFFFF {
-                        // Some malformed fonts have 0xFFFF as the last offset,
-                        // which is invalid and should be ignored.
-                        return None;
-                    }
-
-                    let delta = (u32::from(code_point) - u32::from(start_value)) * 2;
-                    let delta = u16::try_from(delta).ok()?;
-
-                    let id_range_offset_pos =
-                        (self.id_range_offset_pos + usize::from(index) * 2) as u16;
-                    let pos = id_range_offset_pos.wrapping_add(delta);
-                    let pos = pos.wrapping_add(id_range_offset);
-
-                    let glyph_array_value: u16 = Stream::read_at(self.data, usize::from(pos))?;
-
-                    // 0 indicates missing glyph.
-                    if glyph_array_value == 0 {
-                        return None;
-                    }
-
-                    let glyph_id = (glyph_array_value as i16).wrapping_add(id_delta);
-                    return u16::try_from(glyph_id).ok().map(GlyphId);
+                    return self.segment_glyph_index(index, start_value, code_point);
                 }
             } else {
                 start = index + 1;
@@ -100,6 +75,40 @@ impl<'a> Subtable4<'a> {
         None
     }
 
+    fn segment_glyph_index(
+        &self,
+        index: u16,
+        start_value: u16,
+        code_point: u16,
+    ) -> Option<GlyphId> {
+        let id_range_offset = self.id_range_offsets.get(index)?;
+        let id_delta = self.id_deltas.get(index)?;
+        if id_range_offset == 0 {
+            return Some(GlyphId(code_point.wrapping_add(id_delta as u16)));
+        } else if id_range_offset == 0xFFFF {
+            // Some malformed fonts have 0xFFFF as the last offset,
+            // which is invalid and should be ignored.
+            return None;
+        }
+
+        let delta = (u32::from(code_point) - u32::from(start_value)) * 2;
+        let delta = u16::try_from(delta).ok()?;
+
+        let id_range_offset_pos = (self.id_range_offset_pos + usize::from(index) * 2) as u16;
+        let pos = id_range_offset_pos.wrapping_add(delta);
+        let pos = pos.wrapping_add(id_range_offset);
+
+        let glyph_array_value: u16 = Stream::read_at(self.data, usize::from(pos))?;
+
+        // 0 indicates missing glyph.
+        if glyph_array_value == 0 {
+            return None;
+        }
+
+        let glyph_id = (glyph_array_value as i16).wrapping_add(id_delta);
+        u16::try_from(glyph_id).ok().map(GlyphId)
+    }
+
     /// Calls `f` for each codepoint defined in this table.
     pub fn codepoints(&self, mut f: impl FnMut(u32)) {
         for (start, end) in self.start_codes.into_iter().zip(self.end_codes) {
@@ -113,6 +122,36 @@ impl<'a> Subtable4<'a> {
             }
         }
     }
+
+    /// Returns an iterator over all code point to glyph ID mappings in this table.
+    #[inline]
+    pub fn mappings(&self) -> Mappings<'a> {
+        Mappings::new(Format::SegmentMappingToDeltaValues(*self))
+    }
+}
+
+impl MappingRanges for Subtable4<'_> {
+    fn ranges_count(&self) -> u32 {
+        u32::from(self.start_codes.len())
+    }
+
+    fn range(&self, index: u32) -> Option<(u32, u32)> {
+        let index = u16::try_from(index).ok()?;
+        let start = self.start_codes.get(index)?;
+        let end = self.end_codes.get(index)?;
+        // OxFFFF value is special and indicates codes end.
+        if start == end && start == 0xFFFF {
+            return None;
+        }
+
+        Some((u32::from(start), u32::from(end)))
+    }
+
+    fn range_glyph_index(&self, index: u32, code_point: u32) -> Option<GlyphId> {
+        let index = u16::try_from(index).ok()?;
+        let start = self.start_codes.get(index)?;
+        self.segment_glyph_index(index, start, u16::try_from(code_point).ok()?)
+    }
 }
 
 impl core::fmt::Debug for Subtable4<'_> {
@@ -1,5 +1,6 @@
 use core::convert::TryFrom;
 
+use super::{Format, MappingRanges, Mappings};
 use crate::parser::{LazyArray16, Stream};
 use crate::GlyphId;
 
@@ -47,4 +48,26 @@ impl<'a> Subtable6<'a> {
             }
         }
     }
+
+    /// Returns an iterator over all code point to glyph ID mappings in this table.
+    #[inline]
+    pub fn mappings(&self) -> Mappings<'a> {
+        Mappings::new(Format::TrimmedTableMapping(*self))
+    }
+}
+
+impl MappingRanges for Subtable6<'_> {
+    fn ranges_count(&self) -> u32 {
+        1
+    }
+
+    fn range(&self, _: u32) -> Option<(u32, u32)> {
+        let start = u32::from(self.first_code_point);
+        let end = (start + u32::from(self.glyphs.len())).min(0x10000);
+        Some((start, end.checked_sub(1)?))
+    }
+
+    fn range_glyph_index(&self, _: u32, code_point: u32) -> Option<GlyphId> {
+        self.glyph_index(code_point)
+    }
 }
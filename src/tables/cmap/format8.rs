@@ -0,0 +1,84 @@
+use super::format12::{SequentialMapGroup, Subtable12};
+use super::{Format, MappingRanges, Mappings};
+use crate::parser::Stream;
+use crate::GlyphId;
+
+/// A [format 8](https://docs.microsoft.com/en-us/typography/opentype/spec/cmap#format-8-mixed-16-bit-and-32-bit-coverage)
+/// subtable.
+///
+/// Contains a mix of 16-bit and 32-bit character codes.
+/// A 32-bit code is made of two 16-bit words, with the high word being marked in `is32`.
+#[derive(Clone, Copy)]
+pub struct Subtable8<'a> {
+    is32: &'a [u8],
+    // Has the same structure as format 12.
+    inner: Subtable12<'a>,
+}
+
+impl<'a> Subtable8<'a> {
+    /// Parses a subtable from raw data.
+    pub fn parse(data: &'a [u8]) -> Option<Self> {
+        let mut s = Stream::new(data);
+        s.skip::<u16>(); // format
+        s.skip::<u16>(); // reserved
+        s.skip::<u32>(); // length
+        s.skip::<u32>(); // language
+        let is32 = s.read_bytes(8192)?;
+        let count = s.read::<u32>()?;
+        let groups = s.read_array32::<SequentialMapGroup>(count)?;
+        Some(Self {
+            is32,
+            inner: Subtable12 { groups },
+        })
+    }
+
+    /// Checks that a 16-bit word is the high word of a 32-bit character code.
+    #[inline]
+    pub fn is_32bit(&self, word: u16) -> bool {
+        let byte = self.is32.get(usize::from(word / 8)).copied().unwrap_or(0);
+        byte & (0x80 >> (word % 8)) != 0
+    }
+
+    /// Returns a glyph index for a code point.
+    ///
+    /// 32-bit character codes are not converted in any way. So when a subtable stores
+    /// UTF-16 surrogate pairs, they have to be passed as is, like `0xD83DDE00`.
+    #[inline]
+    pub fn glyph_index(&self, code_point: u32) -> Option<GlyphId> {
+        self.inner.glyph_index(code_point)
+    }
+
+    /// Calls `f` for each codepoint defined in this table.
+    #[inline]
+    pub fn codepoints(&self, f: impl FnMut(u32)) {
+        self.inner.codepoints(f)
+    }
+
+    /// Returns an iterator over all code point to glyph ID mappings in this table.
+    ///
+    /// Codes above U+10FFFF, which includes surrogate pairs, are skipped.
+    #[inline]
+    pub fn mappings(&self) -> Mappings<'a> {
+        Mappings::new(Format::MixedCoverage(*self))
+    }
+}
+
+impl MappingRanges for Subtable8<'_> {
+    fn ranges_count(&self) -> u32 {
+        self.inner.ranges_count()
+    }
+
+    fn range(&self, index: u32) -> Option<(u32, u32)> {
+        self.inner.range(index)
+    }
+
+    fn range_glyph_index(&self, index: u32, code_point: u32) -> Option<GlyphId> {
+        self.inner.range_glyph_index(index, code_point)
+    }
+}
+
+impl core::fmt::Debug for Subtable8<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "Subtable8 {{ ... }}")
+    }
+}
@@ -8,6 +8,7 @@ This module provides a low-level alternative to
 methods.
 */
 
+use crate::encoding::{encode_johab, MacEncoding};
 use crate::parser::{FromData, LazyArray16, Offset, Offset32, Stream};
 use crate::{name::PlatformId, GlyphId};
 
@@ -19,15 +20,24 @@ mod format14;
 mod format2;
 mod format4;
 mod format6;
+mod format8;
 
 pub use format0::Subtable0;
 pub use format10::Subtable10;
 pub use format12::Subtable12;
 pub use format13::Subtable13;
-pub use format14::{GlyphVariationResult, Subtable14};
+pub use format14::{
+    GlyphVariation, GlyphVariationResult, Presentation, Subtable14, UnicodeRange, UvsMapping,
+    VariationSelector, VariationSelectors, VariationSelectorsIter,
+};
 pub use format2::Subtable2;
 pub use format4::Subtable4;
 pub use format6::Subtable6;
+pub use format8::Subtable8;
+
+// Code points above the Unicode range are ignored by `Mappings`,
+// to avoid iterating over ~4 billion values in malformed subtables.
+const MAX_CODE_POINT: u32 = 0x10FFFF;
 
 /// A character encoding subtable variant.
 #[allow(missing_docs)]
@@ -37,13 +47,40 @@ pub enum Format<'a> {
     HighByteMappingThroughTable(Subtable2<'a>),
     SegmentMappingToDeltaValues(Subtable4<'a>),
     TrimmedTableMapping(Subtable6<'a>),
-    MixedCoverage, // unsupported
+    MixedCoverage(Subtable8<'a>),
     TrimmedArray(Subtable10<'a>),
     SegmentedCoverage(Subtable12<'a>),
     ManyToOneRangeMappings(Subtable13<'a>),
     UnicodeVariationSequences(Subtable14<'a>),
 }
 
+/// A legacy, non-Unicode character encoding.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum LegacyEncoding {
+    /// Windows Shift JIS (3, 2).
+    ShiftJis,
+    /// Windows PRC, also known as GBK (3, 3).
+    Prc,
+    /// Windows Big5 (3, 4).
+    Big5,
+    /// Windows Wansung (3, 5).
+    Wansung,
+    /// Windows Johab (3, 6).
+    Johab,
+    /// Macintosh with the specified
+    /// [encoding ID](https://docs.microsoft.com/en-us/typography/opentype/spec/name#macintosh-encoding-ids-script-manager-codes).
+    Macintosh(u16),
+}
+
+/// A function that converts a character into a legacy encoding.
+///
+/// Must return a character code that can be passed to [`Subtable::glyph_index`].
+/// Multi-byte codes are stored in the big-endian order,
+/// like `0x82A0` for `あ` in Shift JIS.
+///
+/// Returns `None` when the character is not present in the encoding.
+pub type Transcoder = fn(LegacyEncoding, char) -> Option<u32>;
+
 /// A character encoding subtable.
 #[derive(Clone, Copy, Debug)]
 pub struct Subtable<'a> {
@@ -84,6 +121,54 @@ impl<'a> Subtable<'a> {
         }
     }
 
+    /// Returns a legacy encoding of the subtable.
+    ///
+    /// Returns `None` for Unicode subtables and unknown encodings.
+    #[inline]
+    pub fn legacy_encoding(&self) -> Option<LegacyEncoding> {
+        match (self.platform_id, self.encoding_id) {
+            (PlatformId::Windows, 2) => Some(LegacyEncoding::ShiftJis),
+            (PlatformId::Windows, 3) => Some(LegacyEncoding::Prc),
+            (PlatformId::Windows, 4) => Some(LegacyEncoding::Big5),
+            (PlatformId::Windows, 5) => Some(LegacyEncoding::Wansung),
+            (PlatformId::Windows, 6) => Some(LegacyEncoding::Johab),
+            (PlatformId::Macintosh, id) => Some(LegacyEncoding::Macintosh(id)),
+            _ => None,
+        }
+    }
+
+    /// Maps a character to a glyph ID using a legacy encoding subtable.
+    ///
+    /// A character is converted into the subtable encoding by `transcoder` first.
+    /// When it's not set or returns `None`, a built-in conversion is used,
+    /// which supports:
+    ///
+    /// - ASCII for all encodings.
+    /// - Macintosh Roman, Central European, Cyrillic and Greek.
+    ///   Roman script subtables are assumed to be Mac Roman.
+    /// - Hangul syllables for Johab.
+    ///
+    /// Returns `None`:
+    /// - when the subtable is not a [legacy encoding](Subtable::legacy_encoding) one.
+    /// - when the character cannot be converted.
+    /// - when glyph ID is `0`.
+    pub fn legacy_glyph_index(&self, c: char, transcoder: Option<Transcoder>) -> Option<GlyphId> {
+        let encoding = self.legacy_encoding()?;
+        let code = transcoder
+            .and_then(|f| f(encoding, c))
+            .or_else(|| match encoding {
+                LegacyEncoding::Johab => encode_johab(c).map(u32::from),
+                LegacyEncoding::Macintosh(id) => match MacEncoding::from_ids(id, 0) {
+                    Some(mac_encoding) => mac_encoding.encode(c).map(u32::from),
+                    None if c.is_ascii() => Some(u32::from(c)),
+                    None => None,
+                },
+                _ if c.is_ascii() => Some(u32::from(c)),
+                _ => None,
+            })?;
+        self.glyph_index(code)
+    }
+
     /// Maps a character to a glyph ID.
     ///
     /// This is a low-level method and unlike `Face::glyph_index` it doesn't
@@ -92,7 +177,6 @@ impl<'a> Subtable<'a> {
     ///
     /// Returns `None`:
     /// - when glyph ID is `0`.
-    /// - when format is `MixedCoverage`, since it's not supported.
     /// - when format is `UnicodeVariationSequences`. Use `glyph_variation_index` instead.
     #[inline]
     pub fn glyph_index(&self, code_point: u32) -> Option<GlyphId> {
@@ -101,7 +185,7 @@ impl<'a> Subtable<'a> {
             Format::HighByteMappingThroughTable(ref subtable) => subtable.glyph_index(code_point),
             Format::SegmentMappingToDeltaValues(ref subtable) => subtable.glyph_index(code_point),
             Format::TrimmedTableMapping(ref subtable) => subtable.glyph_index(code_point),
-            Format::MixedCoverage => None,
+            Format::MixedCoverage(ref subtable) => subtable.glyph_index(code_point),
             Format::TrimmedArray(ref subtable) => subtable.glyph_index(code_point),
             Format::SegmentedCoverage(ref subtable) => subtable.glyph_index(code_point),
             Format::ManyToOneRangeMappings(ref subtable) => subtable.glyph_index(code_point),
@@ -139,22 +223,117 @@ impl<'a> Subtable<'a> {
     /// `None` because this method finds all codepoints which were _defined_ in
     /// this subtable. The subtable may still map them to glyph ID `0`.
     ///
-    /// Returns without doing anything:
-    /// - when format is `MixedCoverage`, since it's not supported.
-    /// - when format is `UnicodeVariationSequences`, since it's not supported.
+    /// Returns without doing anything when format is `UnicodeVariationSequences`,
+    /// since it's not supported.
     pub fn codepoints<F: FnMut(u32)>(&self, f: F) {
         match self.format {
             Format::ByteEncodingTable(ref subtable) => subtable.codepoints(f),
             Format::HighByteMappingThroughTable(ref subtable) => subtable.codepoints(f),
             Format::SegmentMappingToDeltaValues(ref subtable) => subtable.codepoints(f),
             Format::TrimmedTableMapping(ref subtable) => subtable.codepoints(f),
-            Format::MixedCoverage => {} // unsupported
+            Format::MixedCoverage(ref subtable) => subtable.codepoints(f),
             Format::TrimmedArray(ref subtable) => subtable.codepoints(f),
             Format::SegmentedCoverage(ref subtable) => subtable.codepoints(f),
             Format::ManyToOneRangeMappings(ref subtable) => subtable.codepoints(f),
             Format::UnicodeVariationSequences(_) => {} // unsupported
         };
     }
+
+    /// Returns an iterator over all code point to glyph ID mappings in this subtable.
+    ///
+    /// Like [`Subtable::codepoints`], this is a low-level method and it doesn't check
+    /// that the current encoding is Unicode.
+    ///
+    /// Unlike [`Subtable::codepoints`], code points mapped to glyph ID `0` are skipped.
+    /// Code points are yielded in the subtable order, which is usually ascending.
+    ///
+    /// Returns an empty iterator when format is `UnicodeVariationSequences`.
+    /// Use `Subtable14` methods instead.
+    #[inline]
+    pub fn mappings(&self) -> Mappings<'a> {
+        Mappings::new(self.format)
+    }
+}
+
+// Most subtables can be represented as a list of code point ranges.
+// This allows us to iterate mappings without binary searching each code point.
+trait MappingRanges {
+    fn ranges_count(&self) -> u32;
+    // Returns `None` for empty or malformed ranges, which should be skipped.
+    fn range(&self, index: u32) -> Option<(u32, u32)>;
+    fn range_glyph_index(&self, index: u32, code_point: u32) -> Option<GlyphId>;
+}
+
+/// An iterator over code point to glyph ID mappings in a [`Subtable`].
+#[derive(Clone, Copy)]
+#[allow(missing_debug_implementations)]
+pub struct Mappings<'a> {
+    format: Format<'a>,
+    range_index: u32,
+    next_code_point: Option<u32>,
+}
+
+impl<'a> Mappings<'a> {
+    fn new(format: Format<'a>) -> Self {
+        Mappings {
+            format,
+            range_index: 0,
+            next_code_point: None,
+        }
+    }
+}
+
+impl<'a> Iterator for Mappings<'a> {
+    type Item = (u32, GlyphId);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let ranges: &dyn MappingRanges = match self.format {
+            Format::ByteEncodingTable(ref subtable) => subtable,
+            Format::HighByteMappingThroughTable(ref subtable) => subtable,
+            Format::SegmentMappingToDeltaValues(ref subtable) => subtable,
+            Format::TrimmedTableMapping(ref subtable) => subtable,
+            Format::MixedCoverage(ref subtable) => subtable,
+            Format::TrimmedArray(ref subtable) => subtable,
+            Format::SegmentedCoverage(ref subtable) => subtable,
+            Format::ManyToOneRangeMappings(ref subtable) => subtable,
+            Format::UnicodeVariationSequences(_) => return None,
+        };
+
+        while self.range_index < ranges.ranges_count() {
+            let index = self.range_index;
+            let (start, end) = match ranges.range(index) {
+                Some(range) => range,
+                None => {
+                    self.range_index += 1;
+                    continue;
+                }
+            };
+
+            let code_point = self.next_code_point.unwrap_or(start);
+            if code_point >= end {
+                // Move to the next range. Also checked after the last code point,
+                // to prevent an overflow.
+                self.range_index += 1;
+                self.next_code_point = None;
+                if code_point > end {
+                    continue;
+                }
+            } else {
+                self.next_code_point = Some(code_point + 1);
+            }
+
+            if code_point < start {
+                continue;
+            }
+
+            match ranges.range_glyph_index(index, code_point) {
+                Some(glyph_id) if glyph_id.0 != 0 => return Some((code_point, glyph_id)),
+                _ => {}
+            }
+        }
+
+        None
+    }
 }
 
 #[derive(Clone, Copy)]
@@ -201,7 +380,7 @@ impl<'a> Subtables<'a> {
             2 => Format::HighByteMappingThroughTable(Subtable2::parse(data)?),
             4 => Format::SegmentMappingToDeltaValues(Subtable4::parse(data)?),
             6 => Format::TrimmedTableMapping(Subtable6::parse(data)?),
-            8 => Format::MixedCoverage, // unsupported
+            8 => Format::MixedCoverage(Subtable8::parse(data)?),
             10 => Format::TrimmedArray(Subtable10::parse(data)?),
             12 => Format::SegmentedCoverage(Subtable12::parse(data)?),
             13 => Format::ManyToOneRangeMappings(Subtable13::parse(data)?),
@@ -262,9 +441,78 @@ impl<'a> Iterator for SubtablesIter<'a> {
     }
 }
 
+/// An iterator over characters mapped to a glyph.
+///
+/// Created by [`Table::glyph_chars`].
+#[derive(Clone, Copy)]
+#[allow(missing_debug_implementations)]
+pub struct GlyphChars<'a> {
+    table: Table<'a>,
+    best_subtable: Option<u16>,
+    glyph_id: GlyphId,
+    position: u16, // A position in the Unicode subtables lookup order.
+    subtable_index: u16,
+    mappings: Option<Mappings<'a>>,
+}
+
+impl Iterator for GlyphChars<'_> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(ref mut mappings) = self.mappings {
+                for (code_point, glyph_id) in mappings {
+                    if glyph_id != self.glyph_id {
+                        continue;
+                    }
+
+                    // Skip code points that are resolved by a preceding subtable.
+                    let resolved = self
+                        .table
+                        .unicode_glyph_index_impl(self.best_subtable, code_point);
+                    if resolved == Some((self.subtable_index, glyph_id)) {
+                        if let Some(c) = core::char::from_u32(code_point) {
+                            return Some(c);
+                        }
+                    }
+                }
+
+                self.mappings = None;
+            }
+
+            self.best_subtable?;
+            while self.mappings.is_none() {
+                if self.position >= self.table.subtables.len() {
+                    return None;
+                }
+
+                let index = unicode_subtable_at(self.best_subtable, self.position);
+                self.position += 1;
+                if let Some(subtable) = self.table.subtables.get(index) {
+                    if subtable.is_unicode() {
+                        self.subtable_index = index;
+                        self.mappings = Some(subtable.mappings());
+                    }
+                }
+            }
+        }
+    }
+}
+
+// Returns a subtable index at the specified position of the Unicode subtables lookup order.
+// The best subtable goes first, followed by all other subtables in order.
+#[inline]
+fn unicode_subtable_at(best: Option<u16>, position: u16) -> u16 {
+    match best {
+        Some(best) if position == 0 => best,
+        Some(best) if position <= best => position - 1,
+        _ => position,
+    }
+}
+
 /// A [Character to Glyph Index Mapping Table](
 /// https://docs.microsoft.com/en-us/typography/opentype/spec/cmap).
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Default, Debug)]
 pub struct Table<'a> {
     /// A list of subtables.
     pub subtables: Subtables<'a>,
@@ -281,4 +529,126 @@ impl<'a> Table<'a> {
             subtables: Subtables { data, records },
         })
     }
+
+    /// Returns a Unicode subtable that should be used for character to glyph mapping.
+    ///
+    /// [`Face::glyph_index`](crate::Face::glyph_index) checks this subtable first,
+    /// followed by the remaining Unicode subtables in the table order.
+    /// Legacy encoding subtables are used only when this method returns `None`.
+    ///
+    /// Only subtables that are [Unicode compatible](Subtable::is_unicode) are checked,
+    /// excluding `UnicodeVariationSequences`. They are preferred in the following order:
+    ///
+    /// 1. Windows, Unicode full repertoire (3, 10)
+    /// 2. Unicode, full repertoire (0, 6)
+    /// 3. Unicode 2.0+, full repertoire (0, 4)
+    /// 4. Windows, Unicode BMP (3, 1)
+    /// 5. Unicode 2.0+, BMP only (0, 3)
+    /// 6. Unicode, ISO/IEC 10646 (0, 2)
+    /// 7. Unicode 1.1 (0, 1)
+    /// 8. Unicode 1.0 (0, 0)
+    /// 9. Unicode, any other encoding
+    ///
+    /// When multiple subtables have the same platform and encoding,
+    /// the first one is used. Malformed subtables are ignored.
+    pub fn best_unicode_subtable(&self) -> Option<Subtable<'a>> {
+        self.subtables.get(self.best_unicode_subtable_index()?)
+    }
+
+    /// Returns an index of the [best Unicode subtable](Table::best_unicode_subtable).
+    pub(crate) fn best_unicode_subtable_index(&self) -> Option<u16> {
+        let mut best: Option<(u16, u8)> = None;
+        for index in 0..self.subtables.len() {
+            let subtable = match self.subtables.get(index) {
+                Some(subtable) => subtable,
+                None => continue,
+            };
+
+            if !subtable.is_unicode()
+                || matches!(subtable.format, Format::UnicodeVariationSequences(..))
+            {
+                continue;
+            }
+
+            let rank = match (subtable.platform_id, subtable.encoding_id) {
+                (PlatformId::Windows, 10) => 0,
+                (PlatformId::Unicode, 6) => 1,
+                (PlatformId::Unicode, 4) => 2,
+                (PlatformId::Windows, 1) => 3,
+                (PlatformId::Unicode, 3) => 4,
+                (PlatformId::Unicode, 2) => 5,
+                (PlatformId::Unicode, 1) => 6,
+                (PlatformId::Unicode, 0) => 7,
+                _ => 8,
+            };
+
+            if best.map(|(_, r)| rank < r).unwrap_or(true) {
+                best = Some((index, rank));
+            }
+        }
+
+        best.map(|(index, _)| index)
+    }
+
+    // Maps a code point using the best Unicode subtable first
+    // and then the remaining Unicode subtables.
+    // Returns the index of the resolving subtable as well.
+    pub(crate) fn unicode_glyph_index_impl(
+        &self,
+        best_subtable: Option<u16>,
+        code_point: u32,
+    ) -> Option<(u16, GlyphId)> {
+        let best_subtable = best_subtable?;
+        (0..self.subtables.len()).find_map(|position| {
+            let index = unicode_subtable_at(Some(best_subtable), position);
+            let subtable = self.subtables.get(index)?;
+            if !subtable.is_unicode() {
+                return None;
+            }
+
+            subtable.glyph_index(code_point).map(|id| (index, id))
+        })
+    }
+
+    /// Returns the first [format 14](Subtable14) subtable.
+    ///
+    /// This is the subtable [`Face::glyph_variation`](crate::Face::glyph_variation) uses.
+    pub fn variation_subtable(&self) -> Option<Subtable14<'a>> {
+        self.subtables
+            .into_iter()
+            .find_map(|subtable| match subtable.format {
+                Format::UnicodeVariationSequences(table) => Some(table),
+                _ => None,
+            })
+    }
+
+    /// Maps a character to a glyph ID using legacy encoding subtables.
+    ///
+    /// Subtables are checked in order, until one of them resolves the character.
+    /// See [`Subtable::legacy_glyph_index`] for details.
+    pub fn legacy_glyph_index(&self, c: char, transcoder: Option<Transcoder>) -> Option<GlyphId> {
+        (0..self.subtables.len())
+            .filter_map(|index| self.subtables.get(index))
+            .find_map(|subtable| subtable.legacy_glyph_index(c, transcoder))
+    }
+
+    /// Returns an iterator over characters mapped to a glyph.
+    ///
+    /// Uses Unicode subtables in the same order as [`Face::glyph_index`](crate::Face::glyph_index)
+    /// and skips characters resolved by a preceding subtable, so it's a reverse of it.
+    ///
+    /// Characters are returned in the subtables order, which is usually ascending.
+    ///
+    /// This method iterates over all mappings, so it's relatively expensive.
+    #[inline]
+    pub fn glyph_chars(&self, glyph_id: GlyphId) -> GlyphChars<'a> {
+        GlyphChars {
+            table: *self,
+            best_subtable: self.best_unicode_subtable_index(),
+            glyph_id,
+            position: 0,
+            subtable_index: 0,
+            mappings: None,
+        }
+    }
 }
@@ -0,0 +1,89 @@
+//! A [Grid-fitting And Scan-conversion Procedure Table](
+//! https://docs.microsoft.com/en-us/typography/opentype/spec/gasp) implementation.
+
+use crate::parser::{FromData, LazyArray16, Stream};
+
+/// [Range gasp behavior flags](
+/// https://docs.microsoft.com/en-us/typography/opentype/spec/gasp#gasprange-record).
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub struct RenderingFlags(pub u16);
+
+#[rustfmt::skip]
+impl RenderingFlags {
+    /// Use gridfitting.
+    #[inline] pub fn gridfit(self) -> bool { self.0 & 0x0001 != 0 }
+    /// Use grayscale rendering.
+    #[inline] pub fn do_gray(self) -> bool { self.0 & 0x0002 != 0 }
+    /// Use gridfitting with ClearType symmetric smoothing.
+    #[inline] pub fn symmetric_gridfit(self) -> bool { self.0 & 0x0004 != 0 }
+    /// Use smoothing along multiple axes with ClearType.
+    #[inline] pub fn symmetric_smoothing(self) -> bool { self.0 & 0x0008 != 0 }
+}
+
+/// A [Gasp Range](
+/// https://docs.microsoft.com/en-us/typography/opentype/spec/gasp#gasprange-record).
+#[derive(Clone, Copy, Debug)]
+pub struct Range {
+    /// Upper limit of the range, in PPEM.
+    pub max_ppem: u16,
+    /// Flags describing desired rasterizer behavior.
+    pub flags: RenderingFlags,
+}
+
+impl FromData for Range {
+    const SIZE: usize = 4;
+
+    #[inline]
+    fn parse(data: &[u8]) -> Option<Self> {
+        let mut s = Stream::new(data);
+        Some(Range {
+            max_ppem: s.read::<u16>()?,
+            flags: RenderingFlags(s.read::<u16>()?),
+        })
+    }
+}
+
+/// A [Grid-fitting And Scan-conversion Procedure Table](
+/// https://docs.microsoft.com/en-us/typography/opentype/spec/gasp).
+#[derive(Clone, Copy, Debug)]
+pub struct Table<'a> {
+    /// Table version.
+    ///
+    /// Symmetric flags are defined only since version 1.
+    pub version: u16,
+    /// A list of ranges.
+    ///
+    /// Sorted by `max_ppem`.
+    pub ranges: LazyArray16<'a, Range>,
+}
+
+impl<'a> Table<'a> {
+    /// Parses a table from raw data.
+    pub fn parse(data: &'a [u8]) -> Option<Self> {
+        let mut s = Stream::new(data);
+        let version = s.read::<u16>()?;
+        if version > 1 {
+            return None;
+        }
+
+        let count = s.read::<u16>()?;
+        let ranges = s.read_array16::<Range>(count)?;
+        Some(Table { version, ranges })
+    }
+
+    /// Returns rendering flags for the specified pixels per EM.
+    ///
+    /// Flags that are not defined for the current table version will be unset.
+    ///
+    /// Returns `None` when `ppem` is larger than the last range,
+    /// which can happen only in a malformed font.
+    pub fn flags(&self, ppem: u16) -> Option<RenderingFlags> {
+        let range = self.ranges.into_iter().find(|r| ppem <= r.max_ppem)?;
+        let mut flags = range.flags.0;
+        if self.version == 0 {
+            flags &= 0x0003;
+        }
+
+        Some(RenderingFlags(flags))
+    }
+}
@@ -1,8 +1,11 @@
 //! A [Glyph Definition Table](
 //! https://docs.microsoft.com/en-us/typography/opentype/spec/gdef) implementation.
 
+use crate::gpos::Device;
 use crate::opentype_layout::{Class, ClassDefinition, Coverage};
-use crate::parser::{FromSlice, LazyArray16, Offset, Offset16, Offset32, Stream};
+use crate::parser::{
+    FromSlice, LazyArray16, LazyOffsetArray16, Offset, Offset16, Offset32, Stream,
+};
 use crate::GlyphId;
 
 #[cfg(feature = "variable-fonts")]
@@ -20,6 +23,63 @@ pub enum GlyphClass {
     Component = 4,
 }
 
+/// A [Caret Value Table](
+/// https://docs.microsoft.com/en-us/typography/opentype/spec/gdef#caret-value-tables).
+#[derive(Clone, Copy, Debug)]
+pub enum CaretValue<'a> {
+    /// A coordinate along the text direction, in design units.
+    Coordinate(i16),
+    /// An index of a contour point in the glyph outline.
+    ContourPoint(u16),
+    /// A coordinate along the text direction, in design units,
+    /// adjusted by a hinting or variation device.
+    DeviceCoordinate {
+        /// A coordinate.
+        coordinate: i16,
+        /// A device table.
+        device: Option<Device<'a>>,
+    },
+}
+
+impl<'a> FromSlice<'a> for CaretValue<'a> {
+    fn parse(data: &'a [u8]) -> Option<Self> {
+        let mut s = Stream::new(data);
+        match s.read::<u16>()? {
+            1 => Some(CaretValue::Coordinate(s.read::<i16>()?)),
+            2 => Some(CaretValue::ContourPoint(s.read::<u16>()?)),
+            3 => {
+                let coordinate = s.read::<i16>()?;
+                let device = s
+                    .read::<Option<Offset16>>()?
+                    .and_then(|offset| data.get(offset.to_usize()..))
+                    .and_then(Device::parse);
+                Some(CaretValue::DeviceCoordinate { coordinate, device })
+            }
+            _ => None,
+        }
+    }
+}
+
+/// A list of caret positions within a ligature glyph, in the text direction order.
+pub type LigatureCarets<'a> = LazyOffsetArray16<'a, CaretValue<'a>>;
+
+impl<'a> FromSlice<'a> for LigatureCarets<'a> {
+    fn parse(data: &'a [u8]) -> Option<Self> {
+        Self::parse(data)
+    }
+}
+
+#[derive(Clone, Copy)]
+struct AttachPoints<'a>(LazyArray16<'a, u16>);
+
+impl<'a> FromSlice<'a> for AttachPoints<'a> {
+    fn parse(data: &'a [u8]) -> Option<Self> {
+        let mut s = Stream::new(data);
+        let count = s.read::<u16>()?;
+        s.read_array16(count).map(AttachPoints)
+    }
+}
+
 /// A [Glyph Definition Table](https://docs.microsoft.com/en-us/typography/opentype/spec/gdef).
 #[allow(missing_debug_implementations)]
 #[derive(Clone, Copy, Default)]
@@ -27,6 +87,8 @@ pub struct Table<'a> {
     glyph_classes: Option<ClassDefinition<'a>>,
     mark_attach_classes: Option<ClassDefinition<'a>>,
     mark_glyph_coverage_offsets: Option<(&'a [u8], LazyArray16<'a, Offset32>)>,
+    attachment_points: Option<(Coverage<'a>, LazyOffsetArray16<'a, AttachPoints<'a>>)>,
+    ligature_carets: Option<(Coverage<'a>, LazyOffsetArray16<'a, LigatureCarets<'a>>)>,
     #[cfg(feature = "variable-fonts")]
     variation_store: Option<ItemVariationStore<'a>>,
 }
@@ -41,8 +103,8 @@ impl<'a> Table<'a> {
         }
 
         let glyph_class_def_offset = s.read::<Option<Offset16>>()?;
-        s.skip::<Offset16>(); // attachListOffset
-        s.skip::<Offset16>(); // ligCaretListOffset
+        let attach_list_offset = s.read::<Option<Offset16>>()?;
+        let lig_caret_list_offset = s.read::<Option<Offset16>>()?;
         let mark_attach_class_def_offset = s.read::<Option<Offset16>>()?;
 
         let mut mark_glyph_sets_def_offset: Option<Offset16> = None;
@@ -69,6 +131,18 @@ impl<'a> Table<'a> {
             }
         }
 
+        if let Some(offset) = attach_list_offset {
+            if let Some(subdata) = data.get(offset.to_usize()..) {
+                table.attachment_points = parse_coverage_list(subdata);
+            }
+        }
+
+        if let Some(offset) = lig_caret_list_offset {
+            if let Some(subdata) = data.get(offset.to_usize()..) {
+                table.ligature_carets = parse_coverage_list(subdata);
+            }
+        }
+
         if let Some(offset) = mark_attach_class_def_offset {
             if let Some(subdata) = data.get(offset.to_usize()..) {
                 table.mark_attach_classes = ClassDefinition::parse(subdata);
@@ -150,6 +224,40 @@ impl<'a> Table<'a> {
         is_mark_glyph_impl(self, glyph_id, set_index).is_some()
     }
 
+    /// Returns contour point indices of glyph's attachment points according to
+    /// [Attachment Point List Table](
+    /// https://docs.microsoft.com/en-us/typography/opentype/spec/gdef#attachment-point-list-table).
+    ///
+    /// Indices are in increasing order.
+    /// Returns `None` when the glyph is not in the list.
+    #[inline]
+    pub fn attachment_points(&self, glyph_id: GlyphId) -> Option<LazyArray16<'a, u16>> {
+        let (coverage, points) = self.attachment_points?;
+        points.get(coverage.get(glyph_id)?).map(|points| points.0)
+    }
+
+    /// Returns caret positions within a ligature glyph according to
+    /// [Ligature Caret List Table](
+    /// https://docs.microsoft.com/en-us/typography/opentype/spec/gdef#ligature-caret-list-table).
+    ///
+    /// Returns `None` when the glyph is not in the list.
+    #[inline]
+    pub fn ligature_carets(&self, glyph_id: GlyphId) -> Option<LigatureCarets<'a>> {
+        let (coverage, carets) = self.ligature_carets?;
+        carets.get(coverage.get(glyph_id)?)
+    }
+
+    /// Returns the
+    /// [Item Variation Store Table](
+    /// https://docs.microsoft.com/en-us/typography/opentype/spec/gdef#item-variation-store-table).
+    ///
+    /// Used by device tables of `GDEF`, `GPOS` and `JSTF` tables.
+    #[cfg(feature = "variable-fonts")]
+    #[inline]
+    pub fn variation_store(&self) -> Option<ItemVariationStore<'a>> {
+        self.variation_store
+    }
+
     /// Returns glyph's variation delta at a specified index according to
     /// [Item Variation Store Table](
     /// https://docs.microsoft.com/en-us/typography/opentype/spec/gdef#item-variation-store-table).
@@ -166,6 +274,17 @@ impl<'a> Table<'a> {
     }
 }
 
+/// Parses a coverage-indexed list of tables, like Attachment Point List or Ligature Caret List.
+fn parse_coverage_list<'a, T: FromSlice<'a>>(
+    data: &'a [u8],
+) -> Option<(Coverage<'a>, LazyOffsetArray16<'a, T>)> {
+    let mut s = Stream::new(data);
+    let coverage = Coverage::parse(s.read_at_offset16(data)?)?;
+    let count = s.read::<u16>()?;
+    let offsets = s.read_array16(count)?;
+    Some((coverage, LazyOffsetArray16::new(data, offsets)))
+}
+
 #[inline(never)]
 fn is_mark_glyph_impl(table: &Table, glyph_id: GlyphId, set_index: Option<u16>) -> Option<()> {
     let (data, offsets) = table.mark_glyph_coverage_offsets?;
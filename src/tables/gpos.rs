@@ -6,12 +6,15 @@
 
 use core::convert::TryFrom;
 
+use crate::gdef;
+use crate::ggg::BitSet;
 use crate::opentype_layout::ChainedContextLookup;
-use crate::opentype_layout::{Class, ClassDefinition, ContextLookup, Coverage, LookupSubtable};
-use crate::parser::{
-    FromData, FromSlice, LazyArray16, LazyArray32, NumFrom, Offset, Offset16, Stream,
+use crate::opentype_layout::{
+    Class, ClassDefinition, ClassDefinitionIter, ContextLookup, Coverage, CoverageIter,
+    LayoutTable, Lookup, LookupIndex, LookupSubtable, VariationIndex,
 };
-use crate::GlyphId;
+use crate::parser::{FromData, LazyArray16, LazyArray32, NumFrom, Offset, Offset16, Stream};
+use crate::{GlyphId, Tag};
 
 /// A [Device Table](
 /// https://docs.microsoft.com/en-us/typography/opentype/spec/chapter2#devVarIdxTbls)
@@ -94,7 +97,10 @@ impl<'a> Device<'a> {
             1..=3 => {
                 let start_size = first;
                 let end_size = second;
-                let count = (1 + (end_size - start_size)) >> (4 - format);
+                // Each word stores 8, 4 or 2 deltas, depending on the format.
+                let sizes = u32::from(end_size.checked_sub(start_size)?) + 1;
+                let shift = 4 - format;
+                let count = u16::try_from((sizes + (1 << shift) - 1) >> shift).ok()?;
                 let delta_values = s.read_array16(count)?;
                 Some(Self::Hinting(HintingDevice {
                     start_size,
@@ -403,6 +409,31 @@ impl<'a> PairSet<'a> {
             ValueRecord::parse(self.data, &mut s, self.flags.1)?,
         ))
     }
+
+    /// Returns the second glyph and a [`ValueRecord`] pair at index.
+    pub fn record(&self, index: u16) -> Option<(GlyphId, ValueRecord<'a>, ValueRecord<'a>)> {
+        let start = usize::from(index) * usize::from(self.record_len);
+        let end = start + usize::from(self.record_len);
+        let mut s = Stream::new(self.data.get(start..end)?);
+        Some((
+            s.read::<GlyphId>()?,
+            ValueRecord::parse(self.data, &mut s, self.flags.0)?,
+            ValueRecord::parse(self.data, &mut s, self.flags.1)?,
+        ))
+    }
+
+    /// Returns the number of pair records.
+    #[inline]
+    pub fn len(&self) -> u16 {
+        // Cannot overflow, since the number of records was read as `u16`.
+        (self.data.len() / usize::from(self.record_len)) as u16
+    }
+
+    /// Checks if the set is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
 }
 
 impl core::fmt::Debug for PairSet<'_> {
@@ -1002,3 +1033,347 @@ impl<'a> PositioningSubtable<'a> {
         }
     }
 }
+
+/// A kerning pair produced by [`KerningPairs`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct KerningPair {
+    /// The left glyph.
+    pub left: GlyphId,
+    /// The right glyph.
+    pub right: GlyphId,
+    /// The left glyph horizontal advance adjustment, in design units.
+    pub value: i32,
+}
+
+// Maximum number of `kern` lookups used by `KerningPairs`.
+const MAX_KERN_LOOKUPS: usize = 256;
+
+/// An iterator over all kerning pairs defined by the `kern` feature.
+///
+/// Only pair adjustment lookups are taken into account,
+/// in the same way a shaper would apply them to a pair of adjacent glyphs:
+///
+/// - only the first subtable of a lookup that matches a pair is used
+/// - glyphs ignored by lookup flags, according to the `GDEF` table, are never paired
+/// - adjustments from multiple lookups are summed
+///
+/// Each pair is returned only once and pairs with a zero adjustment are skipped.
+/// Pairs are returned in a deterministic, but otherwise unspecified order.
+///
+/// Device tables are ignored.
+#[derive(Clone)]
+#[allow(missing_debug_implementations)]
+pub struct KerningPairs<'a> {
+    table: LayoutTable<'a>,
+    gdef: Option<gdef::Table<'a>>,
+    number_of_glyphs: u16,
+    // Sorted and deduplicated `kern` lookup indices.
+    lookup_indices: [LookupIndex; MAX_KERN_LOOKUPS],
+    lookups_count: usize,
+    // A position of the current lookup in `lookup_indices`.
+    lookup_position: Option<usize>,
+    lookup: Option<Lookup<'a>>,
+    subtable_index: u16,
+    subtable: Option<PairAdjustment<'a>>,
+    first_glyphs: Option<CoverageIter<'a>>,
+    first: GlyphId,
+    second_glyphs: SecondGlyphs<'a>,
+}
+
+#[derive(Clone, Copy)]
+enum SecondGlyphs<'a> {
+    None,
+    Set {
+        set: PairSet<'a>,
+        index: u16,
+    },
+    Classes {
+        iter: ClassDefinitionIter<'a>,
+        // The next glyph to check for the zero class, when it has an adjustment.
+        unassigned: Option<u16>,
+    },
+}
+
+impl<'a> KerningPairs<'a> {
+    /// Creates a new iterator over `GPOS` kerning pairs.
+    ///
+    /// `variation_index` can be found via [`LayoutTable::variation_index`].
+    ///
+    /// Returns `None` when the `kern` feature references more than 256 lookups.
+    pub fn new(
+        table: LayoutTable<'a>,
+        gdef: Option<gdef::Table<'a>>,
+        number_of_glyphs: u16,
+        variation_index: Option<VariationIndex>,
+    ) -> Option<Self> {
+        let mut selected = BitSet::new();
+        for index in 0..table.features.len() {
+            let feature = match table.resolve_feature(index, variation_index) {
+                Some(v) if v.tag == Tag::from_bytes(b"kern") => v,
+                _ => continue,
+            };
+
+            for lookup_index in feature.lookup_indices {
+                selected.insert(lookup_index);
+            }
+        }
+
+        let mut lookup_indices = [0; MAX_KERN_LOOKUPS];
+        let mut lookups_count = 0;
+        let mut next = selected.next_from(0);
+        while let Some(index) = next {
+            if lookups_count == MAX_KERN_LOOKUPS {
+                return None;
+            }
+
+            lookup_indices[lookups_count] = index;
+            lookups_count += 1;
+            next = selected.next_from(u32::from(index) + 1);
+        }
+
+        Some(KerningPairs {
+            table,
+            gdef,
+            number_of_glyphs,
+            lookup_indices,
+            lookups_count,
+            lookup_position: None,
+            lookup: None,
+            subtable_index: 0,
+            subtable: None,
+            first_glyphs: None,
+            first: GlyphId(0),
+            second_glyphs: SecondGlyphs::None,
+        })
+    }
+
+    #[inline]
+    fn kern_lookups(&self) -> &[LookupIndex] {
+        &self.lookup_indices[..self.lookups_count]
+    }
+
+    fn next_second(&mut self) -> Option<GlyphId> {
+        match self.second_glyphs {
+            SecondGlyphs::None => None,
+            SecondGlyphs::Set { set, ref mut index } => {
+                let (glyph, _, _) = set.record(*index)?;
+                *index += 1;
+                Some(glyph)
+            }
+            SecondGlyphs::Classes {
+                ref mut iter,
+                ref mut unassigned,
+            } => {
+                if let Some((glyph, _)) = iter.next() {
+                    return Some(glyph);
+                }
+
+                let classes = match self.subtable {
+                    Some(PairAdjustment::Format2 { classes, .. }) => classes.1,
+                    _ => return None,
+                };
+
+                while let Some(glyph) = *unassigned {
+                    if glyph >= self.number_of_glyphs {
+                        break;
+                    }
+
+                    *unassigned = glyph.checked_add(1);
+                    if classes.get(GlyphId(glyph)) == 0 {
+                        return Some(GlyphId(glyph));
+                    }
+                }
+
+                *unassigned = None;
+                None
+            }
+        }
+    }
+
+    fn next_first(&mut self) -> bool {
+        let (first, coverage_index) = match self.first_glyphs.as_mut().and_then(|i| i.next()) {
+            Some(v) => v,
+            None => return false,
+        };
+
+        self.first = first;
+        self.second_glyphs = match self.subtable {
+            Some(PairAdjustment::Format1 { sets, .. }) => match sets.get(coverage_index) {
+                Some(set) => SecondGlyphs::Set { set, index: 0 },
+                None => SecondGlyphs::None,
+            },
+            Some(PairAdjustment::Format2 {
+                classes, matrix, ..
+            }) => {
+                let class = classes.0.get(first);
+                let has_unassigned = matrix
+                    .get((class, 0))
+                    .filter(|(record, _)| record.x_advance != 0)
+                    .is_some();
+                SecondGlyphs::Classes {
+                    iter: classes.1.iter(),
+                    unassigned: if has_unassigned { Some(0) } else { None },
+                }
+            }
+            None => SecondGlyphs::None,
+        };
+
+        true
+    }
+
+    fn next_subtable(&mut self) -> bool {
+        let lookup = match self.lookup {
+            Some(v) => v,
+            None => return false,
+        };
+
+        while self.subtable_index < lookup.subtables.len() {
+            let index = self.subtable_index;
+            self.subtable_index += 1;
+
+            if let Some(PositioningSubtable::Pair(subtable)) = lookup.subtables.get(index) {
+                self.subtable = Some(subtable);
+                self.first_glyphs = Some(subtable.coverage().iter());
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// Returns a pair adjustment of a lookup and an index of the subtable it came from.
+    fn lookup_value(&self, lookup: &Lookup, left: GlyphId, right: GlyphId) -> Option<(u16, i16)> {
+        if self.is_ignored(lookup, left) || self.is_ignored(lookup, right) {
+            return None;
+        }
+
+        for index in 0..lookup.subtables.len() {
+            let records = match lookup.subtables.get(index) {
+                Some(PositioningSubtable::Pair(PairAdjustment::Format1 { coverage, sets })) => {
+                    coverage
+                        .get(left)
+                        .and_then(|index| sets.get(index))
+                        .and_then(|set| set.get(right))
+                }
+                Some(PositioningSubtable::Pair(PairAdjustment::Format2 {
+                    coverage,
+                    classes,
+                    matrix,
+                })) => {
+                    if !coverage.contains(left) {
+                        continue;
+                    }
+
+                    matrix.get((classes.0.get(left), classes.1.get(right)))
+                }
+                _ => None,
+            };
+
+            if let Some((record, _)) = records {
+                return Some((index, record.x_advance));
+            }
+        }
+
+        None
+    }
+
+    fn is_ignored(&self, lookup: &Lookup, glyph: GlyphId) -> bool {
+        let gdef = match self.gdef {
+            Some(v) => v,
+            None => return false,
+        };
+
+        let flags = lookup.flags;
+        match gdef.glyph_class(glyph) {
+            Some(gdef::GlyphClass::Base) => flags.ignore_base_glyphs(),
+            Some(gdef::GlyphClass::Ligature) => flags.ignore_ligatures(),
+            Some(gdef::GlyphClass::Mark) => {
+                if flags.ignore_marks() {
+                    true
+                } else if flags.use_mark_filtering_set() {
+                    !gdef.is_mark_glyph(glyph, lookup.mark_filtering_set)
+                } else {
+                    let kind = flags.mark_attachment_type();
+                    kind != 0 && gdef.glyph_mark_attachment_class(glyph) != u16::from(kind)
+                }
+            }
+            _ => false,
+        }
+    }
+
+    fn pair(&self, left: GlyphId, right: GlyphId) -> Option<KerningPair> {
+        if left.0 >= self.number_of_glyphs || right.0 >= self.number_of_glyphs {
+            return None;
+        }
+
+        let position = self.lookup_position?;
+        let (subtable_index, value) = self.lookup_value(self.lookup.as_ref()?, left, right)?;
+        // The pair is handled by a preceding subtable.
+        if subtable_index + 1 != self.subtable_index || value == 0 {
+            return None;
+        }
+
+        let mut total = i32::from(value);
+        for (other_position, &other_index) in self.kern_lookups().iter().enumerate() {
+            if other_position == position {
+                continue;
+            }
+
+            let other = match self.table.lookups.get(other_index) {
+                Some(v) => v,
+                None => continue,
+            };
+
+            let other_value = match self.lookup_value(&other, left, right) {
+                Some((_, v)) if v != 0 => v,
+                _ => continue,
+            };
+
+            // The pair was already returned by a preceding lookup.
+            if other_position < position {
+                return None;
+            }
+
+            total += i32::from(other_value);
+        }
+
+        if total == 0 {
+            return None;
+        }
+
+        Some(KerningPair {
+            left,
+            right,
+            value: total,
+        })
+    }
+}
+
+impl Iterator for KerningPairs<'_> {
+    type Item = KerningPair;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(right) = self.next_second() {
+                if let Some(pair) = self.pair(self.first, right) {
+                    return Some(pair);
+                }
+
+                continue;
+            }
+
+            if self.next_first() || self.next_subtable() {
+                continue;
+            }
+
+            let position = self.lookup_position.map(|p| p + 1).unwrap_or(0);
+            let index = *self.kern_lookups().get(position)?;
+            self.lookup_position = Some(position);
+            self.lookup = self.table.lookups.get(index);
+            self.subtable_index = 0;
+            self.subtable = None;
+            self.first_glyphs = None;
+            self.second_glyphs = SecondGlyphs::None;
+        }
+    }
+}
@@ -4,9 +4,13 @@
 // A heavily modified port of https://github.com/RazrFalcon/rustybuzz implementation
 // originally written by https://github.com/laurmaedje
 
-use crate::opentype_layout::{ChainedContextLookup, ContextLookup, Coverage, LookupSubtable};
+use crate::ggg::BitSet;
+use crate::opentype_layout::{
+    ChainedContextLookup, Class, ClassDefinition, ContextLookup, Coverage, Feature, FeatureIndex,
+    LayoutTable, LookupIndex, LookupList, LookupSubtable, SequenceLookupRecord, VariationIndex,
+};
 use crate::parser::{FromSlice, LazyArray16, LazyOffsetArray16, Stream};
-use crate::GlyphId;
+use crate::{GlyphId, Tag};
 
 /// A [Single Substitution Subtable](https://docs.microsoft.com/en-us/typography/opentype/spec/gsub#SS).
 #[allow(missing_docs)]
@@ -52,6 +56,23 @@ impl<'a> SingleSubstitution<'a> {
             Self::Format2 { coverage, .. } => *coverage,
         }
     }
+
+    /// Returns a substitute for the glyph.
+    ///
+    /// Returns `None` when the glyph is not covered.
+    pub fn substitute(&self, glyph: GlyphId) -> Option<GlyphId> {
+        match self {
+            Self::Format1 { coverage, delta } => {
+                coverage.get(glyph)?;
+                // Deltas are applied modulo 65536.
+                Some(GlyphId((i32::from(glyph.0) + i32::from(*delta)) as u16))
+            }
+            Self::Format2 {
+                coverage,
+                substitutes,
+            } => substitutes.get(coverage.get(glyph)?),
+        }
+    }
 }
 
 /// A sequence of glyphs for
@@ -290,3 +311,612 @@ impl<'a> SubstitutionSubtable<'a> {
         matches!(self, Self::ReverseChainSingle(_))
     }
 }
+
+/// A glyph alternate.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct GlyphAlternate {
+    /// A tag of the feature that produces the alternate.
+    pub feature: Tag,
+    /// An alternate glyph.
+    pub glyph: GlyphId,
+    /// An index of the alternate in the alternate set.
+    ///
+    /// Always zero for a single substitution.
+    /// Alternate substitution features select an alternate by a feature value
+    /// that is this index plus one.
+    pub index: u16,
+}
+
+/// An iterator over glyph alternates.
+///
+/// Enumerates single and alternate substitutions of the glyph in each `GSUB` feature.
+/// Contextual substitutions are ignored.
+/// Features with the same tag and lookups, which are usually shared between scripts,
+/// are visited only once.
+#[derive(Clone, Copy)]
+#[allow(missing_debug_implementations)]
+pub struct GlyphAlternates<'a> {
+    table: LayoutTable<'a>,
+    variation_index: Option<VariationIndex>,
+    glyph: GlyphId,
+    feature_index: FeatureIndex,
+    feature: Option<Feature<'a>>,
+    lookup_index: u16,
+    alternates: LazyArray16<'a, GlyphId>,
+    single: Option<GlyphId>,
+    alternate_index: u16,
+    features_sorted: bool,
+}
+
+impl<'a> GlyphAlternates<'a> {
+    /// Creates a new iterator over alternates of the glyph.
+    ///
+    /// `variation_index` can be found via [`LayoutTable::variation_index`].
+    pub fn new(
+        table: LayoutTable<'a>,
+        variation_index: Option<VariationIndex>,
+        glyph: GlyphId,
+    ) -> Self {
+        // Features must be sorted by tag, which allows checking only the neighbours
+        // for duplicates.
+        let features = table.features;
+        let features_sorted = (1..features.len()).all(|i| features.tag(i - 1) <= features.tag(i));
+
+        GlyphAlternates {
+            table,
+            variation_index,
+            glyph,
+            feature_index: 0,
+            feature: None,
+            lookup_index: 0,
+            alternates: LazyArray16::default(),
+            single: None,
+            alternate_index: 0,
+            features_sorted,
+        }
+    }
+
+    fn next_feature(&mut self) -> bool {
+        while self.feature_index < self.table.features.len() {
+            let index = self.feature_index;
+            self.feature_index += 1;
+
+            let feature = match self.table.resolve_feature(index, self.variation_index) {
+                Some(v) => v,
+                None => continue,
+            };
+
+            if !self.is_duplicate(index, &feature) {
+                self.feature = Some(feature);
+                self.lookup_index = 0;
+                return true;
+            }
+        }
+
+        false
+    }
+
+    fn is_duplicate(&self, index: FeatureIndex, feature: &Feature) -> bool {
+        let features = self.table.features;
+        let is_same = |i: FeatureIndex| {
+            self.table
+                .resolve_feature(i, self.variation_index)
+                .map(|other| {
+                    other.lookup_indices.len() == feature.lookup_indices.len()
+                        && other
+                            .lookup_indices
+                            .into_iter()
+                            .zip(feature.lookup_indices)
+                            .all(|(a, b)| a == b)
+                })
+                .unwrap_or(false)
+        };
+
+        if self.features_sorted {
+            (0..index)
+                .rev()
+                .take_while(|i| features.tag(*i) == Some(feature.tag))
+                .any(is_same)
+        } else {
+            (0..index)
+                .filter(|i| features.tag(*i) == Some(feature.tag))
+                .any(is_same)
+        }
+    }
+
+    /// Loads alternates from the next lookup of the current feature.
+    fn next_lookup(&mut self) -> bool {
+        let feature = match self.feature {
+            Some(v) => v,
+            None => return false,
+        };
+
+        while self.lookup_index < feature.lookup_indices.len() {
+            let index = feature.lookup_indices.get(self.lookup_index);
+            self.lookup_index += 1;
+
+            let lookup = match index.and_then(|index| self.table.lookups.get(index)) {
+                Some(v) => v,
+                None => continue,
+            };
+
+            // Only the first subtable that covers the glyph is applied.
+            let subtable = lookup
+                .subtables
+                .into_iter::<SubstitutionSubtable>()
+                .find(|subtable| subtable.coverage().contains(self.glyph));
+
+            self.alternate_index = 0;
+            match subtable {
+                Some(SubstitutionSubtable::Single(table)) => {
+                    self.single = table.substitute(self.glyph);
+                    return true;
+                }
+                Some(SubstitutionSubtable::Alternate(table)) => {
+                    let set = table
+                        .coverage
+                        .get(self.glyph)
+                        .and_then(|index| table.alternate_sets.get(index));
+                    if let Some(set) = set {
+                        self.alternates = set.alternates;
+                        return true;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        false
+    }
+}
+
+impl Iterator for GlyphAlternates<'_> {
+    type Item = GlyphAlternate;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let feature = self.feature.map(|f| f.tag);
+            if let (Some(glyph), Some(feature)) = (self.single.take(), feature) {
+                if glyph != self.glyph {
+                    return Some(GlyphAlternate {
+                        feature,
+                        glyph,
+                        index: 0,
+                    });
+                }
+            }
+
+            while self.alternate_index < self.alternates.len() {
+                let index = self.alternate_index;
+                self.alternate_index += 1;
+                match (self.alternates.get(index), feature) {
+                    (Some(glyph), Some(feature)) if glyph != self.glyph => {
+                        return Some(GlyphAlternate {
+                            feature,
+                            glyph,
+                            index,
+                        });
+                    }
+                    _ => {}
+                }
+            }
+
+            self.alternates = LazyArray16::default();
+            if !self.next_lookup() && !self.next_feature() {
+                return None;
+            }
+        }
+    }
+}
+
+const MAX_NESTING_LEVEL: u8 = 64;
+
+/// A set of glyph IDs.
+///
+/// Backed by a fixed-size bitset, therefore takes 8KiB and never allocates.
+#[derive(Clone)]
+pub struct GlyphSet {
+    bits: BitSet,
+    len: u32,
+}
+
+impl GlyphSet {
+    /// Creates an empty set.
+    #[inline]
+    pub fn new() -> Self {
+        GlyphSet {
+            bits: BitSet::new(),
+            len: 0,
+        }
+    }
+
+    /// Adds a glyph to the set.
+    ///
+    /// Returns `true` when the glyph was not present.
+    #[inline]
+    pub fn insert(&mut self, glyph: GlyphId) -> bool {
+        let is_new = self.bits.insert(glyph.0);
+        if is_new {
+            self.len += 1;
+        }
+
+        is_new
+    }
+
+    /// Checks that the glyph is present.
+    #[inline]
+    pub fn contains(&self, glyph: GlyphId) -> bool {
+        self.bits.contains(glyph.0)
+    }
+
+    /// Returns the number of glyphs in the set.
+    #[inline]
+    pub fn len(&self) -> u32 {
+        self.len
+    }
+
+    /// Checks that the set is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns an iterator over glyphs in ascending order.
+    #[inline]
+    pub fn iter(&self) -> GlyphSetIter<'_> {
+        GlyphSetIter { set: self, next: 0 }
+    }
+
+    fn intersects_coverage(&self, coverage: Coverage) -> bool {
+        match coverage {
+            Coverage::Format1 { glyphs } => glyphs.into_iter().any(|g| self.contains(g)),
+            Coverage::Format2 { records } => records
+                .into_iter()
+                .any(|r| self.bits.intersects_range(r.start.0, r.end.0)),
+        }
+    }
+
+    fn intersects_class(&self, classes: ClassDefinition, class: Class) -> bool {
+        match classes {
+            // Class 0 contains all glyphs not assigned to other classes.
+            _ if class == 0 => true,
+            ClassDefinition::Format1 { start, classes } => (0..classes.len())
+                .filter(|i| classes.get(*i) == Some(class))
+                .filter_map(|i| start.0.checked_add(i))
+                .any(|g| self.contains(GlyphId(g))),
+            ClassDefinition::Format2 { records } => records
+                .into_iter()
+                .any(|r| r.value == class && self.bits.intersects_range(r.start.0, r.end.0)),
+        }
+    }
+}
+
+impl Default for GlyphSet {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl core::fmt::Debug for GlyphSet {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl Extend<GlyphId> for GlyphSet {
+    fn extend<T: IntoIterator<Item = GlyphId>>(&mut self, iter: T) {
+        for glyph in iter {
+            self.insert(glyph);
+        }
+    }
+}
+
+/// An iterator over [`GlyphSet`].
+#[derive(Clone, Copy)]
+#[allow(missing_debug_implementations)]
+pub struct GlyphSetIter<'a> {
+    set: &'a GlyphSet,
+    next: u32,
+}
+
+impl Iterator for GlyphSetIter<'_> {
+    type Item = GlyphId;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.set.bits.next_from(self.next)?;
+        self.next = u32::from(index) + 1;
+        Some(GlyphId(index))
+    }
+}
+
+/// Computes a glyph closure over the specified lookups.
+///
+/// Adds to `glyphs` all glyphs that can be produced by the lookups from the glyphs
+/// that are already in the set, transitively.
+/// Lookups referenced by contextual rules are followed as well.
+///
+/// The closure is conservative: contextual rules and ligatures are assumed to match
+/// when all glyphs they require are in the set, regardless of their order.
+/// So it may include glyphs that cannot be produced by any actual text.
+pub fn closure<I>(lookups: LookupList, lookup_indices: I, glyphs: &mut GlyphSet)
+where
+    I: IntoIterator<Item = LookupIndex>,
+{
+    let mut selected = BitSet::new();
+    for index in lookup_indices {
+        selected.insert(index);
+    }
+
+    let mut visited = BitSet::new();
+    loop {
+        let len = glyphs.len();
+
+        visited.clear();
+        let mut next = 0;
+        while let Some(index) = selected.next_from(next) {
+            next = u32::from(index) + 1;
+            closure_lookup(lookups, index, glyphs, &mut visited, 0);
+        }
+
+        if glyphs.len() == len {
+            break;
+        }
+    }
+}
+
+fn closure_lookup(
+    lookups: LookupList,
+    index: LookupIndex,
+    glyphs: &mut GlyphSet,
+    visited: &mut BitSet,
+    depth: u8,
+) {
+    // Each lookup is processed only once per pass.
+    // Glyphs added after that will be picked up by the next one.
+    if depth >= MAX_NESTING_LEVEL || !visited.insert(index) {
+        return;
+    }
+
+    let lookup = match lookups.get(index) {
+        Some(v) => v,
+        None => return,
+    };
+
+    for subtable in lookup.subtables.into_iter::<SubstitutionSubtable>() {
+        match subtable {
+            SubstitutionSubtable::Context(table) => {
+                closure_context(&table, lookups, glyphs, visited, depth);
+            }
+            SubstitutionSubtable::ChainContext(table) => {
+                closure_chained_context(&table, lookups, glyphs, visited, depth);
+            }
+            _ => closure_subtable(&subtable, glyphs),
+        }
+    }
+}
+
+fn closure_subtable(subtable: &SubstitutionSubtable, glyphs: &mut GlyphSet) {
+    let coverage = subtable.coverage();
+
+    // Glyphs inserted during iteration are visited as well, when they are after the cursor.
+    let mut next = 0;
+    while let Some(index) = glyphs.bits.next_from(next) {
+        next = u32::from(index) + 1;
+        let glyph = GlyphId(index);
+        let coverage_index = match coverage.get(glyph) {
+            Some(v) => v,
+            None => continue,
+        };
+
+        match subtable {
+            SubstitutionSubtable::Single(table) => {
+                if let Some(substitute) = table.substitute(glyph) {
+                    glyphs.insert(substitute);
+                }
+            }
+            SubstitutionSubtable::Multiple(table) => {
+                if let Some(sequence) = table.sequences.get(coverage_index) {
+                    glyphs.extend(sequence.substitutes);
+                }
+            }
+            SubstitutionSubtable::Alternate(table) => {
+                if let Some(set) = table.alternate_sets.get(coverage_index) {
+                    glyphs.extend(set.alternates);
+                }
+            }
+            SubstitutionSubtable::Ligature(table) => {
+                if let Some(set) = table.ligature_sets.get(coverage_index) {
+                    for ligature in set {
+                        if ligature.components.into_iter().all(|g| glyphs.contains(g)) {
+                            glyphs.insert(ligature.glyph);
+                        }
+                    }
+                }
+            }
+            SubstitutionSubtable::ReverseChainSingle(table) => {
+                let context = table
+                    .backtrack_coverages
+                    .into_iter()
+                    .chain(table.lookahead_coverages)
+                    .all(|coverage| glyphs.intersects_coverage(coverage));
+                if context {
+                    if let Some(substitute) = table.substitutes.get(coverage_index) {
+                        glyphs.insert(substitute);
+                    }
+                }
+            }
+            SubstitutionSubtable::Context(_) | SubstitutionSubtable::ChainContext(_) => {}
+        }
+    }
+}
+
+fn closure_context(
+    table: &ContextLookup,
+    lookups: LookupList,
+    glyphs: &mut GlyphSet,
+    visited: &mut BitSet,
+    depth: u8,
+) {
+    match *table {
+        ContextLookup::Format1 { coverage, sets } => {
+            let mut next = 0;
+            while let Some(index) = glyphs.bits.next_from(next) {
+                next = u32::from(index) + 1;
+                let set = match coverage.get(GlyphId(index)).and_then(|i| sets.get(i)) {
+                    Some(v) => v,
+                    None => continue,
+                };
+
+                for rule in set {
+                    if rule.input.into_iter().all(|g| glyphs.contains(GlyphId(g))) {
+                        closure_nested(rule.lookups, lookups, glyphs, visited, depth);
+                    }
+                }
+            }
+        }
+        ContextLookup::Format2 {
+            coverage,
+            classes,
+            sets,
+        } => {
+            if !glyphs.intersects_coverage(coverage) {
+                return;
+            }
+
+            // Rule sets are indexed by the class of the first glyph.
+            for class in 0..sets.len() {
+                let set = match sets.get(class) {
+                    Some(v) if glyphs.intersects_class(classes, class) => v,
+                    _ => continue,
+                };
+
+                for rule in set {
+                    let matches = rule
+                        .input
+                        .into_iter()
+                        .all(|c| glyphs.intersects_class(classes, c));
+                    if matches {
+                        closure_nested(rule.lookups, lookups, glyphs, visited, depth);
+                    }
+                }
+            }
+        }
+        ContextLookup::Format3 {
+            coverage,
+            coverages,
+            lookups: records,
+        } => {
+            let matches = core::iter::once(coverage)
+                .chain(coverages)
+                .all(|coverage| glyphs.intersects_coverage(coverage));
+            if matches {
+                closure_nested(records, lookups, glyphs, visited, depth);
+            }
+        }
+    }
+}
+
+fn closure_chained_context(
+    table: &ChainedContextLookup,
+    lookups: LookupList,
+    glyphs: &mut GlyphSet,
+    visited: &mut BitSet,
+    depth: u8,
+) {
+    match *table {
+        ChainedContextLookup::Format1 { coverage, sets } => {
+            let mut next = 0;
+            while let Some(index) = glyphs.bits.next_from(next) {
+                next = u32::from(index) + 1;
+                let set = match coverage.get(GlyphId(index)).and_then(|i| sets.get(i)) {
+                    Some(v) => v,
+                    None => continue,
+                };
+
+                for rule in set {
+                    let matches = rule
+                        .backtrack
+                        .into_iter()
+                        .chain(rule.input)
+                        .chain(rule.lookahead)
+                        .all(|g| glyphs.contains(GlyphId(g)));
+                    if matches {
+                        closure_nested(rule.lookups, lookups, glyphs, visited, depth);
+                    }
+                }
+            }
+        }
+        ChainedContextLookup::Format2 {
+            coverage,
+            backtrack_classes,
+            input_classes,
+            lookahead_classes,
+            sets,
+        } => {
+            if !glyphs.intersects_coverage(coverage) {
+                return;
+            }
+
+            // Rule sets are indexed by the input class of the first glyph.
+            for class in 0..sets.len() {
+                let set = match sets.get(class) {
+                    Some(v) if glyphs.intersects_class(input_classes, class) => v,
+                    _ => continue,
+                };
+
+                for rule in set {
+                    let matches = rule
+                        .backtrack
+                        .into_iter()
+                        .all(|c| glyphs.intersects_class(backtrack_classes, c))
+                        && rule
+                            .input
+                            .into_iter()
+                            .all(|c| glyphs.intersects_class(input_classes, c))
+                        && rule
+                            .lookahead
+                            .into_iter()
+                            .all(|c| glyphs.intersects_class(lookahead_classes, c));
+                    if matches {
+                        closure_nested(rule.lookups, lookups, glyphs, visited, depth);
+                    }
+                }
+            }
+        }
+        ChainedContextLookup::Format3 {
+            coverage,
+            backtrack_coverages,
+            input_coverages,
+            lookahead_coverages,
+            lookups: records,
+        } => {
+            let matches = core::iter::once(coverage)
+                .chain(backtrack_coverages)
+                .chain(input_coverages)
+                .chain(lookahead_coverages)
+                .all(|coverage| glyphs.intersects_coverage(coverage));
+            if matches {
+                closure_nested(records, lookups, glyphs, visited, depth);
+            }
+        }
+    }
+}
+
+fn closure_nested(
+    records: LazyArray16<SequenceLookupRecord>,
+    lookups: LookupList,
+    glyphs: &mut GlyphSet,
+    visited: &mut BitSet,
+    depth: u8,
+) {
+    for record in records {
+        closure_lookup(
+            lookups,
+            record.lookup_list_index,
+            glyphs,
+            visited,
+            depth + 1,
+        );
+    }
+}
@@ -0,0 +1,169 @@
+//! A [Horizontal Device Metrics Table](
+//! https://docs.microsoft.com/en-us/typography/opentype/spec/hdmx) implementation.
+
+use core::convert::TryFrom;
+use core::num::NonZeroU16;
+
+use crate::parser::{LazyArray16, Stream};
+use crate::GlyphId;
+
+/// A [Device Record](
+/// https://docs.microsoft.com/en-us/typography/opentype/spec/hdmx#device-records).
+#[derive(Clone, Copy, Debug)]
+pub struct DeviceRecord<'a> {
+    /// Pixel size for the following widths.
+    pub pixel_size: u8,
+    /// Maximum width.
+    pub max_width: u8,
+    /// A list of advance widths in pixels indexed by glyph ID.
+    pub widths: LazyArray16<'a, u8>,
+}
+
+impl<'a> DeviceRecord<'a> {
+    /// Returns a glyph advance width in pixels.
+    #[inline]
+    pub fn advance(&self, glyph_id: GlyphId) -> Option<u8> {
+        self.widths.get(glyph_id.0)
+    }
+}
+
+/// A list of device records.
+#[derive(Clone, Copy, Default)]
+pub struct DeviceRecords<'a> {
+    data: &'a [u8],
+    len: u16,
+    record_size: usize,
+    number_of_glyphs: u16,
+}
+
+impl<'a> DeviceRecords<'a> {
+    /// Returns a device record at index.
+    pub fn get(&self, index: u16) -> Option<DeviceRecord<'a>> {
+        if index >= self.len {
+            return None;
+        }
+
+        let start = usize::from(index).checked_mul(self.record_size)?;
+        let mut s = Stream::new_at(self.data, start)?;
+        Some(DeviceRecord {
+            pixel_size: s.read::<u8>()?,
+            max_width: s.read::<u8>()?,
+            widths: s.read_array16::<u8>(self.number_of_glyphs)?,
+        })
+    }
+
+    /// Returns a device record for the specified pixel size.
+    pub fn find(&self, pixel_size: u8) -> Option<DeviceRecord<'a>> {
+        self.into_iter().find(|r| r.pixel_size == pixel_size)
+    }
+
+    /// Returns the number of device records.
+    pub fn len(&self) -> u16 {
+        self.len
+    }
+
+    /// Checks if there are any device records.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl core::fmt::Debug for DeviceRecords<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "DeviceRecords {{ ... }}")
+    }
+}
+
+impl<'a> IntoIterator for DeviceRecords<'a> {
+    type Item = DeviceRecord<'a>;
+    type IntoIter = DeviceRecordsIter<'a>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        DeviceRecordsIter {
+            records: self,
+            index: 0,
+        }
+    }
+}
+
+/// An iterator over [`DeviceRecords`].
+#[derive(Clone, Copy)]
+#[allow(missing_debug_implementations)]
+pub struct DeviceRecordsIter<'a> {
+    records: DeviceRecords<'a>,
+    index: u16,
+}
+
+impl<'a> Iterator for DeviceRecordsIter<'a> {
+    type Item = DeviceRecord<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index < self.records.len() {
+            self.index += 1;
+            self.records.get(self.index - 1)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        usize::from(self.records.len().saturating_sub(self.index))
+    }
+}
+
+/// A [Horizontal Device Metrics Table](
+/// https://docs.microsoft.com/en-us/typography/opentype/spec/hdmx).
+#[derive(Clone, Copy, Debug)]
+pub struct Table<'a> {
+    /// A list of device records.
+    ///
+    /// Sorted by pixel size.
+    pub records: DeviceRecords<'a>,
+}
+
+impl<'a> Table<'a> {
+    /// Parses a table from raw data.
+    ///
+    /// - `number_of_glyphs` is from the `maxp` table.
+    pub fn parse(number_of_glyphs: NonZeroU16, data: &'a [u8]) -> Option<Self> {
+        let mut s = Stream::new(data);
+        let version = s.read::<u16>()?;
+        if version != 0 {
+            return None;
+        }
+
+        let count = s.read::<i16>()?;
+        let count = u16::try_from(count).ok()?;
+        let record_size = s.read::<i32>()?;
+        let record_size = usize::try_from(record_size).ok()?;
+
+        // Each record must be able to hold at least a pixel size, a max width
+        // and a width for each glyph.
+        if record_size < usize::from(number_of_glyphs.get()) + 2 {
+            return None;
+        }
+
+        // Do not check the exact length, because the last record is not always padded.
+        let data = s.tail()?;
+
+        Some(Table {
+            records: DeviceRecords {
+                data,
+                len: count,
+                record_size,
+                number_of_glyphs: number_of_glyphs.get(),
+            },
+        })
+    }
+
+    /// Returns a glyph advance width in pixels for the specified pixels per EM.
+    ///
+    /// Returns `None` when there is no device record for this size.
+    #[inline]
+    pub fn advance(&self, glyph_id: GlyphId, pixels_per_em: u16) -> Option<u8> {
+        let pixel_size = u8::try_from(pixels_per_em).ok()?;
+        self.records.find(pixel_size)?.advance(glyph_id)
+    }
+}
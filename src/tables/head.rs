@@ -13,15 +13,78 @@ pub enum IndexToLocationFormat {
     Long,
 }
 
+/// [Font header flags](https://docs.microsoft.com/en-us/typography/opentype/spec/head).
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub struct Flags(pub u16);
+
+#[rustfmt::skip]
+impl Flags {
+    /// Baseline for the face is at y=0.
+    #[inline] pub fn baseline_at_y0(self) -> bool { self.0 & (1 << 0) != 0 }
+    /// Left sidebearing point is at x=0. Relevant only for TrueType rasterizers.
+    #[inline] pub fn left_sidebearing_at_x0(self) -> bool { self.0 & (1 << 1) != 0 }
+    /// Instructions may depend on point size.
+    #[inline] pub fn instructions_depend_on_point_size(self) -> bool { self.0 & (1 << 2) != 0 }
+    /// Force PPEM to integer values for all internal scaler math.
+    #[inline] pub fn force_integer_ppem(self) -> bool { self.0 & (1 << 3) != 0 }
+    /// Instructions may alter advance width.
+    #[inline] pub fn instructions_may_alter_advance(self) -> bool { self.0 & (1 << 4) != 0 }
+    /// Font data is lossless as a result of having been subjected
+    /// to optimizing transformation and/or compression.
+    #[inline] pub fn lossless(self) -> bool { self.0 & (1 << 11) != 0 }
+    /// Font has been converted to produce compatible metrics.
+    #[inline] pub fn converted(self) -> bool { self.0 & (1 << 12) != 0 }
+    /// Font is optimized for ClearType.
+    #[inline] pub fn cleartype_optimized(self) -> bool { self.0 & (1 << 13) != 0 }
+    /// Font is a last resort font.
+    #[inline] pub fn last_resort(self) -> bool { self.0 & (1 << 14) != 0 }
+}
+
+/// [Macintosh style flags](https://docs.microsoft.com/en-us/typography/opentype/spec/head).
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub struct MacStyle(pub u16);
+
+#[rustfmt::skip]
+impl MacStyle {
+    /// Bold.
+    #[inline] pub fn bold(self) -> bool { self.0 & (1 << 0) != 0 }
+    /// Italic.
+    #[inline] pub fn italic(self) -> bool { self.0 & (1 << 1) != 0 }
+    /// Underline.
+    #[inline] pub fn underline(self) -> bool { self.0 & (1 << 2) != 0 }
+    /// Outline.
+    #[inline] pub fn outline(self) -> bool { self.0 & (1 << 3) != 0 }
+    /// Shadow.
+    #[inline] pub fn shadow(self) -> bool { self.0 & (1 << 4) != 0 }
+    /// Condensed.
+    #[inline] pub fn condensed(self) -> bool { self.0 & (1 << 5) != 0 }
+    /// Extended.
+    #[inline] pub fn extended(self) -> bool { self.0 & (1 << 6) != 0 }
+}
+
 /// A [Font Header Table](https://docs.microsoft.com/en-us/typography/opentype/spec/head).
 #[derive(Clone, Copy, Debug)]
 pub struct Table {
+    /// Font revision set by the font manufacturer.
+    pub font_revision: f32,
+    /// Font header flags.
+    pub flags: Flags,
     /// Units per EM.
     ///
     /// Guarantee to be in a 16..=16384 range.
     pub units_per_em: u16,
+    /// Creation time in seconds since the Unix epoch.
+    pub created: i64,
+    /// Modification time in seconds since the Unix epoch.
+    pub modified: i64,
     /// A bounding box that large enough to enclose any glyph from the face.
     pub global_bbox: Rect,
+    /// Macintosh style flags.
+    pub mac_style: MacStyle,
+    /// Smallest readable size in pixels.
+    pub lowest_rec_ppem: u16,
+    /// Deprecated font direction hint. Should be set to 2.
+    pub font_direction_hint: i16,
     /// An index format used by the [Index to Location Table](
     /// https://docs.microsoft.com/en-us/typography/opentype/spec/loca).
     pub index_to_location_format: IndexToLocationFormat,
@@ -38,20 +101,20 @@ impl Table {
 
         let mut s = Stream::new(data);
         s.skip::<u32>(); // version
-        s.skip::<Fixed>(); // font revision
+        let font_revision = s.read::<Fixed>()?.0;
         s.skip::<u32>(); // checksum adjustment
         s.skip::<u32>(); // magic number
-        s.skip::<u16>(); // flags
+        let flags = Flags(s.read::<u16>()?);
         let units_per_em = s.read::<u16>()?;
-        s.skip::<u64>(); // created time
-        s.skip::<u64>(); // modified time
+        let created = to_unix_time(s.read::<u64>()?);
+        let modified = to_unix_time(s.read::<u64>()?);
         let x_min = s.read::<i16>()?;
         let y_min = s.read::<i16>()?;
         let x_max = s.read::<i16>()?;
         let y_max = s.read::<i16>()?;
-        s.skip::<u16>(); // mac style
-        s.skip::<u16>(); // lowest PPEM
-        s.skip::<i16>(); // font direction hint
+        let mac_style = MacStyle(s.read::<u16>()?);
+        let lowest_rec_ppem = s.read::<u16>()?;
+        let font_direction_hint = s.read::<i16>()?;
         let index_to_location_format = s.read::<u16>()?;
 
         if !(16..=16384).contains(&units_per_em) {
@@ -65,14 +128,28 @@ impl Table {
         };
 
         Some(Table {
+            font_revision,
+            flags,
             units_per_em,
+            created,
+            modified,
             global_bbox: Rect {
                 x_min,
                 y_min,
                 x_max,
                 y_max,
             },
+            mac_style,
+            lowest_rec_ppem,
+            font_direction_hint,
             index_to_location_format,
         })
     }
 }
+
+/// Converts `LONGDATETIME` into seconds since the Unix epoch.
+fn to_unix_time(n: u64) -> i64 {
+    // Seconds between 1904-01-01 and 1970-01-01.
+    const UNIX_EPOCH_OFFSET: i64 = 2_082_844_800;
+    (n as i64).wrapping_sub(UNIX_EPOCH_OFFSET)
+}
@@ -12,6 +12,18 @@ pub struct Table {
     pub descender: i16,
     /// Face line gap.
     pub line_gap: i16,
+    /// Caret slope rise.
+    ///
+    /// Together with `caret_slope_run` defines the slope of the cursor.
+    /// 1 for a vertical caret.
+    pub caret_slope_rise: i16,
+    /// Caret slope run.
+    ///
+    /// 0 for a vertical caret.
+    pub caret_slope_run: i16,
+    /// The amount by which a slanted highlight on a glyph needs
+    /// to be shifted to produce the best appearance.
+    pub caret_offset: i16,
     /// Number of metrics in the `hmtx` table.
     pub number_of_metrics: u16,
 }
@@ -30,13 +42,24 @@ impl Table {
         let ascender = s.read::<i16>()?;
         let descender = s.read::<i16>()?;
         let line_gap = s.read::<i16>()?;
-        s.advance(24);
+        s.skip::<u16>(); // advance width max
+        s.skip::<i16>(); // min left side bearing
+        s.skip::<i16>(); // min right side bearing
+        s.skip::<i16>(); // x max extent
+        let caret_slope_rise = s.read::<i16>()?;
+        let caret_slope_run = s.read::<i16>()?;
+        let caret_offset = s.read::<i16>()?;
+        s.advance(8); // reserved
+        s.skip::<i16>(); // metric data format
         let number_of_metrics = s.read::<u16>()?;
 
         Some(Table {
             ascender,
             descender,
             line_gap,
+            caret_slope_rise,
+            caret_slope_run,
+            caret_offset,
             number_of_metrics,
         })
     }
@@ -0,0 +1,91 @@
+//! A [Ligature Caret Table](
+//! https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6lcar.html) implementation.
+
+use core::num::NonZeroU16;
+
+use crate::aat;
+use crate::parser::{LazyArray16, Stream};
+use crate::GlyphId;
+
+/// A list of caret positions within a ligature glyph.
+#[derive(Clone, Copy, Debug)]
+pub enum Carets<'a> {
+    /// Distances from the glyph origin, in font units, along the baseline.
+    Distances(LazyArray16<'a, i16>),
+    /// Indices of control points in the glyph outline.
+    ControlPoints(LazyArray16<'a, u16>),
+}
+
+impl Carets<'_> {
+    /// Returns the number of carets.
+    #[inline]
+    pub fn len(&self) -> u16 {
+        match self {
+            Carets::Distances(carets) => carets.len(),
+            Carets::ControlPoints(carets) => carets.len(),
+        }
+    }
+
+    /// Checks if there are any carets.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// A [Ligature Caret Table](
+/// https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6lcar.html).
+#[derive(Clone)]
+pub struct Table<'a> {
+    lookup: aat::Lookup<'a>,
+    data: &'a [u8], // the whole table
+    control_points: bool,
+}
+
+impl core::fmt::Debug for Table<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "Table {{ ... }}")
+    }
+}
+
+impl<'a> Table<'a> {
+    /// Parses a table from raw data.
+    ///
+    /// `number_of_glyphs` is from the `maxp` table.
+    pub fn parse(number_of_glyphs: NonZeroU16, data: &'a [u8]) -> Option<Self> {
+        let mut s = Stream::new(data);
+
+        let version = s.read::<u32>()?;
+        if version != 0x00010000 {
+            return None;
+        }
+
+        let control_points = match s.read::<u16>()? {
+            0 => false,
+            1 => true,
+            _ => return None,
+        };
+
+        Some(Table {
+            lookup: aat::Lookup::parse(number_of_glyphs, s.tail()?)?,
+            data,
+            control_points,
+        })
+    }
+
+    /// Returns caret positions for the specified ligature glyph.
+    ///
+    /// Returns `None` when the glyph is not a ligature.
+    pub fn carets(&self, glyph_id: GlyphId) -> Option<Carets<'a>> {
+        // Offset from the start of the table.
+        let offset = self.lookup.value(glyph_id)?;
+
+        let mut s = Stream::new_at(self.data, usize::from(offset))?;
+        let count = s.read::<u16>()?;
+        if self.control_points {
+            s.read_array16::<u16>(count).map(Carets::ControlPoints)
+        } else {
+            s.read_array16::<i16>(count).map(Carets::Distances)
+        }
+    }
+}
@@ -573,6 +573,35 @@ impl<'a> Kern<'a> {
     pub fn kern(&self, index: u16) -> Option<MathValue<'a>> {
         Some(self.kerns.get(index)?.get(self.data))
     }
+
+    /// Returns the kern value at the given height, in design units.
+    ///
+    /// Device corrections of correction heights are ignored.
+    /// Use [`Face::math_kern`](crate::Face::math_kern) to apply them.
+    #[inline]
+    pub fn kern_at(&self, height: f32) -> Option<MathValue<'a>> {
+        self.kern_at_with(height, |v| f32::from(v.value))
+    }
+
+    /// Returns the kern value at the given height using the specified height resolver.
+    pub(crate) fn kern_at_with(
+        &self,
+        height: f32,
+        resolve: impl Fn(MathValue<'a>) -> f32,
+    ) -> Option<MathValue<'a>> {
+        // The first kern applies below the first correction height,
+        // the last one - above the last correction height.
+        let mut index = 0;
+        while index < self.count() {
+            if height < resolve(self.height(index)?) {
+                break;
+            }
+
+            index += 1;
+        }
+
+        self.kern(index)
+    }
 }
 
 impl<'a> FromSlice<'a> for Kern<'a> {
@@ -647,6 +676,75 @@ pub struct KernInfo<'a> {
     pub bottom_left: Option<Kern<'a>>,
 }
 
+impl<'a> KernInfo<'a> {
+    /// Returns the kerning data for the specified corner.
+    #[inline]
+    pub fn corner(&self, corner: Corner) -> Option<Kern<'a>> {
+        match corner {
+            Corner::TopRight => self.top_right.clone(),
+            Corner::TopLeft => self.top_left.clone(),
+            Corner::BottomRight => self.bottom_right.clone(),
+            Corner::BottomLeft => self.bottom_left.clone(),
+        }
+    }
+}
+
+/// A glyph corner used for math kerning.
+#[allow(missing_docs)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Corner {
+    TopRight,
+    TopLeft,
+    BottomRight,
+    BottomLeft,
+}
+
+/// A script position relative to a base glyph.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ScriptPosition {
+    /// A superscript, attached to the top-right corner.
+    Superscript,
+    /// A subscript, attached to the bottom-right corner.
+    Subscript,
+    /// A presuperscript, attached to the top-left corner.
+    PreSuperscript,
+    /// A presubscript, attached to the bottom-left corner.
+    PreSubscript,
+}
+
+impl ScriptPosition {
+    /// Returns the base glyph corner used for kerning.
+    #[inline]
+    pub fn base_corner(self) -> Corner {
+        match self {
+            ScriptPosition::Superscript => Corner::TopRight,
+            ScriptPosition::Subscript => Corner::BottomRight,
+            ScriptPosition::PreSuperscript => Corner::TopLeft,
+            ScriptPosition::PreSubscript => Corner::BottomLeft,
+        }
+    }
+
+    /// Returns the script glyph corner used for kerning.
+    #[inline]
+    pub fn script_corner(self) -> Corner {
+        match self {
+            ScriptPosition::Superscript => Corner::BottomLeft,
+            ScriptPosition::Subscript => Corner::TopLeft,
+            ScriptPosition::PreSuperscript => Corner::BottomRight,
+            ScriptPosition::PreSubscript => Corner::TopRight,
+        }
+    }
+
+    /// Checks that the script is placed above the base baseline.
+    #[inline]
+    pub fn is_superscript(self) -> bool {
+        matches!(
+            self,
+            ScriptPosition::Superscript | ScriptPosition::PreSuperscript
+        )
+    }
+}
+
 /// A [Math Kern Info Table](https://docs.microsoft.com/en-us/typography/opentype/spec/math#mathkerninfo-table).
 #[derive(Clone, Copy)]
 pub struct KernInfos<'a> {
@@ -768,6 +866,91 @@ pub struct GlyphAssembly<'a> {
     pub parts: LazyArray16<'a, GlyphPart>,
 }
 
+impl<'a> GlyphAssembly<'a> {
+    /// Assembles a glyph of at least the specified size.
+    ///
+    /// Extender parts are repeated as few times as possible and the overlap
+    /// between adjacent parts is distributed evenly, but never becomes smaller
+    /// than `min_connector_overlap` or larger than the connectors allow.
+    /// So the resulting size can be larger than `target` when the assembly cannot
+    /// be shrunk enough, or smaller when there are no extenders.
+    /// Extenders are repeated at most 1024 times.
+    ///
+    /// `target` and `min_connector_overlap` are in design units.
+    ///
+    /// Returns `None` when the assembly has no parts.
+    pub fn assemble(&self, target: u32, min_connector_overlap: u16) -> Option<AssembledGlyph<'a>> {
+        let min_overlap = f32::from(min_connector_overlap);
+
+        let mut fixed_advance = 0.0;
+        let mut fixed_count = 0u32;
+        let mut extender_advance = 0.0;
+        let mut extender_count = 0u32;
+        for part in self.parts {
+            if part.part_flags.extender() {
+                extender_advance += f32::from(part.full_advance);
+                extender_count += 1;
+            } else {
+                fixed_advance += f32::from(part.full_advance);
+                fixed_count += 1;
+            }
+        }
+
+        if fixed_count + extender_count == 0 {
+            return None;
+        }
+
+        let parts_count = |repeats: u32| fixed_count + extender_count * repeats;
+        let full_size = |repeats: u32| fixed_advance + extender_advance * repeats as f32;
+        let max_size = |repeats: u32| {
+            full_size(repeats) - parts_count(repeats).saturating_sub(1) as f32 * min_overlap
+        };
+
+        let target = target as f32;
+        let mut repeats = 0;
+        let growth = max_size(1) - max_size(0);
+        if max_size(0) < target && extender_count != 0 && growth > 0.0 {
+            let missing = target - max_size(0);
+            let mut needed = (missing / growth) as u32;
+            // `f32::ceil` is not available in `no_std`.
+            if needed as f32 * growth < missing {
+                needed += 1;
+            }
+
+            repeats = needed.min(MAX_EXTENDER_REPEATS);
+        }
+
+        let mut overlap = 0.0;
+        let count = parts_count(repeats);
+        if count > 1 {
+            // The overlap cannot be larger than the shortest connector of each join.
+            let mut max_overlap: Option<u16> = None;
+            let mut prev: Option<GlyphPart> = None;
+            for part in ExpandedParts::new(self.parts, repeats as u16) {
+                if let Some(prev) = prev {
+                    let connector = prev.end_connector_length.min(part.start_connector_length);
+                    max_overlap = Some(max_overlap.map_or(connector, |v| v.min(connector)));
+                }
+
+                prev = Some(part);
+            }
+
+            let needed = (full_size(repeats) - target) / (count - 1) as f32;
+            overlap = needed
+                .min(f32::from(max_overlap.unwrap_or(0)))
+                .max(min_overlap);
+        }
+
+        Some(AssembledGlyph {
+            italics_correction: self.italics_correction,
+            parts: self.parts,
+            repeats: repeats as u16,
+            overlap,
+            size: full_size(repeats) - count.saturating_sub(1) as f32 * overlap,
+        })
+    }
+}
+
 impl<'a> FromSlice<'a> for GlyphAssembly<'a> {
     fn parse(data: &'a [u8]) -> Option<Self> {
         let mut s = Stream::new(data);
@@ -781,6 +964,9 @@ impl<'a> FromSlice<'a> for GlyphAssembly<'a> {
     }
 }
 
+// Prevents excessively long assemblies.
+const MAX_EXTENDER_REPEATS: u32 = 1024;
+
 /// Description of math glyph variants.
 #[derive(Clone, Copy, Debug)]
 pub struct GlyphVariant {
@@ -822,6 +1008,172 @@ impl<'a> FromSlice<'a> for GlyphConstruction<'a> {
     }
 }
 
+impl<'a> GlyphConstruction<'a> {
+    /// Stretches a glyph to at least the specified size.
+    ///
+    /// Chooses the smallest variant that is large enough.
+    /// When there is no such variant, [assembles](GlyphAssembly::assemble) a glyph
+    /// from parts. When there is no assembly either, the largest variant is used.
+    ///
+    /// `target` and `min_connector_overlap` are in design units.
+    ///
+    /// Returns `None` when there are no variants and no assembly.
+    pub fn stretch(&self, target: u32, min_connector_overlap: u16) -> Option<StretchedGlyph<'a>> {
+        if let Some(variant) = self
+            .variants
+            .into_iter()
+            .find(|v| u32::from(v.advance_measurement) >= target)
+        {
+            return Some(StretchedGlyph::Variant(variant));
+        }
+
+        if let Some(assembly) = self
+            .assembly
+            .and_then(|a| a.assemble(target, min_connector_overlap))
+        {
+            return Some(StretchedGlyph::Assembly(assembly));
+        }
+
+        self.variants.last().map(StretchedGlyph::Variant)
+    }
+}
+
+/// A direction in which a math glyph grows.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Direction {
+    /// Grows vertically, like parentheses.
+    Vertical,
+    /// Grows horizontally, like overbraces.
+    Horizontal,
+}
+
+/// A glyph stretched to a specific size.
+#[derive(Clone, Copy, Debug)]
+pub enum StretchedGlyph<'a> {
+    /// A prepared glyph variant.
+    Variant(GlyphVariant),
+    /// A glyph assembled from parts.
+    Assembly(AssembledGlyph<'a>),
+}
+
+/// A glyph assembled from parts to a specific size.
+#[derive(Clone, Copy, Debug)]
+pub struct AssembledGlyph<'a> {
+    /// The italics correction of the assembled glyph.
+    pub italics_correction: MathValue<'a>,
+    parts: LazyArray16<'a, GlyphPart>,
+    repeats: u16,
+    overlap: f32,
+    size: f32,
+}
+
+impl<'a> AssembledGlyph<'a> {
+    /// Returns the assembled glyph size along the growth direction, in design units.
+    #[inline]
+    pub fn size(&self) -> f32 {
+        self.size
+    }
+
+    /// Returns how many times each extender part is repeated.
+    #[inline]
+    pub fn extender_repeats(&self) -> u16 {
+        self.repeats
+    }
+
+    /// Returns the overlap between adjacent parts, in design units.
+    #[inline]
+    pub fn overlap(&self) -> f32 {
+        self.overlap
+    }
+
+    /// Returns an iterator over positioned parts.
+    ///
+    /// Parts are ordered from bottom to top or from left to right.
+    #[inline]
+    pub fn parts(&self) -> AssembledParts<'a> {
+        AssembledParts {
+            parts: ExpandedParts::new(self.parts, self.repeats),
+            overlap: self.overlap,
+            offset: 0.0,
+        }
+    }
+}
+
+/// A part of an assembled glyph.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct PositionedPart {
+    /// Glyph ID of the part.
+    pub glyph_id: GlyphId,
+    /// The part offset from the assembled glyph start along the growth direction,
+    /// in design units.
+    pub offset: f32,
+}
+
+/// An iterator over [`AssembledGlyph`] parts.
+#[derive(Clone, Copy)]
+#[allow(missing_debug_implementations)]
+pub struct AssembledParts<'a> {
+    parts: ExpandedParts<'a>,
+    overlap: f32,
+    offset: f32,
+}
+
+impl Iterator for AssembledParts<'_> {
+    type Item = PositionedPart;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let part = self.parts.next()?;
+        let offset = self.offset;
+        self.offset += f32::from(part.full_advance) - self.overlap;
+        Some(PositionedPart {
+            glyph_id: part.glyph_id,
+            offset,
+        })
+    }
+}
+
+/// Assembly parts with extenders repeated.
+#[derive(Clone, Copy)]
+struct ExpandedParts<'a> {
+    parts: LazyArray16<'a, GlyphPart>,
+    repeats: u16,
+    index: u16,
+    repeat: u16,
+}
+
+impl<'a> ExpandedParts<'a> {
+    fn new(parts: LazyArray16<'a, GlyphPart>, repeats: u16) -> Self {
+        ExpandedParts {
+            parts,
+            repeats,
+            index: 0,
+            repeat: 0,
+        }
+    }
+}
+
+impl Iterator for ExpandedParts<'_> {
+    type Item = GlyphPart;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let part = self.parts.get(self.index)?;
+            if !part.part_flags.extender() {
+                self.index += 1;
+                return Some(part);
+            }
+
+            if self.repeat < self.repeats {
+                self.repeat += 1;
+                return Some(part);
+            }
+
+            self.index += 1;
+            self.repeat = 0;
+        }
+    }
+}
+
 /// A mapping from glyphs to
 /// [Math Glyph Construction Tables](
 /// https://learn.microsoft.com/en-us/typography/opentype/spec/math#mathglyphconstruction-table).
@@ -871,6 +1223,30 @@ pub struct Variants<'a> {
     pub horizontal_constructions: GlyphConstructions<'a>,
 }
 
+impl<'a> Variants<'a> {
+    /// Stretches a glyph in the specified direction to at least the specified size.
+    ///
+    /// `target` is in design units.
+    /// See [`GlyphConstruction::stretch`] for details.
+    ///
+    /// Returns `None` when the glyph has no construction.
+    pub fn stretch(
+        &self,
+        glyph: GlyphId,
+        direction: Direction,
+        target: u32,
+    ) -> Option<StretchedGlyph<'a>> {
+        let constructions = match direction {
+            Direction::Vertical => self.vertical_constructions,
+            Direction::Horizontal => self.horizontal_constructions,
+        };
+
+        constructions
+            .get(glyph)?
+            .stretch(target, self.min_connector_overlap)
+    }
+}
+
 impl<'a> FromSlice<'a> for Variants<'a> {
     fn parse(data: &'a [u8]) -> Option<Self> {
         let mut s = Stream::new(data);
@@ -5,11 +5,47 @@ use core::num::NonZeroU16;
 
 use crate::parser::Stream;
 
+/// TrueType-specific limits from a version 1.0 [Maximum Profile Table](
+/// https://docs.microsoft.com/en-us/typography/opentype/spec/maxp#version-10).
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Limits {
+    /// Maximum points in a non-composite glyph.
+    pub max_points: u16,
+    /// Maximum contours in a non-composite glyph.
+    pub max_contours: u16,
+    /// Maximum points in a composite glyph.
+    pub max_composite_points: u16,
+    /// Maximum contours in a composite glyph.
+    pub max_composite_contours: u16,
+    /// 1 if instructions do not use the twilight zone, 2 otherwise.
+    pub max_zones: u16,
+    /// Maximum points used in the twilight zone.
+    pub max_twilight_points: u16,
+    /// Number of Storage Area locations.
+    pub max_storage: u16,
+    /// Number of function definitions.
+    pub max_function_defs: u16,
+    /// Number of instruction definitions.
+    pub max_instruction_defs: u16,
+    /// Maximum stack depth across all programs in the font.
+    pub max_stack_elements: u16,
+    /// Maximum byte count for glyph instructions.
+    pub max_size_of_instructions: u16,
+    /// Maximum number of components referenced at the top level of any composite glyph.
+    pub max_component_elements: u16,
+    /// Maximum levels of recursion. 1 for simple components.
+    pub max_component_depth: u16,
+}
+
 /// A [Maximum Profile Table](https://docs.microsoft.com/en-us/typography/opentype/spec/maxp).
 #[derive(Clone, Copy, Debug)]
 pub struct Table {
     /// The total number of glyphs in the face.
     pub number_of_glyphs: NonZeroU16,
+    /// TrueType-specific limits.
+    ///
+    /// `None` for version 0.5 tables and for truncated version 1.0 tables.
+    pub limits: Option<Limits>,
 }
 
 impl Table {
@@ -23,6 +59,36 @@ impl Table {
 
         let n = s.read::<u16>()?;
         let number_of_glyphs = NonZeroU16::new(n)?;
-        Some(Table { number_of_glyphs })
+
+        let limits = if version == 0x00010000 {
+            Limits::parse(&mut s)
+        } else {
+            None
+        };
+
+        Some(Table {
+            number_of_glyphs,
+            limits,
+        })
+    }
+}
+
+impl Limits {
+    fn parse(s: &mut Stream) -> Option<Self> {
+        Some(Limits {
+            max_points: s.read::<u16>()?,
+            max_contours: s.read::<u16>()?,
+            max_composite_points: s.read::<u16>()?,
+            max_composite_contours: s.read::<u16>()?,
+            max_zones: s.read::<u16>()?,
+            max_twilight_points: s.read::<u16>()?,
+            max_storage: s.read::<u16>()?,
+            max_function_defs: s.read::<u16>()?,
+            max_instruction_defs: s.read::<u16>()?,
+            max_stack_elements: s.read::<u16>()?,
+            max_size_of_instructions: s.read::<u16>()?,
+            max_component_elements: s.read::<u16>()?,
+            max_component_depth: s.read::<u16>()?,
+        })
     }
 }
@@ -0,0 +1,249 @@
+//! A [Metadata Table](
+//! https://docs.microsoft.com/en-us/typography/opentype/spec/meta) implementation.
+
+use core::convert::TryFrom;
+
+use crate::language::LanguageTagParts;
+use crate::parser::{FromData, LazyArray32, Offset, Offset32, Stream};
+use crate::Tag;
+
+#[derive(Clone, Copy, Debug)]
+struct DataMapRecord {
+    tag: Tag,
+    offset: Offset32,
+    length: u32,
+}
+
+impl FromData for DataMapRecord {
+    const SIZE: usize = 12;
+
+    #[inline]
+    fn parse(data: &[u8]) -> Option<Self> {
+        let mut s = Stream::new(data);
+        Some(DataMapRecord {
+            tag: s.read::<Tag>()?,
+            offset: s.read::<Offset32>()?,
+            length: s.read::<u32>()?,
+        })
+    }
+}
+
+/// A metadata entry.
+#[derive(Clone, Copy, Debug)]
+pub struct DataMap<'a> {
+    /// A metadata tag.
+    pub tag: Tag,
+    /// Raw metadata.
+    pub data: &'a [u8],
+}
+
+/// A list of metadata entries.
+#[derive(Clone, Copy, Default)]
+pub struct DataMaps<'a> {
+    records: LazyArray32<'a, DataMapRecord>,
+    data: &'a [u8], // the whole table
+}
+
+impl<'a> DataMaps<'a> {
+    /// Returns a metadata entry at index.
+    pub fn get(&self, index: u32) -> Option<DataMap<'a>> {
+        let record = self.records.get(index)?;
+        let start = record.offset.to_usize();
+        let end = start.checked_add(usize::try_from(record.length).ok()?)?;
+        Some(DataMap {
+            tag: record.tag,
+            data: self.data.get(start..end)?,
+        })
+    }
+
+    /// Returns the first metadata entry with the specified tag.
+    pub fn find(&self, tag: Tag) -> Option<DataMap<'a>> {
+        self.into_iter().find(|m| m.tag == tag)
+    }
+
+    /// Returns the number of metadata entries.
+    pub fn len(&self) -> u32 {
+        self.records.len()
+    }
+
+    /// Checks if there are any metadata entries.
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+}
+
+impl core::fmt::Debug for DataMaps<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "DataMaps {{ ... }}")
+    }
+}
+
+impl<'a> IntoIterator for DataMaps<'a> {
+    type Item = DataMap<'a>;
+    type IntoIter = DataMapsIter<'a>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        DataMapsIter {
+            maps: self,
+            index: 0,
+        }
+    }
+}
+
+/// An iterator over [`DataMaps`].
+#[derive(Clone, Copy)]
+#[allow(missing_debug_implementations)]
+pub struct DataMapsIter<'a> {
+    maps: DataMaps<'a>,
+    index: u32,
+}
+
+impl<'a> Iterator for DataMapsIter<'a> {
+    type Item = DataMap<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Skip malformed entries instead of stopping.
+        while self.index < self.maps.len() {
+            self.index += 1;
+            if let Some(map) = self.maps.get(self.index - 1) {
+                return Some(map);
+            }
+        }
+
+        None
+    }
+}
+
+/// A list of [ScriptLangTags](
+/// https://docs.microsoft.com/en-us/typography/opentype/spec/meta#scriptlangtag-values).
+///
+/// Each tag is a BCP 47 language tag, like `en`, `zh-Hant` or `und-Latn`,
+/// or just a script subtag, like `Hans`.
+#[derive(Clone, Copy, Default)]
+pub struct ScriptLangTags<'a> {
+    data: &'a str,
+}
+
+impl<'a> ScriptLangTags<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        // Must be ASCII according to the spec.
+        ScriptLangTags {
+            data: core::str::from_utf8(data).unwrap_or(""),
+        }
+    }
+
+    /// Checks if any of the tags covers the specified locale.
+    ///
+    /// `locale` is a BCP 47 language tag, like `zh-TW`.
+    /// A tag covers a locale when all of its language, script and region subtags
+    /// are either not set or match the locale ones.
+    /// Like in [`name::Names::find_localized`](crate::name::Names::find_localized),
+    /// the script of a Chinese locale is inferred from the region.
+    /// So `Hant` covers `zh-TW`, but not `zh-CN`.
+    /// Otherwise, the default script of the language is used, so `Latn` covers `en`.
+    /// A script tag never covers a locale of a language with an unknown default script.
+    ///
+    /// Returns `false` for an empty or malformed locale.
+    pub fn supports(&self, locale: &str) -> bool {
+        let locale = LanguageTagParts::parse(locale.chars());
+        if !locale.is_valid() {
+            return false;
+        }
+
+        self.into_iter()
+            .any(|tag| LanguageTagParts::parse_script_lang_tag(tag).covers(&locale))
+    }
+
+    /// Checks if there are any tags.
+    pub fn is_empty(&self) -> bool {
+        self.into_iter().next().is_none()
+    }
+}
+
+impl core::fmt::Debug for ScriptLangTags<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_list().entries(*self).finish()
+    }
+}
+
+impl<'a> IntoIterator for ScriptLangTags<'a> {
+    type Item = &'a str;
+    type IntoIter = ScriptLangTagsIter<'a>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        ScriptLangTagsIter {
+            inner: self.data.split(','),
+        }
+    }
+}
+
+/// An iterator over [`ScriptLangTags`].
+#[derive(Clone)]
+#[allow(missing_debug_implementations)]
+pub struct ScriptLangTagsIter<'a> {
+    inner: core::str::Split<'a, char>,
+}
+
+impl<'a> Iterator for ScriptLangTagsIter<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Tags can be separated by a comma and any amount of spaces.
+        self.inner
+            .by_ref()
+            .map(|s| s.trim())
+            .find(|s| !s.is_empty())
+    }
+}
+
+/// A [Metadata Table](https://docs.microsoft.com/en-us/typography/opentype/spec/meta).
+#[derive(Clone, Copy, Debug)]
+pub struct Table<'a> {
+    /// A list of metadata entries.
+    pub data_maps: DataMaps<'a>,
+}
+
+impl<'a> Table<'a> {
+    /// Parses a table from raw data.
+    pub fn parse(data: &'a [u8]) -> Option<Self> {
+        let mut s = Stream::new(data);
+        let version = s.read::<u32>()?;
+        if version != 1 {
+            return None;
+        }
+
+        s.skip::<u32>(); // flags
+        s.skip::<u32>(); // reserved
+        let count = s.read::<u32>()?;
+        let records = s.read_array32::<DataMapRecord>(count)?;
+
+        Some(Table {
+            data_maps: DataMaps { records, data },
+        })
+    }
+
+    /// Returns languages the font was primarily designed for.
+    ///
+    /// Based on the `dlng` entry.
+    #[inline]
+    pub fn design_languages(&self) -> ScriptLangTags<'a> {
+        self.script_lang_tags(b"dlng")
+    }
+
+    /// Returns languages the font is capable of supporting.
+    ///
+    /// Based on the `slng` entry.
+    #[inline]
+    pub fn supported_languages(&self) -> ScriptLangTags<'a> {
+        self.script_lang_tags(b"slng")
+    }
+
+    fn script_lang_tags(&self, tag: &[u8; 4]) -> ScriptLangTags<'a> {
+        self.data_maps
+            .find(Tag::from_bytes(tag))
+            .map(|m| ScriptLangTags::new(m.data))
+            .unwrap_or_default()
+    }
+}
@@ -4,18 +4,22 @@ mod cff;
 pub mod cmap;
 pub mod colr;
 pub mod cpal;
+pub mod gasp;
 pub mod glyf;
+pub mod hdmx;
 pub mod head;
 pub mod hhea;
 pub mod hmtx;
 pub mod kern;
 pub mod loca;
 pub mod maxp;
+pub mod meta;
 pub mod name;
 pub mod os2;
 pub mod post;
 pub mod sbix;
 pub mod svg;
+pub mod vdmx;
 pub mod vhea;
 pub mod vorg;
 
@@ -35,6 +39,8 @@ pub mod feat;
 #[cfg(feature = "apple-layout")]
 pub mod kerx;
 #[cfg(feature = "apple-layout")]
+pub mod lcar;
+#[cfg(feature = "apple-layout")]
 pub mod morx;
 #[cfg(feature = "apple-layout")]
 pub mod trak;
@@ -6,6 +6,8 @@ use std::string::String;
 #[cfg(feature = "std")]
 use std::vec::Vec;
 
+use crate::encoding::MacEncoding;
+use crate::language::{mac_language_tag, LanguageTagParts};
 use crate::parser::{FromData, LazyArray16, Offset, Offset16, Stream};
 use crate::Language;
 
@@ -111,6 +113,78 @@ impl FromData for NameRecord {
     }
 }
 
+#[derive(Clone, Copy)]
+struct LangTagRecord {
+    length: u16,
+    offset: Offset16,
+}
+
+impl FromData for LangTagRecord {
+    const SIZE: usize = 4;
+
+    #[inline]
+    fn parse(data: &[u8]) -> Option<Self> {
+        let mut s = Stream::new(data);
+        Some(LangTagRecord {
+            length: s.read::<u16>()?,
+            offset: s.read::<Offset16>()?,
+        })
+    }
+}
+
+/// A [BCP 47](https://www.rfc-editor.org/info/bcp47) language tag.
+///
+/// Stored as UTF-16BE, just like Unicode names.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct LanguageTag<'a> {
+    /// A raw tag data.
+    pub data: &'a [u8],
+}
+
+impl<'a> LanguageTag<'a> {
+    /// Returns an iterator over tag's characters.
+    ///
+    /// Invalid UTF-16 sequences will be replaced with `U+FFFD`.
+    #[inline]
+    pub fn chars(&self) -> impl Iterator<Item = char> + 'a {
+        core::char::decode_utf16(LazyArray16::<u16>::new(self.data))
+            .map(|c| c.unwrap_or(core::char::REPLACEMENT_CHARACTER))
+    }
+
+    /// Checks that the tag is equal to the provided one, ignoring ASCII case.
+    ///
+    /// BCP 47 tags are case-insensitive.
+    #[inline]
+    pub fn eq_ignore_ascii_case(&self, tag: &str) -> bool {
+        let mut iter = self.chars();
+        for c in tag.chars() {
+            match iter.next() {
+                Some(c2) if c2.eq_ignore_ascii_case(&c) => {}
+                _ => return false,
+            }
+        }
+
+        iter.next().is_none()
+    }
+}
+
+impl core::fmt::Display for LanguageTag<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        use core::fmt::Write;
+        for c in self.chars() {
+            f.write_char(c)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl core::fmt::Debug for LanguageTag<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "LanguageTag({})", self)
+    }
+}
+
 /// A [Name Record](https://docs.microsoft.com/en-us/typography/opentype/spec/name#name-records).
 #[derive(Clone, Copy)]
 pub struct Name<'a> {
@@ -120,6 +194,10 @@ pub struct Name<'a> {
     pub encoding_id: u16,
     /// A language ID.
     pub language_id: u16,
+    /// A language tag.
+    ///
+    /// Set only for records with a language ID >= 0x8000 in the name table version 1.
+    pub language_tag: Option<LanguageTag<'a>>,
     /// A [Name ID](https://docs.microsoft.com/en-us/typography/opentype/spec/name#name-ids).
     ///
     /// A predefined list of ID's can be found in the [`name_id`](name_id/index.html) module.
@@ -133,29 +211,66 @@ pub struct Name<'a> {
 impl<'a> Name<'a> {
     /// Returns the Name's data as a UTF-8 string.
     ///
-    /// Only Unicode names are supported. And since they are stored as UTF-16BE,
-    /// we can't return `&str` and have to allocate a `String`.
+    /// Unicode names are stored as UTF-16BE, therefore we can't return `&str`
+    /// and have to allocate a `String`.
     ///
     /// Supports:
     /// - Unicode Platform ID
     /// - Windows Platform ID + Symbol
     /// - Windows Platform ID + Unicode BMP
+    /// - Macintosh Platform ID + Roman, Central European, Cyrillic and Greek scripts.
+    ///   Including Icelandic, Turkish, Croatian and Romanian variants of the Roman script.
+    ///
+    /// Use [`Name::to_string_with`] to decode other encodings.
     #[cfg(feature = "std")]
     #[inline(never)]
     pub fn to_string(&self) -> Option<String> {
         if self.is_unicode() {
             self.name_from_utf16_be()
         } else {
-            None
+            let encoding = self.mac_encoding()?;
+            Some(self.name.iter().map(|b| encoding.decode(*b)).collect())
+        }
+    }
+
+    /// Returns the Name's data as a UTF-8 string using a custom decoder as a fallback.
+    ///
+    /// `decoder` will be called only for encodings not supported by [`Name::to_string`].
+    /// Like Windows ShiftJIS and Big5 or Macintosh Japanese and Chinese.
+    /// Which are way too large to be embedded into the library.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn to_string_with<F>(&self, decoder: F) -> Option<String>
+    where
+        F: FnOnce(&Name<'a>) -> Option<String>,
+    {
+        if self.is_supported_encoding() {
+            self.to_string()
+        } else {
+            decoder(self)
         }
     }
 
+    #[inline]
+    fn is_supported_encoding(&self) -> bool {
+        self.is_unicode() || self.mac_encoding().is_some()
+    }
+
     /// Checks that the current Name data has a Unicode encoding.
     #[inline]
     pub fn is_unicode(&self) -> bool {
         is_unicode_encoding(self.platform_id, self.encoding_id)
     }
 
+    #[inline]
+    fn mac_encoding(&self) -> Option<MacEncoding> {
+        if self.platform_id == PlatformId::Macintosh {
+            MacEncoding::from_ids(self.encoding_id, self.language_id)
+        } else {
+            None
+        }
+    }
+
     #[cfg(feature = "std")]
     #[inline(never)]
     fn name_from_utf16_be(&self) -> Option<String> {
@@ -168,6 +283,9 @@ impl<'a> Name<'a> {
     }
 
     /// Returns a Name language.
+    ///
+    /// Returns `Language::Unknown` for names with a language tag.
+    /// Use [`Name::language_tag`](Name#structfield.language_tag) instead.
     pub fn language(&self) -> Language {
         if self.platform_id == PlatformId::Windows {
             Language::windows_language(self.language_id)
@@ -180,6 +298,20 @@ impl<'a> Name<'a> {
             Language::Unknown
         }
     }
+
+    fn language_tag_parts(&self) -> LanguageTagParts {
+        if let Some(tag) = self.language_tag {
+            return LanguageTagParts::parse(tag.chars());
+        }
+
+        let tag = match self.platform_id {
+            PlatformId::Windows => Some(Language::windows_language(self.language_id).bcp47_tag()),
+            PlatformId::Macintosh => mac_language_tag(self.language_id),
+            _ => None,
+        };
+
+        LanguageTagParts::parse(tag.unwrap_or("").chars())
+    }
 }
 
 #[cfg(feature = "std")]
@@ -192,6 +324,7 @@ impl<'a> core::fmt::Debug for Name<'a> {
             .field("encoding_id", &self.encoding_id)
             .field("language_id", &self.language_id)
             .field("language", &self.language())
+            .field("language_tag", &self.language_tag)
             .field("name_id", &self.name_id)
             .finish()
     }
@@ -206,6 +339,7 @@ impl<'a> core::fmt::Debug for Name<'a> {
             .field("encoding_id", &self.encoding_id)
             .field("language_id", &self.language_id)
             .field("language", &self.language())
+            .field("language_tag", &self.language_tag)
             .field("name_id", &self.name_id)
             .finish()
     }
@@ -215,6 +349,7 @@ impl<'a> core::fmt::Debug for Name<'a> {
 #[derive(Clone, Copy, Default)]
 pub struct Names<'a> {
     records: LazyArray16<'a, NameRecord>,
+    lang_tags: LazyArray16<'a, LangTagRecord>,
     storage: &'a [u8],
 }
 
@@ -229,11 +364,77 @@ impl<'a> Names<'a> {
             platform_id: record.platform_id,
             encoding_id: record.encoding_id,
             language_id: record.language_id,
+            language_tag: self.language_tag(record.language_id),
             name_id: record.name_id,
             name,
         })
     }
 
+    /// Returns a language tag for a language ID.
+    ///
+    /// Language IDs >= 0x8000 are indices into the language tags list
+    /// of the name table version 1.
+    pub fn language_tag(&self, language_id: u16) -> Option<LanguageTag<'a>> {
+        let index = language_id.checked_sub(0x8000)?;
+        let record = self.lang_tags.get(index)?;
+        let start = record.offset.to_usize();
+        let end = start + usize::from(record.length);
+        Some(LanguageTag {
+            data: self.storage.get(start..end)?,
+        })
+    }
+
+    /// Returns the best matching name for a locale.
+    ///
+    /// `locale` is a [BCP 47](https://www.rfc-editor.org/info/bcp47) language tag,
+    /// like `en-US`, `de` or `zh-Hant-HK`. Only the language, script and region subtags are used.
+    ///
+    /// Only names with encodings supported by [`Name::to_string`] are checked.
+    /// Records are ranked in the following order:
+    ///
+    /// 1. Language match. Records in a different language are used only as a fallback.
+    ///    A matching script is preferred over a matching region.
+    ///    The script of Chinese names is inferred from the region, when not set explicitly.
+    ///    So `zh-TW` is treated as `zh-Hant-TW`.
+    /// 2. When no language matches: US English, any English, then language-neutral records.
+    /// 3. Platform: Windows, Unicode, Macintosh.
+    ///
+    /// When all of the above are equal, the first record is used.
+    pub fn find_localized(&self, name_id: u16, locale: &str) -> Option<Name<'a>> {
+        let locale = LanguageTagParts::parse(locale.chars());
+        let en_us = LanguageTagParts::parse("en-US".chars());
+
+        let mut best: Option<(Name<'a>, (u8, u8, u8))> = None;
+        for name in *self {
+            if name.name_id != name_id || !name.is_supported_encoding() {
+                continue;
+            }
+
+            let tag = name.language_tag_parts();
+
+            let fallback_score = match en_us.match_score(&tag) {
+                0 if !tag.is_valid() || tag.language == *b"und\0\0\0\0\0" => 1,
+                0 => 0,
+                1..=3 => 2,
+                _ => 3,
+            };
+
+            let platform_score = match name.platform_id {
+                PlatformId::Windows => 3,
+                PlatformId::Unicode => 2,
+                PlatformId::Macintosh => 1,
+                _ => 0,
+            };
+
+            let score = (locale.match_score(&tag), fallback_score, platform_score);
+            if best.map(|(_, s)| score > s).unwrap_or(true) {
+                best = Some((name, score));
+            }
+        }
+
+        best.map(|(name, _)| name)
+    }
+
     /// Returns a number of name records.
     pub fn len(&self) -> u16 {
         self.records.len()
@@ -301,27 +502,26 @@ pub struct Table<'a> {
 impl<'a> Table<'a> {
     /// Parses a table from raw data.
     pub fn parse(data: &'a [u8]) -> Option<Self> {
-        // https://docs.microsoft.com/en-us/typography/opentype/spec/name#naming-table-format-1
-        const LANG_TAG_RECORD_SIZE: u16 = 4;
-
         let mut s = Stream::new(data);
         let version = s.read::<u16>()?;
         let count = s.read::<u16>()?;
         let storage_offset = s.read::<Offset16>()?.to_usize();
 
-        if version == 0 {
-            // Do nothing.
-        } else if version == 1 {
-            let lang_tag_count = s.read::<u16>()?;
-            let lang_tag_len = lang_tag_count.checked_mul(LANG_TAG_RECORD_SIZE)?;
-            s.advance(usize::from(lang_tag_len)); // langTagRecords
-        } else {
+        if version > 1 {
             // Unsupported version.
             return None;
         }
 
         let records = s.read_array16::<NameRecord>(count)?;
 
+        // https://docs.microsoft.com/en-us/typography/opentype/spec/name#naming-table-format-1
+        let lang_tags = if version == 1 {
+            let lang_tag_count = s.read::<u16>()?;
+            s.read_array16::<LangTagRecord>(lang_tag_count)?
+        } else {
+            LazyArray16::default()
+        };
+
         if s.offset() < storage_offset {
             s.advance(storage_offset - s.offset());
         }
@@ -329,7 +529,11 @@ impl<'a> Table<'a> {
         let storage = s.tail()?;
 
         Some(Table {
-            names: Names { records, storage },
+            names: Names {
+                records,
+                lang_tags,
+                storage,
+            },
         })
     }
 }
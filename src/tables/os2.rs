@@ -2,7 +2,7 @@
 //! implementation.
 
 use crate::parser::Stream;
-use crate::LineMetrics;
+use crate::{LineMetrics, Tag};
 
 const WEIGHT_CLASS_OFFSET: usize = 4;
 const WIDTH_CLASS_OFFSET: usize = 6;
@@ -11,15 +11,25 @@ const Y_SUBSCRIPT_X_SIZE_OFFSET: usize = 10;
 const Y_SUPERSCRIPT_X_SIZE_OFFSET: usize = 18;
 const Y_STRIKEOUT_SIZE_OFFSET: usize = 26;
 const Y_STRIKEOUT_POSITION_OFFSET: usize = 28;
+const PANOSE_OFFSET: usize = 32;
 const UNICODE_RANGES_OFFSET: usize = 42;
+const VENDOR_ID_OFFSET: usize = 58;
 const SELECTION_OFFSET: usize = 62;
+const FIRST_CHAR_INDEX_OFFSET: usize = 64;
+const LAST_CHAR_INDEX_OFFSET: usize = 66;
 const TYPO_ASCENDER_OFFSET: usize = 68;
 const TYPO_DESCENDER_OFFSET: usize = 70;
 const TYPO_LINE_GAP_OFFSET: usize = 72;
 const WIN_ASCENT: usize = 74;
 const WIN_DESCENT: usize = 76;
+const CODE_PAGE_RANGES_OFFSET: usize = 78;
 const X_HEIGHT_OFFSET: usize = 86;
 const CAP_HEIGHT_OFFSET: usize = 88;
+const DEFAULT_CHAR_OFFSET: usize = 90;
+const BREAK_CHAR_OFFSET: usize = 92;
+const MAX_CONTEXT_OFFSET: usize = 94;
+const LOWER_OPTICAL_POINT_SIZE_OFFSET: usize = 96;
+const UPPER_OPTICAL_POINT_SIZE_OFFSET: usize = 98;
 
 /// A face [weight](https://docs.microsoft.com/en-us/typography/opentype/spec/os2#usweightclass).
 #[allow(missing_docs)]
@@ -166,17 +176,52 @@ pub struct ScriptMetrics {
     pub y_offset: i16,
 }
 
-// https://docs.microsoft.com/en-us/typography/opentype/spec/os2#fsselection
-#[derive(Clone, Copy)]
-struct SelectionFlags(u16);
+/// A [PANOSE classification](https://docs.microsoft.com/en-us/typography/opentype/spec/os2#panose).
+///
+/// The meaning of all values except `family_type` depends on the family type.
+/// Zero means *Any* and one means *No Fit* for all of them.
+#[allow(missing_docs)]
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug, Hash)]
+pub struct Panose {
+    pub family_type: u8,
+    pub serif_style: u8,
+    pub weight: u8,
+    pub proportion: u8,
+    pub contrast: u8,
+    pub stroke_variation: u8,
+    pub arm_style: u8,
+    pub letterform: u8,
+    pub midline: u8,
+    pub x_height: u8,
+}
+
+/// [Font selection flags](https://docs.microsoft.com/en-us/typography/opentype/spec/os2#fsselection).
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub struct SelectionFlags(pub u16);
 
 #[rustfmt::skip]
 impl SelectionFlags {
-    #[inline] fn italic(self) -> bool { self.0 & (1 << 0) != 0 }
-    #[inline] fn bold(self) -> bool { self.0 & (1 << 5) != 0 }
-    // #[inline] fn regular(self) -> bool { self.0 & (1 << 6) != 0 }
-    #[inline] fn use_typo_metrics(self) -> bool { self.0 & (1 << 7) != 0 }
-    #[inline] fn oblique(self) -> bool { self.0 & (1 << 9) != 0 }
+    /// Glyphs are italic.
+    #[inline] pub fn italic(self) -> bool { self.0 & (1 << 0) != 0 }
+    /// Glyphs are underscored.
+    #[inline] pub fn underscore(self) -> bool { self.0 & (1 << 1) != 0 }
+    /// Glyphs have their foreground and background reversed.
+    #[inline] pub fn negative(self) -> bool { self.0 & (1 << 2) != 0 }
+    /// Glyphs are outlined (hollow).
+    #[inline] pub fn outlined(self) -> bool { self.0 & (1 << 3) != 0 }
+    /// Glyphs are overstruck.
+    #[inline] pub fn strikeout(self) -> bool { self.0 & (1 << 4) != 0 }
+    /// Glyphs are emboldened.
+    #[inline] pub fn bold(self) -> bool { self.0 & (1 << 5) != 0 }
+    /// Glyphs are in the standard weight/style for the font.
+    #[inline] pub fn regular(self) -> bool { self.0 & (1 << 6) != 0 }
+    /// Typographic metrics should be used for line spacing. Defined since version 4.
+    #[inline] pub fn use_typo_metrics(self) -> bool { self.0 & (1 << 7) != 0 }
+    /// The font has name table strings consistent with a weight/width/slope family.
+    /// Defined since version 4.
+    #[inline] pub fn wws(self) -> bool { self.0 & (1 << 8) != 0 }
+    /// Glyphs are oblique. Defined since version 4.
+    #[inline] pub fn oblique(self) -> bool { self.0 & (1 << 9) != 0 }
 }
 
 /// [Unicode Ranges](https://docs.microsoft.com/en-us/typography/opentype/spec/os2#ur).
@@ -195,6 +240,83 @@ impl UnicodeRanges {
     }
 }
 
+/// [Code Page Character Ranges](https://docs.microsoft.com/en-us/typography/opentype/spec/os2#cpr).
+///
+/// Contains `ulCodePageRange1` in the lower 32 bits and `ulCodePageRange2` in the upper ones.
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub struct CodePageRanges(pub u64);
+
+impl CodePageRanges {
+    /// Checks if ranges contain the specified Windows code page.
+    ///
+    /// The Macintosh Roman character set is checked via code page 10000.
+    /// Returns `false` for unknown code pages.
+    pub fn contains_code_page(&self, code_page: u16) -> bool {
+        match code_page_bit(code_page) {
+            Some(bit) => self.contains_bit(bit),
+            None => false,
+        }
+    }
+
+    /// Checks if ranges contain the OEM character set.
+    #[inline]
+    pub fn contains_oem(&self) -> bool {
+        self.contains_bit(30)
+    }
+
+    /// Checks if ranges contain the symbol character set.
+    #[inline]
+    pub fn contains_symbol(&self) -> bool {
+        self.contains_bit(31)
+    }
+
+    /// Checks if the specified bit is set.
+    #[inline]
+    pub fn contains_bit(&self, bit: u8) -> bool {
+        bit < 64 && self.0 & (1 << bit) != 0
+    }
+}
+
+fn code_page_bit(code_page: u16) -> Option<u8> {
+    let bit = match code_page {
+        1252 => 0,
+        1250 => 1,
+        1251 => 2,
+        1253 => 3,
+        1254 => 4,
+        1255 => 5,
+        1256 => 6,
+        1257 => 7,
+        1258 => 8,
+        874 => 16,
+        932 => 17,
+        936 => 18,
+        949 => 19,
+        950 => 20,
+        1361 => 21,
+        10000 => 29,
+        869 => 48,
+        866 => 49,
+        865 => 50,
+        864 => 51,
+        863 => 52,
+        862 => 53,
+        861 => 54,
+        860 => 55,
+        857 => 56,
+        855 => 57,
+        852 => 58,
+        775 => 59,
+        737 => 60,
+        708 => 61,
+        850 => 62,
+        437 => 63,
+        _ => return None,
+    };
+
+    Some(bit)
+}
+
 fn char_range_index(c: char) -> i8 {
     match c as u32 {
         0x0000..=0x007F => 0,
@@ -521,14 +643,141 @@ impl<'a> Table<'a> {
         UnicodeRanges(n4 << 96 | n3 << 64 | n2 << 32 | n1)
     }
 
+    /// Returns PANOSE classification.
+    #[inline]
+    pub fn panose(&self) -> Panose {
+        let mut s = Stream::new_at(self.data, PANOSE_OFFSET).unwrap_or_default();
+        Panose {
+            family_type: s.read::<u8>().unwrap_or(0),
+            serif_style: s.read::<u8>().unwrap_or(0),
+            weight: s.read::<u8>().unwrap_or(0),
+            proportion: s.read::<u8>().unwrap_or(0),
+            contrast: s.read::<u8>().unwrap_or(0),
+            stroke_variation: s.read::<u8>().unwrap_or(0),
+            arm_style: s.read::<u8>().unwrap_or(0),
+            letterform: s.read::<u8>().unwrap_or(0),
+            midline: s.read::<u8>().unwrap_or(0),
+            x_height: s.read::<u8>().unwrap_or(0),
+        }
+    }
+
+    /// Returns font vendor identification.
+    #[inline]
+    pub fn vendor_id(&self) -> Tag {
+        Tag(Stream::read_at::<u32>(self.data, VENDOR_ID_OFFSET).unwrap_or(0))
+    }
+
+    /// Returns font selection flags.
+    ///
+    /// Flags that are not defined for the current table version are returned as is.
+    #[inline]
+    pub fn selection_flags(&self) -> SelectionFlags {
+        SelectionFlags(Stream::read_at::<u16>(self.data, SELECTION_OFFSET).unwrap_or(0))
+    }
+
+    /// Returns the minimum Unicode code point in the font.
+    ///
+    /// Values above 0xFFFF are stored as 0xFFFF.
+    #[inline]
+    pub fn first_char_index(&self) -> u16 {
+        Stream::read_at::<u16>(self.data, FIRST_CHAR_INDEX_OFFSET).unwrap_or(0)
+    }
+
+    /// Returns the maximum Unicode code point in the font.
+    ///
+    /// Values above 0xFFFF are stored as 0xFFFF.
+    #[inline]
+    pub fn last_char_index(&self) -> u16 {
+        Stream::read_at::<u16>(self.data, LAST_CHAR_INDEX_OFFSET).unwrap_or(0)
+    }
+
+    /// Returns code page character ranges.
+    ///
+    /// Returns `None` version is < 1.
+    #[inline]
+    pub fn code_page_ranges(&self) -> Option<CodePageRanges> {
+        if self.version < 1 {
+            return None;
+        }
+
+        let mut s = Stream::new_at(self.data, CODE_PAGE_RANGES_OFFSET)?;
+        let n1 = u64::from(s.read::<u32>()?);
+        let n2 = u64::from(s.read::<u32>()?);
+        Some(CodePageRanges(n2 << 32 | n1))
+    }
+
+    /// Returns a code point used as a substitute for characters not supported by the font.
+    ///
+    /// Zero means that glyph 0 should be used.
+    ///
+    /// Returns `None` version is < 2.
+    #[inline]
+    pub fn default_char(&self) -> Option<u16> {
+        if self.version < 2 {
+            None
+        } else {
+            Stream::read_at::<u16>(self.data, DEFAULT_CHAR_OFFSET)
+        }
+    }
+
+    /// Returns a code point used as a word break character.
+    ///
+    /// Returns `None` version is < 2.
+    #[inline]
+    pub fn break_char(&self) -> Option<u16> {
+        if self.version < 2 {
+            None
+        } else {
+            Stream::read_at::<u16>(self.data, BREAK_CHAR_OFFSET)
+        }
+    }
+
+    /// Returns the maximum length of a target glyph context for any feature in the font.
+    ///
+    /// Returns `None` version is < 2.
+    #[inline]
+    pub fn max_context(&self) -> Option<u16> {
+        if self.version < 2 {
+            None
+        } else {
+            Stream::read_at::<u16>(self.data, MAX_CONTEXT_OFFSET)
+        }
+    }
+
+    /// Returns the lower end of the size range for which the font was designed, in points.
+    ///
+    /// The value is inclusive.
+    ///
+    /// Returns `None` version is < 5.
     #[inline]
-    fn fs_selection(&self) -> u16 {
-        Stream::read_at::<u16>(self.data, SELECTION_OFFSET).unwrap_or(0)
+    pub fn lower_optical_point_size(&self) -> Option<f32> {
+        if self.version < 5 {
+            None
+        } else {
+            // Stored in TWIPs.
+            Stream::read_at::<u16>(self.data, LOWER_OPTICAL_POINT_SIZE_OFFSET)
+                .map(|n| f32::from(n) / 20.0)
+        }
+    }
+
+    /// Returns the upper end of the size range for which the font was designed, in points.
+    ///
+    /// The value is exclusive. The maximum value of 3276.75 means no upper limit.
+    ///
+    /// Returns `None` version is < 5.
+    #[inline]
+    pub fn upper_optical_point_size(&self) -> Option<f32> {
+        if self.version < 5 {
+            None
+        } else {
+            Stream::read_at::<u16>(self.data, UPPER_OPTICAL_POINT_SIZE_OFFSET)
+                .map(|n| f32::from(n) / 20.0)
+        }
     }
 
     /// Returns style.
     pub fn style(&self) -> Style {
-        let flags = SelectionFlags(self.fs_selection());
+        let flags = self.selection_flags();
         if flags.italic() {
             Style::Italic
         } else if self.version >= 4 && flags.oblique() {
@@ -543,7 +792,7 @@ impl<'a> Table<'a> {
     /// Do not confuse with [`Weight::Bold`].
     #[inline]
     pub fn is_bold(&self) -> bool {
-        SelectionFlags(self.fs_selection()).bold()
+        self.selection_flags().bold()
     }
 
     /// Checks if typographic metrics should be used.
@@ -552,7 +801,7 @@ impl<'a> Table<'a> {
         if self.version < 4 {
             false
         } else {
-            SelectionFlags(self.fs_selection()).use_typo_metrics()
+            self.selection_flags().use_typo_metrics()
         }
     }
 
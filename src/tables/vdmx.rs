@@ -0,0 +1,166 @@
+//! A [Vertical Device Metrics Table](
+//! https://docs.microsoft.com/en-us/typography/opentype/spec/vdmx) implementation.
+
+use crate::parser::{FromData, LazyArray16, Offset, Offset16, Stream};
+
+/// A [Ratio Range Record](
+/// https://docs.microsoft.com/en-us/typography/opentype/spec/vdmx#ratio-range-record).
+#[derive(Clone, Copy, Debug)]
+pub struct RatioRange {
+    /// Character set.
+    pub char_set: u8,
+    /// Value to use for x-Ratio.
+    pub x_ratio: u8,
+    /// Starting y-Ratio value.
+    pub y_start_ratio: u8,
+    /// Ending y-Ratio value.
+    pub y_end_ratio: u8,
+}
+
+impl RatioRange {
+    /// Checks that the range matches the specified device aspect ratio.
+    ///
+    /// A range with all values set to zero matches any aspect ratio.
+    #[inline]
+    pub fn contains(&self, x_ratio: u16, y_ratio: u16) -> bool {
+        if self.x_ratio == 0 && self.y_start_ratio == 0 && self.y_end_ratio == 0 {
+            return true;
+        }
+
+        // Compare y/x against y_start/x_ratio and y_end/x_ratio without division.
+        let x = u32::from(x_ratio);
+        let y = u32::from(y_ratio) * u32::from(self.x_ratio);
+        u32::from(self.y_start_ratio) * x <= y && y <= u32::from(self.y_end_ratio) * x
+    }
+}
+
+impl FromData for RatioRange {
+    const SIZE: usize = 4;
+
+    #[inline]
+    fn parse(data: &[u8]) -> Option<Self> {
+        let mut s = Stream::new(data);
+        Some(RatioRange {
+            char_set: s.read::<u8>()?,
+            x_ratio: s.read::<u8>()?,
+            y_start_ratio: s.read::<u8>()?,
+            y_end_ratio: s.read::<u8>()?,
+        })
+    }
+}
+
+/// Vertical metrics for a specific pixels per EM.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Metrics {
+    /// Pixels per EM for which these values apply.
+    pub pixels_per_em: u16,
+    /// Maximum value (in pixels) for this pixels per EM.
+    pub y_max: i16,
+    /// Minimum value (in pixels) for this pixels per EM.
+    pub y_min: i16,
+}
+
+impl FromData for Metrics {
+    const SIZE: usize = 6;
+
+    #[inline]
+    fn parse(data: &[u8]) -> Option<Self> {
+        let mut s = Stream::new(data);
+        Some(Metrics {
+            pixels_per_em: s.read::<u16>()?,
+            y_max: s.read::<i16>()?,
+            y_min: s.read::<i16>()?,
+        })
+    }
+}
+
+/// A [VDMX Group](https://docs.microsoft.com/en-us/typography/opentype/spec/vdmx#vdmx-group).
+#[derive(Clone, Copy, Debug)]
+pub struct Group<'a> {
+    /// Starting pixels per EM.
+    pub start_size: u8,
+    /// Ending pixels per EM.
+    pub end_size: u8,
+    /// A list of metrics.
+    ///
+    /// Sorted by `pixels_per_em`.
+    pub metrics: LazyArray16<'a, Metrics>,
+}
+
+impl<'a> Group<'a> {
+    fn parse(data: &'a [u8]) -> Option<Self> {
+        let mut s = Stream::new(data);
+        let count = s.read::<u16>()?;
+        let start_size = s.read::<u8>()?;
+        let end_size = s.read::<u8>()?;
+        let metrics = s.read_array16::<Metrics>(count)?;
+        Some(Group {
+            start_size,
+            end_size,
+            metrics,
+        })
+    }
+
+    /// Returns metrics for the specified pixels per EM.
+    #[inline]
+    pub fn get(&self, pixels_per_em: u16) -> Option<Metrics> {
+        self.metrics
+            .binary_search_by(|m| m.pixels_per_em.cmp(&pixels_per_em))
+            .map(|(_, m)| m)
+    }
+}
+
+/// A [Vertical Device Metrics Table](
+/// https://docs.microsoft.com/en-us/typography/opentype/spec/vdmx).
+#[derive(Clone, Copy, Debug)]
+pub struct Table<'a> {
+    /// Table version.
+    pub version: u16,
+    /// A list of ratio ranges.
+    ///
+    /// Each range has a corresponding group, that can be accessed via [`Table::group`].
+    pub ratios: LazyArray16<'a, RatioRange>,
+    offsets: LazyArray16<'a, Offset16>,
+    data: &'a [u8], // the whole table
+}
+
+impl<'a> Table<'a> {
+    /// Parses a table from raw data.
+    pub fn parse(data: &'a [u8]) -> Option<Self> {
+        let mut s = Stream::new(data);
+        let version = s.read::<u16>()?;
+        if version > 1 {
+            return None;
+        }
+
+        s.skip::<u16>(); // number of groups
+        let ratios_count = s.read::<u16>()?;
+        let ratios = s.read_array16::<RatioRange>(ratios_count)?;
+        let offsets = s.read_array16::<Offset16>(ratios_count)?;
+
+        Some(Table {
+            version,
+            ratios,
+            offsets,
+            data,
+        })
+    }
+
+    /// Returns a group for the ratio range at index.
+    pub fn group(&self, index: u16) -> Option<Group<'a>> {
+        let offset = self.offsets.get(index)?.to_usize();
+        Group::parse(self.data.get(offset..)?)
+    }
+
+    /// Returns vertical metrics for the specified device aspect ratio and pixels per EM.
+    ///
+    /// Ratio ranges are checked in order and the first matching one is used.
+    pub fn metrics(&self, x_ratio: u16, y_ratio: u16, pixels_per_em: u16) -> Option<Metrics> {
+        let (index, _) = self
+            .ratios
+            .into_iter()
+            .enumerate()
+            .find(|(_, r)| r.contains(x_ratio, y_ratio))?;
+        self.group(index as u16)?.get(pixels_per_em)
+    }
+}
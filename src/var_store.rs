@@ -5,13 +5,22 @@
 use crate::parser::{FromData, LazyArray16, NumFrom, Stream};
 use crate::NormalizedCoordinate;
 
+/// An [Item Variation Store](
+/// https://docs.microsoft.com/en-us/typography/opentype/spec/otvarcommonformats#item-variation-store).
 #[derive(Clone, Copy)]
-pub(crate) struct ItemVariationStore<'a> {
+pub struct ItemVariationStore<'a> {
     data: &'a [u8],
     data_offsets: LazyArray16<'a, u32>,
+    /// A list of variation regions referenced by item variation data subtables.
     pub regions: VariationRegionList<'a>,
 }
 
+impl core::fmt::Debug for ItemVariationStore<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "ItemVariationStore {{ ... }}")
+    }
+}
+
 impl<'a> Default for ItemVariationStore<'a> {
     #[inline]
     fn default() -> Self {
@@ -28,7 +37,7 @@ impl<'a> Default for ItemVariationStore<'a> {
 
 impl<'a> ItemVariationStore<'a> {
     #[inline]
-    pub fn parse(mut s: Stream) -> Option<ItemVariationStore> {
+    pub(crate) fn parse(mut s: Stream) -> Option<ItemVariationStore> {
         let data = s.tail()?;
 
         let mut regions_s = s.clone();
@@ -60,7 +69,22 @@ impl<'a> ItemVariationStore<'a> {
         })
     }
 
-    pub fn region_indices(&self, index: u16) -> Option<LazyArray16<u16>> {
+    /// Returns the number of item variation data subtables.
+    #[inline]
+    pub fn data_count(&self) -> u16 {
+        self.data_offsets.len()
+    }
+
+    /// Returns the number of delta sets in an item variation data subtable.
+    pub fn item_count(&self, index: u16) -> Option<u16> {
+        let offset = self.data_offsets.get(index)?;
+        let mut s = Stream::new_at(self.data, usize::num_from(offset))?;
+        s.read::<u16>()
+    }
+
+    /// Returns indices in [`VariationRegionList`]
+    /// referenced by an item variation data subtable.
+    pub fn region_indices(&self, index: u16) -> Option<LazyArray16<'a, u16>> {
         // Offsets in bytes from the start of the item variation store
         // to each item variation data subtable.
         let offset = self.data_offsets.get(index)?;
@@ -71,6 +95,10 @@ impl<'a> ItemVariationStore<'a> {
         s.read_array16::<u16>(count)
     }
 
+    /// Returns a delta of an item for the specified variation coordinates.
+    ///
+    /// `outer_index` is an item variation data subtable index
+    /// and `inner_index` is a delta set index in it.
     pub fn parse_delta(
         &self,
         outer_index: u16,
@@ -109,6 +137,8 @@ impl<'a> ItemVariationStore<'a> {
     }
 }
 
+/// A [Variation Region List](
+/// https://docs.microsoft.com/en-us/typography/opentype/spec/otvarcommonformats#variation-regions).
 #[derive(Clone, Copy)]
 pub struct VariationRegionList<'a> {
     axis_count: u16,
@@ -116,6 +146,35 @@ pub struct VariationRegionList<'a> {
 }
 
 impl<'a> VariationRegionList<'a> {
+    /// Returns the number of variation axes each region is defined for.
+    #[inline]
+    pub fn axis_count(&self) -> u16 {
+        self.axis_count
+    }
+
+    /// Returns the number of regions.
+    #[inline]
+    pub fn len(&self) -> u16 {
+        self.regions.len().checked_div(self.axis_count).unwrap_or(0)
+    }
+
+    /// Checks if there are any regions.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a region at index.
+    pub fn get(&self, index: u16) -> Option<VariationRegion<'a>> {
+        if index >= self.len() {
+            return None;
+        }
+
+        let start = index * self.axis_count;
+        let axes = self.regions.slice(start..start + self.axis_count)?;
+        Some(VariationRegion { axes })
+    }
+
     #[inline]
     pub(crate) fn evaluate_region(&self, index: u16, coordinates: &[NormalizedCoordinate]) -> f32 {
         let mut v = 1.0;
@@ -137,14 +196,86 @@ impl<'a> VariationRegionList<'a> {
     }
 }
 
+impl core::fmt::Debug for VariationRegionList<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "VariationRegionList {{ ... }}")
+    }
+}
+
+impl<'a> IntoIterator for VariationRegionList<'a> {
+    type Item = VariationRegion<'a>;
+    type IntoIter = VariationRegionListIter<'a>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        VariationRegionListIter {
+            list: self,
+            index: 0,
+        }
+    }
+}
+
+/// An iterator over [`VariationRegionList`].
 #[derive(Clone, Copy)]
-struct RegionAxisCoordinatesRecord {
-    start_coord: i16,
-    peak_coord: i16,
-    end_coord: i16,
+#[allow(missing_debug_implementations)]
+pub struct VariationRegionListIter<'a> {
+    list: VariationRegionList<'a>,
+    index: u16,
+}
+
+impl<'a> Iterator for VariationRegionListIter<'a> {
+    type Item = VariationRegion<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index < self.list.len() {
+            self.index += 1;
+            self.list.get(self.index - 1)
+        } else {
+            None
+        }
+    }
+}
+
+/// A variation region.
+#[derive(Clone, Copy, Debug)]
+pub struct VariationRegion<'a> {
+    /// Region coordinates for each variation axis, in the `fvar` axes order.
+    pub axes: LazyArray16<'a, RegionAxisCoordinatesRecord>,
+}
+
+impl VariationRegion<'_> {
+    /// Returns a region scalar for the specified variation coordinates.
+    ///
+    /// The scalar is in the 0..=1 range. Missing coordinates are treated as default ones.
+    pub fn evaluate(&self, coordinates: &[NormalizedCoordinate]) -> f32 {
+        let mut v = 1.0;
+        for (i, axis) in self.axes.into_iter().enumerate() {
+            let coord = coordinates.get(i).map(|c| c.get()).unwrap_or(0);
+            v *= axis.evaluate_axis(coord);
+            if v == 0.0 {
+                break;
+            }
+        }
+
+        v
+    }
+}
+
+/// Region coordinates for a single variation axis.
+///
+/// All coordinates are normalized and stored as F2DOT14.
+#[derive(Clone, Copy, Debug)]
+pub struct RegionAxisCoordinatesRecord {
+    /// The region start coordinate.
+    pub start_coord: i16,
+    /// The region peak coordinate.
+    pub peak_coord: i16,
+    /// The region end coordinate.
+    pub end_coord: i16,
 }
 
 impl RegionAxisCoordinatesRecord {
+    /// Returns a scalar for the specified normalized coordinate.
     #[inline]
     pub fn evaluate_axis(&self, coord: i16) -> f32 {
         let start = self.start_coord;
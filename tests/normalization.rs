@@ -0,0 +1,51 @@
+#![cfg(feature = "unicode-normalization")]
+
+use ttf_parser::{normalization, Face};
+
+static FONT_DATA: &[u8] = include_bytes!("fonts/demo.ttf");
+
+#[test]
+fn decompose() {
+    let d = normalization::decompose('ǖ').unwrap();
+    assert_eq!(d.as_slice(), ['u', '\u{0308}', '\u{0304}']);
+
+    // Singleton.
+    let d = normalization::decompose('\u{212B}').unwrap();
+    assert_eq!(d.as_slice(), ['A', '\u{030A}']);
+
+    let d = normalization::decompose('각').unwrap();
+    assert_eq!(d.as_slice(), ['\u{1100}', '\u{1161}', '\u{11A8}']);
+
+    assert!(normalization::decompose('a').is_none());
+}
+
+#[test]
+fn compose() {
+    assert_eq!(normalization::compose('u', '\u{0308}'), Some('ü'));
+    assert_eq!(normalization::compose('ü', '\u{0304}'), Some('ǖ'));
+    assert_eq!(normalization::compose('\u{1100}', '\u{1161}'), Some('가'));
+    assert_eq!(normalization::compose('가', '\u{11A8}'), Some('각'));
+    assert_eq!(normalization::compose('각', '\u{11A8}'), None);
+    // Composition exclusion.
+    assert_eq!(normalization::compose('\u{0915}', '\u{093C}'), None);
+    assert_eq!(normalization::compose('a', 'b'), None);
+}
+
+#[test]
+fn glyph_index_decomposed() {
+    let face = Face::parse(FONT_DATA, 0).unwrap();
+    let a = face.glyph_index('A').unwrap();
+    assert_eq!(face.glyph_index_decomposed('A').unwrap().as_slice(), [a]);
+    // No combining ring above.
+    assert_eq!(face.glyph_index_decomposed('Å'), None);
+    assert_eq!(face.glyph_index_decomposed('B'), None);
+}
+
+#[test]
+fn glyph_index_composed() {
+    let face = Face::parse(FONT_DATA, 0).unwrap();
+    let a = face.glyph_index('A').unwrap();
+    assert_eq!(face.glyph_index_composed(&['A', '\u{030A}']), Some((a, 1)));
+    assert_eq!(face.glyph_index_composed(&['B', '\u{030A}']), None);
+    assert_eq!(face.glyph_index_composed(&[]), None);
+}
@@ -0,0 +1,144 @@
+#![cfg(feature = "rasterizer")]
+
+use ttf_parser::raster::Rasterizer;
+use ttf_parser::{Face, GlyphId, OutlineBuilder};
+
+fn rect(r: &mut Rasterizer, x_min: f32, y_min: f32, x_max: f32, y_max: f32) {
+    r.move_to(x_min, y_min);
+    r.line_to(x_max, y_min);
+    r.line_to(x_max, y_max);
+    r.line_to(x_min, y_max);
+    r.close();
+}
+
+#[rustfmt::skip]
+#[test]
+fn pixel_aligned_rect() {
+    let mut r = Rasterizer::new(4, 4);
+    r.set_transform(1.0, 0.0, 4.0);
+    rect(&mut r, 1.0, 1.0, 3.0, 3.0);
+    assert_eq!(r.finish(), vec![
+        0,   0,   0, 0,
+        0, 255, 255, 0,
+        0, 255, 255, 0,
+        0,   0,   0, 0,
+    ]);
+}
+
+#[rustfmt::skip]
+#[test]
+fn sub_pixel_offset() {
+    let mut r = Rasterizer::new(3, 2);
+    r.set_transform(1.0, 0.5, 2.0);
+    rect(&mut r, 0.0, 0.0, 2.0, 2.0);
+    assert_eq!(r.finish(), vec![
+        128, 255, 128,
+        128, 255, 128,
+    ]);
+}
+
+#[test]
+fn exact_area() {
+    let mut r = Rasterizer::new(1, 1);
+    r.set_transform(1.0, 0.0, 1.0);
+    r.move_to(0.0, 0.0);
+    r.line_to(1.0, 0.0);
+    r.line_to(0.0, 1.0);
+    assert_eq!(r.finish(), vec![128]);
+}
+
+#[test]
+fn non_zero_winding() {
+    let mut r = Rasterizer::new(3, 1);
+    r.set_transform(1.0, 0.0, 1.0);
+    rect(&mut r, 0.0, 0.0, 2.0, 1.0);
+    rect(&mut r, 1.0, 0.0, 3.0, 1.0);
+    assert_eq!(r.finish(), vec![255, 255, 255]);
+}
+
+#[rustfmt::skip]
+#[test]
+fn hole() {
+    let mut r = Rasterizer::new(3, 3);
+    r.set_transform(1.0, 0.0, 3.0);
+    rect(&mut r, 0.0, 0.0, 3.0, 3.0);
+    // Reversed direction.
+    r.move_to(1.0, 1.0);
+    r.line_to(1.0, 2.0);
+    r.line_to(2.0, 2.0);
+    r.line_to(2.0, 1.0);
+    r.close();
+    assert_eq!(r.finish(), vec![
+        255, 255, 255,
+        255,   0, 255,
+        255, 255, 255,
+    ]);
+}
+
+#[test]
+fn outside_of_canvas() {
+    let mut r = Rasterizer::new(2, 2);
+    r.set_transform(1.0, 0.0, 2.0);
+    rect(&mut r, -5.0, -5.0, 1.0, 10.0);
+    assert_eq!(r.finish(), vec![255, 0, 255, 0]);
+}
+
+#[test]
+fn curves() {
+    // A quarter of a circle with a radius of 10.
+    const K: f32 = 0.552_284_8 * 10.0;
+    let mut r = Rasterizer::new(10, 10);
+    r.set_transform(1.0, 0.0, 10.0);
+    r.move_to(0.0, 0.0);
+    r.line_to(10.0, 0.0);
+    r.curve_to(10.0, K, K, 10.0, 0.0, 10.0);
+    r.close();
+    let area: f32 = r.finish().iter().map(|c| f32::from(*c) / 255.0).sum();
+    assert!((area - 78.54).abs() < 0.5);
+
+    // The same shape using a quadratic curve.
+    let mut r = Rasterizer::new(10, 10);
+    r.set_transform(1.0, 0.0, 10.0);
+    r.move_to(0.0, 0.0);
+    r.line_to(10.0, 0.0);
+    r.quad_to(10.0, 10.0, 0.0, 10.0);
+    r.close();
+    let area: f32 = r.finish().iter().map(|c| f32::from(*c) / 255.0).sum();
+    assert!((area - 83.33).abs() < 0.5);
+}
+
+#[test]
+fn glyph() {
+    let data = include_bytes!("fonts/demo.ttf");
+    let face = Face::parse(data, 0).unwrap();
+
+    let mask = face.rasterize_glyph(GlyphId(1), 100.0, (0.0, 0.0)).unwrap();
+    assert_eq!(mask.left, 0);
+    assert_eq!(mask.top, 66);
+    assert_eq!(mask.width, 55);
+    assert_eq!(mask.height, 66);
+    assert_eq!(mask.data.len(), 55 * 66);
+    assert_eq!(mask.get(4, 65), Some(255));
+    assert_eq!(mask.get(27, 65), Some(0));
+    assert_eq!(mask.get(55, 0), None);
+
+    let mask = face.rasterize_glyph(GlyphId(1), 100.0, (0.5, 0.0)).unwrap();
+    assert_eq!(mask.left, 1);
+    assert_eq!(mask.width, 54);
+
+    assert!(face.rasterize_glyph(GlyphId(1), 0.0, (0.0, 0.0)).is_none());
+    // 5351x6560 pixels.
+    assert!(face
+        .rasterize_glyph(GlyphId(1), 10000.0, (0.0, 0.0))
+        .is_none());
+    assert!(face.rasterize_glyph(GlyphId(1), 1e30, (0.0, 0.0)).is_none());
+}
+
+#[test]
+fn too_large() {
+    let mut r = Rasterizer::new(100_000, 100_000);
+    rect(&mut r, 0.0, 0.0, 10.0, 10.0);
+    assert!(r.finish().is_empty());
+
+    assert_eq!(Rasterizer::new(4096, 4096).finish().len(), 4096 * 4096);
+}
@@ -23,6 +23,9 @@ mod format0 {
         let mut vec = vec![];
         subtable.codepoints(|c| vec.push(c));
         assert_eq!(vec, [0x40]);
+
+        let mappings: Vec<_> = subtable.mappings().collect();
+        assert_eq!(mappings, [(0x40, GlyphId(100))]);
     }
 }
 
@@ -93,6 +96,9 @@ mod format2 {
         assert_eq!(subtable.glyph_index(40), Some(GlyphId(100)));
         assert_eq!(subtable.glyph_index(41), Some(GlyphId(1000)));
         assert_eq!(subtable.glyph_index(42), None);
+
+        let mappings: Vec<_> = subtable.mappings().collect();
+        assert_eq!(mappings, [(40, GlyphId(100)), (41, GlyphId(1000))]);
     }
 }
 
@@ -551,5 +557,417 @@ mod format4 {
         let mut vec = vec![];
         subtable.codepoints(|c| vec.push(c));
         assert_eq!(vec, [27, 28, 29, 30, 31, 32, 33, 34, 65533, 65534, 65535]);
+
+        // Glyph ID 0 and glyphs outside the glyph index array are skipped.
+        let mappings: Vec<_> = subtable.mappings().collect();
+        assert_eq!(mappings, [
+            (28, GlyphId(10)),
+            (65533, GlyphId(65534)),
+            (65534, GlyphId(65535)),
+        ]);
+    }
+}
+
+mod format8 {
+    use ttf_parser::{cmap, GlyphId};
+    use crate::{convert, Unit::*};
+
+    #[test]
+    fn mixed_coverage() {
+        let mut data = convert(&[
+            UInt16(8), // format
+            UInt16(0), // reserved
+            UInt32(8232), // subtable size
+            UInt32(0), // language ID
+        ]);
+
+        // Mark 0xD83D as the high word of a 32-bit code.
+        let mut is32 = vec![0u8; 8192];
+        is32[0xD83D / 8] = 0x80 >> (0xD83D % 8);
+        data.extend(is32);
+
+        data.extend(convert(&[
+            UInt32(2), // number of groups
+            // SequentialMapGroup [0]
+            UInt32(65), // start char code
+            UInt32(66), // end char code
+            UInt32(1), // start glyph ID
+            // SequentialMapGroup [1]
+            UInt32(0xD83DDE00), // start char code
+            UInt32(0xD83DDE00), // end char code
+            UInt32(3), // start glyph ID
+        ]));
+
+        let subtable = cmap::Subtable8::parse(&data).unwrap();
+        assert!(subtable.is_32bit(0xD83D));
+        assert!(!subtable.is_32bit(0xD83E));
+        assert!(!subtable.is_32bit(65));
+        assert_eq!(subtable.glyph_index(64), None);
+        assert_eq!(subtable.glyph_index(66), Some(GlyphId(2)));
+        assert_eq!(subtable.glyph_index(0xD83DDE00), Some(GlyphId(3)));
+
+        let mut vec = vec![];
+        subtable.codepoints(|c| vec.push(c));
+        assert_eq!(vec, [65, 66, 0xD83DDE00]);
+
+        // Codes above the Unicode range are ignored.
+        let mappings: Vec<_> = subtable.mappings().collect();
+        assert_eq!(mappings, [(65, GlyphId(1)), (66, GlyphId(2))]);
+    }
+}
+
+mod format12 {
+    use ttf_parser::{cmap, GlyphId};
+    use crate::{convert, Unit::*};
+
+    #[test]
+    fn mappings() {
+        let data = convert(&[
+            UInt16(12), // format
+            UInt16(0), // reserved
+            UInt32(40), // subtable size
+            UInt32(0), // language ID
+            UInt32(2), // number of groups
+            // SequentialMapGroup [0]
+            UInt32(65), // start char code
+            UInt32(67), // end char code
+            UInt32(1), // start glyph ID
+            // SequentialMapGroup [1]
+            UInt32(0x10FFFE), // start char code
+            UInt32(0xFFFFFFFF), // end char code
+            UInt32(10), // start glyph ID
+        ]);
+
+        let subtable = cmap::Subtable12::parse(&data).unwrap();
+        let mappings: Vec<_> = subtable.mappings().collect();
+        // Code points outside the Unicode range are ignored.
+        assert_eq!(mappings, [
+            (65, GlyphId(1)),
+            (66, GlyphId(2)),
+            (67, GlyphId(3)),
+            (0x10FFFE, GlyphId(10)),
+            (0x10FFFF, GlyphId(11)),
+        ]);
+    }
+}
+
+mod format13 {
+    use ttf_parser::{cmap, GlyphId};
+    use crate::{convert, Unit::*};
+
+    #[test]
+    fn mappings() {
+        let data = convert(&[
+            UInt16(13), // format
+            UInt16(0), // reserved
+            UInt32(28), // subtable size
+            UInt32(0), // language ID
+            UInt32(1), // number of groups
+            // ConstantMapGroup [0]
+            UInt32(65), // start char code
+            UInt32(67), // end char code
+            UInt32(1), // glyph ID
+        ]);
+
+        let subtable = cmap::Subtable13::parse(&data).unwrap();
+        let mappings: Vec<_> = subtable.mappings().collect();
+        assert_eq!(mappings, [(65, GlyphId(1)), (66, GlyphId(1)), (67, GlyphId(1))]);
+    }
+}
+
+mod format14 {
+    use ttf_parser::{cmap, GlyphId};
+    use crate::{convert, Unit::*};
+
+    fn subtable_data() -> Vec<u8> {
+        convert(&[
+            UInt16(14), // format
+            UInt32(49), // subtable size
+            UInt32(2), // number of records
+            // VariationSelector Record [0]
+            UInt8(0), UInt16(0xFE0E), // variation selector
+            UInt32(32), // offset to default UVS
+            UInt32(0), // offset to non-default UVS
+            // VariationSelector Record [1]
+            UInt8(0), UInt16(0xFE0F), // variation selector
+            UInt32(0), // offset to default UVS
+            UInt32(40), // offset to non-default UVS
+
+            // Default UVS
+            UInt32(1), // number of ranges
+            // Unicode Range [0]
+            UInt8(0), UInt16(0x2764), // start unicode value
+            UInt8(1), // additional count
+
+            // Non-default UVS
+            UInt32(1), // number of mappings
+            // UVS Mapping [0]
+            UInt8(0), UInt16(0x2764), // unicode value
+            UInt16(7), // glyph ID
+        ])
+    }
+
+    #[test]
+    fn variation_selectors() {
+        let data = subtable_data();
+        let subtable = cmap::Subtable14::parse(&data).unwrap();
+        let selectors = subtable.variation_selectors();
+        assert_eq!(selectors.len(), 2);
+
+        let text = selectors.get(0).unwrap();
+        assert_eq!(text.var_selector, 0xFE0E);
+        assert_eq!(text.default_uvs.len(), 1);
+        assert_eq!(text.default_uvs.get(0).unwrap().end(), 0x2765);
+        assert!(text.non_default_uvs.is_empty());
+        assert!(text.contains(0x2765));
+        assert!(!text.contains(0x2766));
+
+        let emoji = selectors.find(0xFE0F).unwrap();
+        assert!(emoji.default_uvs.is_empty());
+        assert_eq!(
+            emoji.non_default_uvs.get(0),
+            Some(cmap::UvsMapping { code_point: 0x2764, glyph_id: GlyphId(7) })
+        );
+        assert!(emoji.contains(0x2764));
+        assert!(!emoji.contains(0x2765));
+
+        assert!(selectors.find(0xFE00).is_none());
+        assert_eq!(selectors.into_iter().count(), 2);
+    }
+
+    #[test]
+    fn glyph_index() {
+        let data = subtable_data();
+        let subtable = cmap::Subtable14::parse(&data).unwrap();
+        assert_eq!(subtable.glyph_index(0x2764, 0xFE0E), Some(cmap::GlyphVariationResult::UseDefault));
+        assert_eq!(subtable.glyph_index(0x2764, 0xFE0F), Some(cmap::GlyphVariationResult::Found(GlyphId(7))));
+        assert_eq!(subtable.glyph_index(0x2765, 0xFE0F), None);
+        assert_eq!(subtable.glyph_index(0x2764, 0xFE00), None);
+    }
+
+    #[test]
+    fn malformed_offset() {
+        let mut data = subtable_data();
+        data[16] = 0xFF; // default UVS offset of the first record
+        let subtable = cmap::Subtable14::parse(&data).unwrap();
+        let selector = subtable.variation_selectors().get(0).unwrap();
+        assert!(selector.default_uvs.is_empty());
+        assert_eq!(subtable.glyph_index(0x2764, 0xFE0E), None);
+    }
+
+    #[test]
+    fn presentation() {
+        assert_eq!(cmap::Presentation::from_selector('\u{FE0F}'), Some(cmap::Presentation::Emoji));
+        assert_eq!(cmap::Presentation::from_selector('\u{FE00}'), None);
+        assert_eq!(cmap::Presentation::Text.selector(), '\u{FE0E}');
+    }
+
+    #[test]
+    fn glyph_variation() {
+        let variation = cmap::GlyphVariation::Fallback(GlyphId(3));
+        assert_eq!(variation.glyph_id(), GlyphId(3));
+        assert!(!variation.is_supported());
+        assert!(cmap::GlyphVariation::Default(GlyphId(3)).is_supported());
+    }
+}
+
+mod table {
+    use ttf_parser::{cmap, GlyphId, PlatformId};
+    use crate::{convert, Unit::*};
+
+    #[test]
+    fn glyph_chars() {
+        let data = convert(&[
+            UInt16(0), // version
+            UInt16(2), // number of subtables
+            // EncodingRecord [0]
+            UInt16(0), // platform ID
+            UInt16(3), // encoding ID
+            UInt32(20), // offset
+            // EncodingRecord [1]
+            UInt16(3), // platform ID
+            UInt16(10), // encoding ID
+            UInt32(60), // offset
+
+            // Format 4 subtable
+            UInt16(4), // format
+            UInt16(40), // subtable size
+            UInt16(0), // language ID
+            UInt16(6), // 2 x segCount
+            UInt16(4), // search range
+            UInt16(1), // entry selector
+            UInt16(2), // range shift
+            // End character codes
+            UInt16(65), // char code [0]
+            UInt16(66), // char code [1]
+            UInt16(65535), // char code [2]
+            UInt16(0), // reserved
+            // Start character codes
+            UInt16(65), // char code [0]
+            UInt16(66), // char code [1]
+            UInt16(65535), // char code [2]
+            // Deltas
+            Int16(-64), // delta [0]
+            Int16(-65), // delta [1]
+            Int16(1), // delta [2]
+            // Offsets into Glyph index array
+            UInt16(0), // offset [0]
+            UInt16(0), // offset [1]
+            UInt16(0), // offset [2]
+
+            // Format 12 subtable
+            UInt16(12), // format
+            UInt16(0), // reserved
+            UInt32(52), // subtable size
+            UInt32(0), // language ID
+            UInt32(3), // number of groups
+            // SequentialMapGroup [0]
+            UInt32(65), // start char code
+            UInt32(65), // end char code
+            UInt32(7), // start glyph ID
+            // SequentialMapGroup [1]
+            UInt32(67), // start char code
+            UInt32(67), // end char code
+            UInt32(2), // start glyph ID
+            // SequentialMapGroup [2]
+            UInt32(0x1F600), // start char code
+            UInt32(0x1F600), // end char code
+            UInt32(1), // start glyph ID
+        ]);
+
+        let table = cmap::Table::parse(&data).unwrap();
+
+        // Windows full repertoire subtable is preferred.
+        let subtable = table.best_unicode_subtable().unwrap();
+        assert_eq!(subtable.platform_id, PlatformId::Windows);
+        assert_eq!(subtable.encoding_id, 10);
+
+        // `B` is missing in the best subtable, so the format 4 one is used.
+        // `A` is resolved by the best subtable.
+        let chars: Vec<_> = table.glyph_chars(GlyphId(1)).collect();
+        assert_eq!(chars, ['\u{1F600}', 'B']);
+        let chars: Vec<_> = table.glyph_chars(GlyphId(2)).collect();
+        assert_eq!(chars, ['C']);
+        let chars: Vec<_> = table.glyph_chars(GlyphId(7)).collect();
+        assert_eq!(chars, ['A']);
+        assert_eq!(table.glyph_chars(GlyphId(0)).count(), 0);
+    }
+
+    #[test]
+    fn best_unicode_subtable() {
+        let data = convert(&[
+            UInt16(0), // version
+            UInt16(3), // number of subtables
+            // EncodingRecord [0]
+            UInt16(1), // platform ID
+            UInt16(0), // encoding ID
+            UInt32(28), // offset
+            // EncodingRecord [1]
+            UInt16(0), // platform ID
+            UInt16(3), // encoding ID
+            UInt32(28), // offset
+            // EncodingRecord [2]
+            UInt16(3), // platform ID
+            UInt16(1), // encoding ID
+            UInt32(28), // offset
+
+            // Format 6 subtable
+            UInt16(6), // format
+            UInt16(12), // subtable size
+            UInt16(0), // language ID
+            UInt16(65), // first code
+            UInt16(1), // number of codes
+            UInt16(1), // glyph ID [0]
+        ]);
+
+        let table = cmap::Table::parse(&data).unwrap();
+        let subtable = table.best_unicode_subtable().unwrap();
+        assert_eq!(subtable.platform_id, PlatformId::Windows);
+        assert_eq!(subtable.encoding_id, 1);
+    }
+
+    #[test]
+    fn no_unicode_subtables() {
+        let data = convert(&[
+            UInt16(0), // version
+            UInt16(1), // number of subtables
+            // EncodingRecord [0]
+            UInt16(1), // platform ID
+            UInt16(0), // encoding ID
+            UInt32(12), // offset
+
+            // Format 6 subtable
+            UInt16(6), // format
+            UInt16(12), // subtable size
+            UInt16(0), // language ID
+            UInt16(65), // first code
+            UInt16(1), // number of codes
+            UInt16(1), // glyph ID [0]
+        ]);
+
+        let table = cmap::Table::parse(&data).unwrap();
+        assert!(table.best_unicode_subtable().is_none());
+        assert_eq!(table.glyph_chars(GlyphId(1)).count(), 0);
+    }
+
+    fn legacy_table(platform_id: u16, encoding_id: u16, code: u16) -> Vec<u8> {
+        convert(&[
+            UInt16(0), // version
+            UInt16(1), // number of subtables
+            // EncodingRecord [0]
+            UInt16(platform_id), // platform ID
+            UInt16(encoding_id), // encoding ID
+            UInt32(12), // offset
+
+            // Format 6 subtable
+            UInt16(6), // format
+            UInt16(12), // subtable size
+            UInt16(0), // language ID
+            UInt16(code), // first code
+            UInt16(1), // number of codes
+            UInt16(5), // glyph ID [0]
+        ])
+    }
+
+    #[test]
+    fn legacy_mac_roman() {
+        let data = legacy_table(1, 0, 0x8E);
+        let table = cmap::Table::parse(&data).unwrap();
+        let subtable = table.subtables.get(0).unwrap();
+        assert_eq!(subtable.legacy_encoding(), Some(cmap::LegacyEncoding::Macintosh(0)));
+        assert_eq!(table.legacy_glyph_index('é', None), Some(GlyphId(5)));
+        assert_eq!(table.legacy_glyph_index('A', None), None);
+        assert_eq!(table.legacy_glyph_index('Ж', None), None);
+    }
+
+    #[test]
+    fn legacy_johab() {
+        let data = legacy_table(3, 6, 0x8861);
+        let table = cmap::Table::parse(&data).unwrap();
+        assert_eq!(table.legacy_glyph_index('가', None), Some(GlyphId(5)));
+        assert_eq!(table.legacy_glyph_index('각', None), None);
+    }
+
+    #[test]
+    fn legacy_transcoder() {
+        fn transcode(encoding: cmap::LegacyEncoding, c: char) -> Option<u32> {
+            match (encoding, c) {
+                (cmap::LegacyEncoding::ShiftJis, 'あ') => Some(0x82A0),
+                _ => None,
+            }
+        }
+
+        let data = legacy_table(3, 2, 0x82A0);
+        let table = cmap::Table::parse(&data).unwrap();
+        assert_eq!(table.legacy_glyph_index('あ', None), None);
+        assert_eq!(table.legacy_glyph_index('あ', Some(transcode)), Some(GlyphId(5)));
+    }
+
+    #[test]
+    fn unicode_subtables_are_not_legacy() {
+        let data = legacy_table(3, 1, 0x41);
+        let table = cmap::Table::parse(&data).unwrap();
+        assert_eq!(table.subtables.get(0).unwrap().legacy_encoding(), None);
+        assert_eq!(table.legacy_glyph_index('A', None), None);
     }
 }
@@ -0,0 +1,85 @@
+use ttf_parser::{cmap, coverage};
+use crate::{convert, Unit::*};
+
+#[test]
+fn subtable_coverage() {
+    let data = convert(&[
+        UInt16(0), // version
+        UInt16(1), // number of subtables
+        // EncodingRecord [0]
+        UInt16(3), // platform ID
+        UInt16(10), // encoding ID
+        UInt32(12), // offset
+
+        // Format 12 subtable
+        UInt16(12), // format
+        UInt16(0), // reserved
+        UInt32(64), // subtable size
+        UInt32(0), // language ID
+        UInt32(4), // number of groups
+        // SequentialMapGroup [0]
+        UInt32(0x61), // start char code
+        UInt32(0x7A), // end char code
+        UInt32(1), // start glyph ID
+        // SequentialMapGroup [1]
+        UInt32(0xE9), // start char code
+        UInt32(0xE9), // end char code
+        UInt32(30), // start glyph ID
+        // SequentialMapGroup [2]
+        UInt32(0x0378), // start char code, unassigned
+        UInt32(0x0378), // end char code
+        UInt32(31), // start glyph ID
+        // SequentialMapGroup [3]
+        UInt32(0xE000), // start char code
+        UInt32(0xE000), // end char code
+        UInt32(32), // start glyph ID
+    ]);
+
+    let table = cmap::Table::parse(&data).unwrap();
+    let coverage = coverage::Coverage::from_subtable(table.best_unicode_subtable().unwrap());
+
+    let blocks: Vec<_> = coverage.blocks().map(|b| (b.block.name, b.covered, b.total)).collect();
+    assert_eq!(blocks, [
+        ("Basic Latin", 26, 95),
+        ("Latin-1 Supplement", 1, 96),
+        ("Private Use Area", 1, 6400),
+    ]);
+
+    let scripts: Vec<_> = coverage.scripts().map(|s| (s.script.code, s.covered)).collect();
+    assert_eq!(scripts, [("Latn", 27)]);
+
+    let latin = coverage.script("latn").unwrap();
+    assert_eq!(latin.script.name, "Latin");
+    assert_eq!(latin.covered, 27);
+    assert!(latin.percentage() > 1.0 && latin.percentage() < 2.0);
+
+    let greek = coverage.block('α').unwrap();
+    assert_eq!(greek.block.name, "Greek and Coptic");
+    assert_eq!(greek.covered, 0);
+    assert_eq!(greek.percentage(), 0.0);
+
+    assert!(coverage.script("Xxxx").is_none());
+}
+
+#[test]
+fn char_properties() {
+    let block = coverage::Block::from_char('ж').unwrap();
+    assert_eq!((block.start, block.end, block.name), (0x0400, 0x04FF, "Cyrillic"));
+    assert_eq!(coverage::Script::from_char('ж').unwrap().code, "Cyrl");
+    assert_eq!(coverage::Script::from_char('!').unwrap().name, "Common");
+    assert_eq!(coverage::Script::from_char('\u{0378}'), None);
+    assert_eq!(coverage::Script::from_char('\u{E000}'), None);
+    assert_eq!(coverage::Script::from_code("hani").unwrap().name, "Han");
+}
+
+#[test]
+fn language_exemplars() {
+    assert!(coverage::language_exemplars("vi").unwrap().contains('ơ'));
+    assert_eq!(coverage::language_exemplars("pt-BR"), coverage::language_exemplars("pt"));
+    assert_eq!(coverage::language_exemplars("zh-TW"), coverage::language_exemplars("zh-Hant"));
+    assert_eq!(coverage::language_exemplars("zh-CN"), coverage::language_exemplars("zh-Hans"));
+    assert_eq!(coverage::language_exemplars("sr-Latn").unwrap().chars().next(), Some('a'));
+    assert_eq!(coverage::language_exemplars("xx"), None);
+    assert_eq!(coverage::language_exemplars(""), None);
+    assert!(coverage::languages().any(|tag| tag == "hi"));
+}
@@ -0,0 +1,75 @@
+use ttf_parser::gasp::Table;
+use crate::{convert, Unit::*};
+
+#[test]
+fn basic() {
+    let data = convert(&[
+        UInt16(1), // version
+        UInt16(2), // number of ranges
+
+        // GaspRange [0]
+        UInt16(8), // max PPEM
+        UInt16(0x0002), // behavior
+
+        // GaspRange [1]
+        UInt16(0xFFFF), // max PPEM
+        UInt16(0x000F), // behavior
+    ]);
+
+    let table = Table::parse(&data).unwrap();
+    assert_eq!(table.ranges.len(), 2);
+
+    let flags = table.flags(8).unwrap();
+    assert!(!flags.gridfit());
+    assert!(flags.do_gray());
+
+    let flags = table.flags(9).unwrap();
+    assert!(flags.gridfit());
+    assert!(flags.do_gray());
+    assert!(flags.symmetric_gridfit());
+    assert!(flags.symmetric_smoothing());
+}
+
+#[test]
+fn version_0_ignores_symmetric_flags() {
+    let data = convert(&[
+        UInt16(0), // version
+        UInt16(1), // number of ranges
+
+        // GaspRange [0]
+        UInt16(0xFFFF), // max PPEM
+        UInt16(0x000F), // behavior
+    ]);
+
+    let table = Table::parse(&data).unwrap();
+    let flags = table.flags(12).unwrap();
+    assert!(flags.gridfit());
+    assert!(flags.do_gray());
+    assert!(!flags.symmetric_gridfit());
+    assert!(!flags.symmetric_smoothing());
+}
+
+#[test]
+fn ppem_out_of_range() {
+    let data = convert(&[
+        UInt16(1), // version
+        UInt16(1), // number of ranges
+
+        // GaspRange [0]
+        UInt16(8), // max PPEM
+        UInt16(0x0002), // behavior
+    ]);
+
+    let table = Table::parse(&data).unwrap();
+    assert!(table.flags(9).is_none());
+}
+
+#[test]
+fn unknown_version() {
+    let data = convert(&[
+        UInt16(2), // version
+        UInt16(0), // number of ranges
+    ]);
+
+    assert!(Table::parse(&data).is_none());
+}
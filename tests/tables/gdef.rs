@@ -0,0 +1,167 @@
+use ttf_parser::gdef::{CaretValue, Table};
+use ttf_parser::gpos::Device;
+use ttf_parser::GlyphId;
+use crate::{convert, Unit::*};
+
+#[test]
+fn ligature_carets() {
+    let data = convert(&[
+        UInt32(0x00010000), // version
+        UInt16(0), // offset to glyph class definition
+        UInt16(0), // offset to attachment point list
+        UInt16(12), // offset to ligature caret list
+        UInt16(0), // offset to mark attachment class definition
+
+        // Ligature Caret List
+        UInt16(6), // offset to coverage
+        UInt16(1), // number of ligature glyphs
+        UInt16(12), // offset to ligature glyph [0]
+        // Coverage
+        UInt16(1), // format
+        UInt16(1), // number of glyphs
+        UInt16(7), // glyph [0]
+
+        // Ligature Glyph
+        UInt16(3), // number of carets
+        UInt16(8), // offset to caret value [0]
+        UInt16(12), // offset to caret value [1]
+        UInt16(16), // offset to caret value [2]
+        // Caret Value [0]
+        UInt16(1), // format
+        Int16(300), // coordinate
+        // Caret Value [1]
+        UInt16(2), // format
+        UInt16(5), // contour point index
+        // Caret Value [2]
+        UInt16(3), // format
+        Int16(600), // coordinate
+        UInt16(6), // offset to device
+        // Device
+        UInt16(10), // start size
+        UInt16(10), // end size
+        UInt16(1), // delta format
+        UInt16(0x4000), // delta values
+    ]);
+
+    let table = Table::parse(&data).unwrap();
+    assert!(table.ligature_carets(GlyphId(6)).is_none());
+
+    let carets = table.ligature_carets(GlyphId(7)).unwrap();
+    assert_eq!(carets.len(), 3);
+    assert!(matches!(carets.get(0).unwrap(), CaretValue::Coordinate(300)));
+    assert!(matches!(carets.get(1).unwrap(), CaretValue::ContourPoint(5)));
+    match carets.get(2).unwrap() {
+        CaretValue::DeviceCoordinate { coordinate, device } => {
+            assert_eq!(coordinate, 600);
+            match device {
+                Some(Device::Hinting(device)) => {
+                    // One pixel at 10 ppem, in design units.
+                    assert_eq!(device.x_delta(1000, Some((10, 10))), Some(100));
+                }
+                _ => panic!("a hinting device is expected"),
+            }
+        }
+        _ => panic!("a device coordinate is expected"),
+    }
+}
+
+#[test]
+fn attachment_points() {
+    let data = convert(&[
+        UInt32(0x00010000), // version
+        UInt16(0), // offset to glyph class definition
+        UInt16(12), // offset to attachment point list
+        UInt16(0), // offset to ligature caret list
+        UInt16(0), // offset to mark attachment class definition
+
+        // Attachment Point List
+        UInt16(8), // offset to coverage
+        UInt16(2), // number of glyphs
+        UInt16(16), // offset to attachment point [0]
+        UInt16(22), // offset to attachment point [1]
+        // Coverage
+        UInt16(1), // format
+        UInt16(2), // number of glyphs
+        UInt16(3), // glyph [0]
+        UInt16(5), // glyph [1]
+
+        // Attachment Point [0]
+        UInt16(2), // number of points
+        UInt16(1), // point index [0]
+        UInt16(4), // point index [1]
+        // Attachment Point [1]
+        UInt16(1), // number of points
+        UInt16(7), // point index [0]
+    ]);
+
+    let table = Table::parse(&data).unwrap();
+    assert!(table.attachment_points(GlyphId(4)).is_none());
+
+    let points = table.attachment_points(GlyphId(3)).unwrap();
+    assert_eq!(points.into_iter().collect::<Vec<_>>(), vec![1, 4]);
+
+    let points = table.attachment_points(GlyphId(5)).unwrap();
+    assert_eq!(points.into_iter().collect::<Vec<_>>(), vec![7]);
+}
+
+#[cfg(feature = "variable-fonts")]
+#[test]
+fn variation_store() {
+    use ttf_parser::NormalizedCoordinate;
+
+    let data = convert(&[
+        UInt32(0x00010003), // version
+        UInt16(0), // offset to glyph class definition
+        UInt16(0), // offset to attachment point list
+        UInt16(0), // offset to ligature caret list
+        UInt16(0), // offset to mark attachment class definition
+        UInt16(0), // offset to mark glyph sets definition
+        UInt32(18), // offset to item variation store
+
+        // Item Variation Store
+        UInt16(1), // format
+        UInt32(12), // offset to variation region list
+        UInt16(1), // number of item variation data
+        UInt32(28), // offset to item variation data [0]
+
+        // Variation Region List
+        UInt16(1), // number of axes
+        UInt16(2), // number of regions
+        // Region [0]
+        Int16(0), // start coordinate
+        Int16(16384), // peak coordinate
+        Int16(16384), // end coordinate
+        // Region [1]
+        Int16(-16384), // start coordinate
+        Int16(-16384), // peak coordinate
+        Int16(0), // end coordinate
+
+        // Item Variation Data
+        UInt16(1), // number of items
+        UInt16(1), // number of short deltas
+        UInt16(2), // number of region indices
+        UInt16(0), // region index [0]
+        UInt16(1), // region index [1]
+        // Delta Set [0]
+        Int16(100), // delta [0]
+        Int8(-50), // delta [1]
+    ]);
+
+    let table = Table::parse(&data).unwrap();
+    let store = table.variation_store().unwrap();
+    assert_eq!(store.data_count(), 1);
+    assert_eq!(store.item_count(0), Some(1));
+    assert_eq!(store.region_indices(0).unwrap().into_iter().collect::<Vec<_>>(), vec![0, 1]);
+
+    let regions = store.regions;
+    assert_eq!(regions.axis_count(), 1);
+    assert_eq!(regions.len(), 2);
+    let peaks: Vec<i16> = regions.into_iter().map(|r| r.axes.get(0).unwrap().peak_coord).collect();
+    assert_eq!(peaks, vec![16384, -16384]);
+
+    let half = [NormalizedCoordinate::from(8192)];
+    assert_eq!(regions.get(0).unwrap().evaluate(&half), 0.5);
+    assert_eq!(regions.get(1).unwrap().evaluate(&half), 0.0);
+    assert_eq!(store.parse_delta(0, 0, &half), Some(50.0));
+    assert_eq!(table.glyph_variation_delta(0, 0, &[NormalizedCoordinate::from(-16384)]), Some(-50.0));
+}
@@ -0,0 +1,105 @@
+use ttf_parser::opentype_layout::{ClassDefinition, Coverage};
+use ttf_parser::GlyphId;
+use crate::{convert, Unit::*};
+
+#[test]
+fn coverage_format1_iter() {
+    let data = convert(&[
+        UInt16(1), // format
+        UInt16(3), // number of glyphs
+        UInt16(2), // glyph [0]
+        UInt16(5), // glyph [1]
+        UInt16(9), // glyph [2]
+    ]);
+
+    let coverage = Coverage::parse(&data).unwrap();
+    let glyphs: Vec<_> = coverage.iter().collect();
+    assert_eq!(glyphs, vec![(GlyphId(2), 0), (GlyphId(5), 1), (GlyphId(9), 2)]);
+}
+
+#[test]
+fn coverage_format2_iter() {
+    let data = convert(&[
+        UInt16(2), // format
+        UInt16(3), // number of ranges
+        // Range [0]
+        UInt16(3), // start glyph
+        UInt16(5), // end glyph
+        UInt16(0), // start coverage index
+        // Range [1]
+        UInt16(10), // start glyph
+        UInt16(9), // end glyph, malformed
+        UInt16(3), // start coverage index
+        // Range [2]
+        UInt16(65534), // start glyph
+        UInt16(65535), // end glyph
+        UInt16(3), // start coverage index
+    ]);
+
+    let coverage = Coverage::parse(&data).unwrap();
+    let glyphs: Vec<_> = coverage.into_iter().collect();
+    assert_eq!(glyphs, vec![
+        (GlyphId(3), 0),
+        (GlyphId(4), 1),
+        (GlyphId(5), 2),
+        (GlyphId(65534), 3),
+        (GlyphId(65535), 4),
+    ]);
+
+    for (glyph, index) in glyphs {
+        assert_eq!(coverage.get(glyph), Some(index));
+    }
+}
+
+#[test]
+fn class_definition_format1_iter() {
+    let data = convert(&[
+        UInt16(1), // format
+        UInt16(10), // start glyph
+        UInt16(4), // number of glyphs
+        UInt16(1), // class [0]
+        UInt16(0), // class [1]
+        UInt16(2), // class [2]
+        UInt16(1), // class [3]
+    ]);
+
+    let classes = ClassDefinition::parse(&data).unwrap();
+    let glyphs: Vec<_> = classes.iter().collect();
+    assert_eq!(glyphs, vec![(GlyphId(10), 1), (GlyphId(12), 2), (GlyphId(13), 1)]);
+
+    assert_eq!(classes.class_glyphs(1).collect::<Vec<_>>(), vec![GlyphId(10), GlyphId(13)]);
+    assert_eq!(classes.class_glyphs(2).collect::<Vec<_>>(), vec![GlyphId(12)]);
+    assert_eq!(classes.class_glyphs(0).count(), 0);
+    assert_eq!(classes.class_glyphs(3).count(), 0);
+}
+
+#[test]
+fn class_definition_format2_iter() {
+    let data = convert(&[
+        UInt16(2), // format
+        UInt16(3), // number of ranges
+        // Range [0]
+        UInt16(2), // start glyph
+        UInt16(3), // end glyph
+        UInt16(1), // class
+        // Range [1]
+        UInt16(4), // start glyph
+        UInt16(6), // end glyph
+        UInt16(0), // class
+        // Range [2]
+        UInt16(8), // start glyph
+        UInt16(8), // end glyph
+        UInt16(2), // class
+    ]);
+
+    let classes = ClassDefinition::parse(&data).unwrap();
+    let glyphs: Vec<_> = classes.into_iter().collect();
+    assert_eq!(glyphs, vec![(GlyphId(2), 1), (GlyphId(3), 1), (GlyphId(8), 2)]);
+
+    for (glyph, class) in glyphs {
+        assert_eq!(classes.get(glyph), class);
+    }
+
+    assert_eq!(classes.class_glyphs(1).collect::<Vec<_>>(), vec![GlyphId(2), GlyphId(3)]);
+    assert_eq!(classes.class_glyphs(2).collect::<Vec<_>>(), vec![GlyphId(8)]);
+}
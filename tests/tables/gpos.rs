@@ -0,0 +1,213 @@
+use ttf_parser::gdef;
+use ttf_parser::gpos::{KerningPair, KerningPairs};
+use ttf_parser::opentype_layout::LayoutTable;
+use ttf_parser::Tag;
+use crate::{convert, Unit::*};
+
+#[test]
+fn kerning_pairs() {
+    let data = convert(&[
+        UInt16(1), // major version
+        UInt16(0), // minor version
+        UInt16(10), // offset to script list
+        UInt16(12), // offset to feature list
+        UInt16(40), // offset to lookup list
+
+        // Script List
+        UInt16(0), // number of scripts
+
+        // Feature List
+        UInt16(2), // number of features
+        // Feature Record [0]
+        UInt32(Tag::from_bytes(b"kern").0), // tag
+        UInt16(14), // offset to feature
+        // Feature Record [1]
+        UInt32(Tag::from_bytes(b"kern").0), // tag
+        UInt16(22), // offset to feature
+        // Feature [0]
+        UInt16(0), // offset to feature params
+        UInt16(2), // number of lookups
+        UInt16(1), // lookup index [0]
+        UInt16(0), // lookup index [1]
+        // Feature [1]
+        UInt16(0), // offset to feature params
+        UInt16(1), // number of lookups
+        UInt16(0), // lookup index [0]
+
+        // Lookup List
+        UInt16(2), // number of lookups
+        UInt16(6), // offset to lookup [0]
+        UInt16(96), // offset to lookup [1]
+
+        // Lookup [0]
+        UInt16(2), // type: pair adjustment
+        UInt16(0), // flags
+        UInt16(2), // number of subtables
+        UInt16(10), // offset to subtable [0]
+        UInt16(38), // offset to subtable [1]
+
+        // Pair Adjustment [0]
+        UInt16(1), // format
+        UInt16(12), // offset to coverage
+        UInt16(0x0004), // value format 1: x advance
+        UInt16(0), // value format 2
+        UInt16(1), // number of pair sets
+        UInt16(18), // offset to pair set [0]
+        // Coverage
+        UInt16(1), // format
+        UInt16(1), // number of glyphs
+        UInt16(1), // glyph [0]
+        // Pair Set [0]
+        UInt16(2), // number of pair values
+        UInt16(2), // second glyph
+        Int16(-50), // x advance
+        UInt16(3), // second glyph
+        Int16(0), // x advance, shadows the next subtable
+
+        // Pair Adjustment [1]
+        UInt16(2), // format
+        UInt16(24), // offset to coverage
+        UInt16(0x0004), // value format 1: x advance
+        UInt16(0), // value format 2
+        UInt16(32), // offset to class definition 1
+        UInt16(42), // offset to class definition 2
+        UInt16(2), // number of class 1 records
+        UInt16(2), // number of class 2 records
+        Int16(0), // class 0 x class 0
+        Int16(-20), // class 0 x class 1
+        Int16(10), // class 1 x class 0
+        Int16(-30), // class 1 x class 1
+        // Coverage
+        UInt16(1), // format
+        UInt16(2), // number of glyphs
+        UInt16(1), // glyph [0]
+        UInt16(4), // glyph [1]
+        // Class Definition 1
+        UInt16(2), // format
+        UInt16(1), // number of ranges
+        UInt16(4), // start glyph
+        UInt16(4), // end glyph
+        UInt16(1), // class
+        // Class Definition 2
+        UInt16(1), // format
+        UInt16(2), // start glyph
+        UInt16(2), // number of glyphs
+        UInt16(1), // class [0]
+        UInt16(1), // class [1]
+
+        // Lookup [1]
+        UInt16(2), // type: pair adjustment
+        UInt16(0x0008), // flags: ignore marks
+        UInt16(1), // number of subtables
+        UInt16(8), // offset to subtable [0]
+
+        // Pair Adjustment
+        UInt16(1), // format
+        UInt16(14), // offset to coverage
+        UInt16(0x0004), // value format 1: x advance
+        UInt16(0), // value format 2
+        UInt16(2), // number of pair sets
+        UInt16(22), // offset to pair set [0]
+        UInt16(32), // offset to pair set [1]
+        // Coverage
+        UInt16(1), // format
+        UInt16(2), // number of glyphs
+        UInt16(4), // glyph [0]
+        UInt16(5), // glyph [1]
+        // Pair Set [0]
+        UInt16(2), // number of pair values
+        UInt16(2), // second glyph
+        Int16(-5), // x advance
+        UInt16(5), // second glyph
+        Int16(-10), // x advance
+        // Pair Set [1]
+        UInt16(1), // number of pair values
+        UInt16(1), // second glyph
+        Int16(7), // x advance
+    ]);
+
+    let gdef_data = convert(&[
+        UInt32(0x00010000), // version
+        UInt16(12), // offset to glyph class definition
+        UInt16(0), // offset to attachment point list
+        UInt16(0), // offset to ligature caret list
+        UInt16(0), // offset to mark attachment class definition
+
+        // Class Definition
+        UInt16(2), // format
+        UInt16(1), // number of ranges
+        UInt16(5), // start glyph
+        UInt16(5), // end glyph
+        UInt16(3), // class: mark
+    ]);
+
+    let table = LayoutTable::parse(&data).unwrap();
+    let gdef = gdef::Table::parse(&gdef_data);
+    let pairs = |gdef| -> Vec<(u16, u16, i32)> {
+        KerningPairs::new(table, gdef, 6, None)
+            .unwrap()
+            .map(|KerningPair { left, right, value }| (left.0, right.0, value))
+            .collect()
+    };
+
+    // Glyph 5 is a mark, therefore it is ignored by lookup 1.
+    assert_eq!(pairs(gdef), vec![
+        (1, 2, -50),
+        (4, 2, -35),
+        (4, 3, -30),
+        (4, 0, 10),
+        (4, 1, 10),
+        (4, 4, 10),
+        (4, 5, 10),
+    ]);
+
+    // Without GDEF, (4, 5) is cancelled out by lookup 1.
+    assert_eq!(pairs(None), vec![
+        (1, 2, -50),
+        (4, 2, -35),
+        (4, 3, -30),
+        (4, 0, 10),
+        (4, 1, 10),
+        (4, 4, 10),
+        (5, 1, 7),
+    ]);
+}
+
+#[test]
+fn too_many_kern_lookups() {
+    let data = |count: u16| {
+        let mut units = vec![
+            UInt16(1), // major version
+            UInt16(0), // minor version
+            UInt16(10), // offset to script list
+            UInt16(12), // offset to feature list
+            UInt16(24 + count * 2), // offset to lookup list
+
+            // Script List
+            UInt16(0), // number of scripts
+
+            // Feature List
+            UInt16(1), // number of features
+            // Feature Record [0]
+            Raw(b"kern"), // tag
+            UInt16(8), // offset
+
+            // Feature [0]
+            UInt16(0), // offset to parameters
+            UInt16(count), // number of lookups
+        ];
+        // Lookup indices.
+        units.extend((0..count).map(UInt16));
+        // Lookup List
+        units.push(UInt16(0)); // number of lookups
+        convert(&units)
+    };
+
+    let data_256 = data(256);
+    let table = LayoutTable::parse(&data_256).unwrap();
+    assert!(KerningPairs::new(table, None, 1, None).is_some());
+
+    let data_257 = data(257);
+    let table = LayoutTable::parse(&data_257).unwrap();
+    assert!(KerningPairs::new(table, None, 1, None).is_none());
+}
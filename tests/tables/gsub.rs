@@ -0,0 +1,597 @@
+use ttf_parser::gsub::{self, GlyphAlternates, GlyphSet};
+use ttf_parser::opentype_layout::{Feature, FeatureParams, LayoutTable};
+use ttf_parser::{GlyphId, NormalizedCoordinate, Tag};
+use crate::{convert, Unit::*};
+
+fn layout_table() -> Vec<u8> {
+    convert(&[
+        UInt16(1), // major version
+        UInt16(0), // minor version
+        UInt16(10), // offset to script list
+        UInt16(46), // offset to feature list
+        UInt16(115), // offset to lookup list
+
+        // Script List
+        UInt16(1), // number of scripts
+        // Script Record [0]
+        Raw(b"latn"), // tag
+        UInt16(8), // offset
+
+        // Script
+        UInt16(10), // offset to default language system
+        UInt16(1), // number of language systems
+        // Language System Record [0]
+        Raw(b"TRK "), // tag
+        UInt16(20), // offset
+
+        // Default Language System
+        UInt16(0), // lookup order
+        UInt16(0xFFFF), // required feature index: none
+        UInt16(2), // number of features
+        UInt16(0), // feature index [0]
+        UInt16(1), // feature index [1]
+
+        // Language System
+        UInt16(0), // lookup order
+        UInt16(2), // required feature index
+        UInt16(1), // number of features
+        UInt16(3), // feature index [0]
+
+        // Feature List
+        UInt16(4), // number of features
+        // Feature Record [0]
+        Raw(b"cv01"), // tag
+        UInt16(26), // offset
+        // Feature Record [1]
+        Raw(b"size"), // tag
+        UInt16(47), // offset
+        // Feature Record [2]
+        Raw(b"ss01"), // tag
+        UInt16(61), // offset
+        // Feature Record [3]
+        Raw(b"ss02"), // tag
+        UInt16(47), // offset, shares the size feature
+
+        // Feature [0]
+        UInt16(4), // offset to parameters
+        UInt16(0), // number of lookups
+        // Character Variant Parameters
+        UInt16(0), // format
+        UInt16(256), // label name ID
+        UInt16(257), // tooltip name ID
+        UInt16(0), // sample text name ID: none
+        UInt16(2), // number of named parameters
+        UInt16(258), // first parameter label name ID
+        UInt16(1), // number of characters
+        UInt8(0), UInt16(0x61), // character [0]
+
+        // Feature [1]
+        UInt16(4), // offset to parameters
+        UInt16(0), // number of lookups
+        // Size Parameters
+        UInt16(100), // design size
+        UInt16(1), // subfamily ID
+        UInt16(260), // subfamily name ID
+        UInt16(80), // range start
+        UInt16(140), // range end
+
+        // Feature [2]
+        UInt16(4), // offset to parameters
+        UInt16(0), // number of lookups
+        // Stylistic Set Parameters
+        UInt16(0), // version
+        UInt16(261), // UI name ID
+
+        // Lookup List
+        UInt16(0), // number of lookups
+    ])
+}
+
+#[test]
+fn feature_params() {
+    let data = layout_table();
+    let table = LayoutTable::parse(&data).unwrap();
+
+    let feature = table.features.get(0).unwrap();
+    let params = match feature.params {
+        Some(FeatureParams::CharacterVariant(params)) => params,
+        _ => panic!("character variant parameters are expected"),
+    };
+    assert_eq!(params.feature_ui_label_name_id, Some(256));
+    assert_eq!(params.feature_ui_tooltip_text_name_id, Some(257));
+    assert_eq!(params.sample_text_name_id, None);
+    assert_eq!(params.param_ui_label_name_id(0), Some(258));
+    assert_eq!(params.param_ui_label_name_id(1), Some(259));
+    assert_eq!(params.param_ui_label_name_id(2), None);
+    assert_eq!(params.characters.len(), 1);
+    assert_eq!(params.characters.get(0).unwrap().0, 0x61);
+    assert_eq!(feature.params.unwrap().ui_name_id(), Some(256));
+
+    let params = match table.features.get(1).unwrap().params {
+        Some(FeatureParams::Size(params)) => params,
+        _ => panic!("size parameters are expected"),
+    };
+    assert_eq!(params.design_size, 100);
+    assert_eq!(params.subfamily_id, 1);
+    assert_eq!(params.subfamily_name_id, Some(260));
+    assert!(!params.contains(80));
+    assert!(params.contains(81));
+    assert!(params.contains(140));
+    assert!(!params.contains(141));
+
+    let params = table.features.get(2).unwrap().params.unwrap();
+    assert!(matches!(params, FeatureParams::StylisticSet(_)));
+    assert_eq!(params.ui_name_id(), Some(261));
+
+    // Parameters format is defined by the feature tag.
+    let feature = table.features.get(3).unwrap();
+    assert_eq!(feature.tag, Tag::from_bytes(b"ss02"));
+    assert!(feature.params.is_none());
+}
+
+#[test]
+fn language_features() {
+    let data = layout_table();
+    let table = LayoutTable::parse(&data).unwrap();
+
+    let latn = Tag::from_bytes(b"latn");
+    let tags = |language| -> Vec<(u16, Tag)> {
+        table
+            .language_features(latn, language)
+            .unwrap()
+            .map(|(index, feature)| (index, feature.tag))
+            .collect()
+    };
+
+    let default = vec![
+        (0, Tag::from_bytes(b"cv01")),
+        (1, Tag::from_bytes(b"size")),
+    ];
+    assert_eq!(tags(None), default);
+    assert_eq!(tags(Some(Tag::from_bytes(b"DEU "))), default);
+    assert_eq!(
+        tags(Some(Tag::from_bytes(b"TRK "))),
+        vec![
+            (2, Tag::from_bytes(b"ss01")),
+            (3, Tag::from_bytes(b"ss02")),
+        ]
+    );
+
+    assert!(table
+        .language_features(Tag::from_bytes(b"cyrl"), None)
+        .is_none());
+}
+
+#[test]
+fn feature_variations() {
+    let data = convert(&[
+        UInt16(1), // major version
+        UInt16(1), // minor version
+        UInt16(14), // offset to script list
+        UInt16(34), // offset to feature list
+        UInt16(48), // offset to lookup list
+        UInt32(50), // offset to feature variations
+
+        // Script List
+        UInt16(1), // number of scripts
+        // Script Record [0]
+        Raw(b"DFLT"), // tag
+        UInt16(8), // offset
+
+        // Script
+        UInt16(4), // offset to default language system
+        UInt16(0), // number of language systems
+
+        // Default Language System
+        UInt16(0), // lookup order
+        UInt16(0xFFFF), // required feature index: none
+        UInt16(1), // number of features
+        UInt16(0), // feature index [0]
+
+        // Feature List
+        UInt16(1), // number of features
+        // Feature Record [0]
+        Raw(b"rvrn"), // tag
+        UInt16(8), // offset
+
+        // Feature [0]
+        UInt16(0), // offset to parameters
+        UInt16(1), // number of lookups
+        UInt16(0), // lookup index [0]
+
+        // Lookup List
+        UInt16(0), // number of lookups
+
+        // Feature Variations
+        UInt16(1), // major version
+        UInt16(0), // minor version
+        UInt32(1), // number of records
+        // Feature Variation Record [0]
+        UInt32(16), // offset to condition set
+        UInt32(30), // offset to feature table substitution
+
+        // Condition Set
+        UInt16(1), // number of conditions
+        UInt32(6), // offset to condition [0]
+
+        // Condition
+        UInt16(1), // format
+        UInt16(0), // axis index
+        Int16(8192), // filter range min: 0.5
+        Int16(16384), // filter range max: 1.0
+
+        // Feature Table Substitution
+        UInt16(1), // major version
+        UInt16(0), // minor version
+        UInt16(1), // number of substitutions
+        // Feature Table Substitution Record [0]
+        UInt16(0), // feature index
+        UInt32(12), // offset to alternate feature
+
+        // Alternate Feature
+        UInt16(0), // offset to parameters
+        UInt16(1), // number of lookups
+        UInt16(5), // lookup index [0]
+    ]);
+
+    let table = LayoutTable::parse(&data).unwrap();
+    let default = [NormalizedCoordinate::from(0.0)];
+    let bold = [NormalizedCoordinate::from(0.75)];
+
+    assert_eq!(table.variation_index(&default), None);
+    assert_eq!(table.variation_index(&bold), Some(0));
+
+    let lookups = |feature: Feature| feature.lookup_indices.into_iter().collect::<Vec<_>>();
+    let feature = table.feature_variation(0, None).unwrap();
+    assert_eq!(lookups(feature), vec![0]);
+    let feature = table.feature_variation(0, Some(0)).unwrap();
+    assert_eq!(feature.tag, Tag::from_bytes(b"rvrn"));
+    assert_eq!(lookups(feature), vec![5]);
+
+    let dflt = Tag::from_bytes(b"DFLT");
+    let features = |coords| -> Vec<Vec<u16>> {
+        table
+            .language_feature_variations(dflt, None, coords)
+            .unwrap()
+            .map(|(_, feature)| lookups(feature))
+            .collect()
+    };
+    assert_eq!(features(&default), vec![vec![0]]);
+    assert_eq!(features(&bold), vec![vec![5]]);
+}
+
+#[test]
+fn feature_variation_params() {
+    let data = convert(&[
+        UInt16(1), // major version
+        UInt16(1), // minor version
+        UInt16(14), // offset to script list
+        UInt16(34), // offset to feature list
+        UInt16(52), // offset to lookup list
+        UInt32(54), // offset to feature variations
+
+        // Script List
+        UInt16(1), // number of scripts
+        // Script Record [0]
+        Raw(b"DFLT"), // tag
+        UInt16(8), // offset
+
+        // Script
+        UInt16(4), // offset to default language system
+        UInt16(0), // number of language systems
+
+        // Default Language System
+        UInt16(0), // lookup order
+        UInt16(0xFFFF), // required feature index: none
+        UInt16(1), // number of features
+        UInt16(0), // feature index [0]
+
+        // Feature List
+        UInt16(1), // number of features
+        // Feature Record [0]
+        Raw(b"ss01"), // tag
+        UInt16(8), // offset
+
+        // Feature [0]
+        UInt16(6), // offset to parameters
+        UInt16(1), // number of lookups
+        UInt16(0), // lookup index [0]
+        // Stylistic Set Parameters
+        UInt16(0), // version
+        UInt16(256), // UI name ID
+
+        // Lookup List
+        UInt16(0), // number of lookups
+
+        // Feature Variations
+        UInt16(1), // major version
+        UInt16(0), // minor version
+        UInt32(1), // number of records
+        // Feature Variation Record [0]
+        UInt32(16), // offset to condition set
+        UInt32(30), // offset to feature table substitution
+
+        // Condition Set
+        UInt16(1), // number of conditions
+        UInt32(6), // offset to condition [0]
+
+        // Condition
+        UInt16(1), // format
+        UInt16(0), // axis index
+        Int16(8192), // filter range min: 0.5
+        Int16(16384), // filter range max: 1.0
+
+        // Feature Table Substitution
+        UInt16(1), // major version
+        UInt16(0), // minor version
+        UInt16(1), // number of substitutions
+        // Feature Table Substitution Record [0]
+        UInt16(0), // feature index
+        UInt32(12), // offset to alternate feature
+
+        // Alternate Feature
+        UInt16(6), // offset to parameters
+        UInt16(1), // number of lookups
+        UInt16(5), // lookup index [0]
+        // Stylistic Set Parameters
+        UInt16(0), // version
+        UInt16(257), // UI name ID
+    ]);
+
+    let table = LayoutTable::parse(&data).unwrap();
+
+    let feature = table.feature_variation(0, None).unwrap();
+    assert_eq!(feature.params.unwrap().ui_name_id(), Some(256));
+
+    // Parameters of the alternate feature are parsed using the original feature tag.
+    let feature = table.feature_variation(0, Some(0)).unwrap();
+    assert_eq!(feature.tag, Tag::from_bytes(b"ss01"));
+    let params = feature.params.unwrap();
+    assert!(matches!(params, FeatureParams::StylisticSet(_)));
+    assert_eq!(params.ui_name_id(), Some(257));
+}
+
+#[test]
+fn closure() {
+    let data = convert(&[
+        UInt16(1), // major version
+        UInt16(0), // minor version
+        UInt16(10), // offset to script list
+        UInt16(12), // offset to feature list
+        UInt16(14), // offset to lookup list
+
+        // Script List
+        UInt16(0), // number of scripts
+
+        // Feature List
+        UInt16(0), // number of features
+
+        // Lookup List
+        UInt16(4), // number of lookups
+        UInt16(10), // offset to lookup [0]
+        UInt16(30), // offset to lookup [1]
+        UInt16(62), // offset to lookup [2]
+        UInt16(92), // offset to lookup [3]
+
+        // Lookup [0]
+        UInt16(1), // type: single
+        UInt16(0), // flags
+        UInt16(1), // number of subtables
+        UInt16(8), // offset to subtable [0]
+        // Single Substitution
+        UInt16(1), // format
+        UInt16(6), // offset to coverage
+        Int16(10), // delta
+        // Coverage
+        UInt16(1), // format
+        UInt16(1), // number of glyphs
+        UInt16(1), // glyph [0]
+
+        // Lookup [1]
+        UInt16(4), // type: ligature
+        UInt16(0), // flags
+        UInt16(1), // number of subtables
+        UInt16(8), // offset to subtable [0]
+        // Ligature Substitution
+        UInt16(1), // format
+        UInt16(8), // offset to coverage
+        UInt16(1), // number of ligature sets
+        UInt16(14), // offset to ligature set [0]
+        // Coverage
+        UInt16(1), // format
+        UInt16(1), // number of glyphs
+        UInt16(11), // glyph [0]
+        // Ligature Set
+        UInt16(1), // number of ligatures
+        UInt16(4), // offset to ligature [0]
+        // Ligature
+        UInt16(20), // ligature glyph
+        UInt16(2), // number of components
+        UInt16(2), // component [1]
+
+        // Lookup [2]
+        UInt16(6), // type: chained context
+        UInt16(0), // flags
+        UInt16(1), // number of subtables
+        UInt16(8), // offset to subtable [0]
+        // Chained Context
+        UInt16(3), // format
+        UInt16(0), // number of backtrack coverages
+        UInt16(1), // number of input coverages
+        UInt16(16), // offset to input coverage [0]
+        UInt16(0), // number of lookahead coverages
+        UInt16(1), // number of lookup records
+        // Sequence Lookup Record [0]
+        UInt16(0), // sequence index
+        UInt16(3), // lookup index
+        // Coverage
+        UInt16(1), // format
+        UInt16(1), // number of glyphs
+        UInt16(20), // glyph [0]
+
+        // Lookup [3]
+        UInt16(3), // type: alternate
+        UInt16(0), // flags
+        UInt16(1), // number of subtables
+        UInt16(8), // offset to subtable [0]
+        // Alternate Substitution
+        UInt16(1), // format
+        UInt16(8), // offset to coverage
+        UInt16(1), // number of alternate sets
+        UInt16(14), // offset to alternate set [0]
+        // Coverage
+        UInt16(1), // format
+        UInt16(1), // number of glyphs
+        UInt16(20), // glyph [0]
+        // Alternate Set
+        UInt16(2), // number of alternates
+        UInt16(30), // alternate [0]
+        UInt16(31), // alternate [1]
+    ]);
+
+    let table = LayoutTable::parse(&data).unwrap();
+    let closure = |glyphs: &[u16]| -> Vec<u16> {
+        let mut set = GlyphSet::new();
+        set.extend(glyphs.iter().map(|g| GlyphId(*g)));
+        // Lookup 3 is reachable only through the chained context lookup.
+        gsub::closure(table.lookups, vec![0, 1, 2], &mut set);
+        set.iter().map(|g| g.0).collect()
+    };
+
+    assert_eq!(closure(&[1]), vec![1, 11]);
+    assert_eq!(closure(&[2]), vec![2]);
+    assert_eq!(closure(&[1, 2]), vec![1, 2, 11, 20, 30, 31]);
+    assert_eq!(closure(&[20]), vec![20, 30, 31]);
+}
+
+#[test]
+fn glyph_set() {
+    let mut set = GlyphSet::new();
+    assert!(set.is_empty());
+    assert!(set.insert(GlyphId(65535)));
+    assert!(set.insert(GlyphId(0)));
+    assert!(set.insert(GlyphId(33)));
+    assert!(!set.insert(GlyphId(33)));
+    assert_eq!(set.len(), 3);
+    assert!(set.contains(GlyphId(33)));
+    assert!(!set.contains(GlyphId(32)));
+    assert_eq!(
+        set.iter().collect::<Vec<_>>(),
+        vec![GlyphId(0), GlyphId(33), GlyphId(65535)]
+    );
+}
+
+#[test]
+fn glyph_alternates() {
+    let data = convert(&[
+        UInt16(1), // major version
+        UInt16(0), // minor version
+        UInt16(10), // offset to script list
+        UInt16(12), // offset to feature list
+        UInt16(50), // offset to lookup list
+
+        // Script List
+        UInt16(0), // number of scripts
+
+        // Feature List
+        UInt16(4), // number of features
+        // Feature Record [0]
+        Raw(b"aalt"), // tag
+        UInt16(26), // offset
+        // Feature Record [1]
+        Raw(b"salt"), // tag
+        UInt16(32), // offset
+        // Feature Record [2]
+        Raw(b"salt"), // tag
+        UInt16(32), // offset, the same feature for another script
+        // Feature Record [3]
+        Raw(b"smcp"), // tag
+        UInt16(32), // offset
+
+        // Feature [0]
+        UInt16(0), // offset to parameters
+        UInt16(1), // number of lookups
+        UInt16(1), // lookup index [0]
+
+        // Feature [1]
+        UInt16(0), // offset to parameters
+        UInt16(1), // number of lookups
+        UInt16(0), // lookup index [0]
+
+        // Lookup List
+        UInt16(2), // number of lookups
+        UInt16(6), // offset to lookup [0]
+        UInt16(28), // offset to lookup [1]
+
+        // Lookup [0]
+        UInt16(1), // type: single
+        UInt16(0), // flags
+        UInt16(1), // number of subtables
+        UInt16(8), // offset to subtable [0]
+        // Single Substitution
+        UInt16(2), // format
+        UInt16(8), // offset to coverage
+        UInt16(1), // number of substitutes
+        UInt16(50), // substitute [0]
+        // Coverage
+        UInt16(1), // format
+        UInt16(1), // number of glyphs
+        UInt16(5), // glyph [0]
+
+        // Lookup [1]
+        UInt16(3), // type: alternate
+        UInt16(0), // flags
+        UInt16(1), // number of subtables
+        UInt16(8), // offset to subtable [0]
+        // Alternate Substitution
+        UInt16(1), // format
+        UInt16(8), // offset to coverage
+        UInt16(1), // number of alternate sets
+        UInt16(14), // offset to alternate set [0]
+        // Coverage
+        UInt16(1), // format
+        UInt16(1), // number of glyphs
+        UInt16(5), // glyph [0]
+        // Alternate Set
+        UInt16(3), // number of alternates
+        UInt16(51), // alternate [0]
+        UInt16(5), // alternate [1], the glyph itself
+        UInt16(52), // alternate [2]
+    ]);
+
+    let table = LayoutTable::parse(&data).unwrap();
+    let alternates = |glyph| -> Vec<(Tag, u16, u16)> {
+        GlyphAlternates::new(table, None, GlyphId(glyph))
+            .map(|alt| (alt.feature, alt.glyph.0, alt.index))
+            .collect()
+    };
+
+    assert_eq!(
+        alternates(5),
+        vec![
+            (Tag::from_bytes(b"aalt"), 51, 0),
+            (Tag::from_bytes(b"aalt"), 52, 2),
+            (Tag::from_bytes(b"salt"), 50, 0),
+            (Tag::from_bytes(b"smcp"), 50, 0),
+        ]
+    );
+    assert!(alternates(6).is_empty());
+
+    // Unsorted feature records are still deduplicated.
+    let mut data = data;
+    let (first, second) = data[14..26].split_at_mut(6);
+    first.swap_with_slice(second);
+    let table = LayoutTable::parse(&data).unwrap();
+    let alternates: Vec<_> = GlyphAlternates::new(table, None, GlyphId(5))
+        .map(|alt| (alt.feature, alt.glyph.0))
+        .collect();
+    assert_eq!(
+        alternates,
+        vec![
+            (Tag::from_bytes(b"salt"), 50),
+            (Tag::from_bytes(b"aalt"), 51),
+            (Tag::from_bytes(b"aalt"), 52),
+            (Tag::from_bytes(b"smcp"), 50),
+        ]
+    );
+}
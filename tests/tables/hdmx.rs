@@ -0,0 +1,57 @@
+use std::num::NonZeroU16;
+use ttf_parser::GlyphId;
+use ttf_parser::hdmx::Table;
+use crate::{convert, Unit::*};
+
+#[test]
+fn basic() {
+    let data = convert(&[
+        UInt16(0), // version
+        Int16(2), // number of records
+        Int32(8), // size of a device record
+
+        // DeviceRecord [0]
+        UInt8(12), // pixel size
+        UInt8(9), // max width
+        UInt8(7), // width [0]
+        UInt8(9), // width [1]
+        UInt8(8), // width [2]
+        UInt8(0), // padding
+        UInt8(0), // padding
+        UInt8(0), // padding
+
+        // DeviceRecord [1]
+        UInt8(16), // pixel size
+        UInt8(12), // max width
+        UInt8(10), // width [0]
+        UInt8(12), // width [1]
+        UInt8(11), // width [2]
+        UInt8(0), // padding
+        UInt8(0), // padding
+        UInt8(0), // padding
+    ]);
+
+    let table = Table::parse(NonZeroU16::new(3).unwrap(), &data).unwrap();
+    assert_eq!(table.records.len(), 2);
+    assert_eq!(table.records.get(1).unwrap().max_width, 12);
+    assert_eq!(table.advance(GlyphId(1), 12), Some(9));
+    assert_eq!(table.advance(GlyphId(2), 16), Some(11));
+    assert_eq!(table.advance(GlyphId(3), 16), None);
+    assert_eq!(table.advance(GlyphId(0), 14), None);
+    assert_eq!(table.advance(GlyphId(0), 300), None);
+}
+
+#[test]
+fn record_size_too_small() {
+    let data = convert(&[
+        UInt16(0), // version
+        Int16(1), // number of records
+        Int32(2), // size of a device record
+
+        // DeviceRecord [0]
+        UInt8(12), // pixel size
+        UInt8(9), // max width
+    ]);
+
+    assert!(Table::parse(NonZeroU16::new(3).unwrap(), &data).is_none());
+}
@@ -0,0 +1,65 @@
+use ttf_parser::head::{IndexToLocationFormat, Table};
+use crate::{convert, Unit::*};
+
+#[test]
+fn parse() {
+    let data = convert(&[
+        Fixed(1.0), // version
+        Fixed(2.5), // font revision
+        UInt32(0), // checksum adjustment
+        UInt32(0x5F0F3CF5), // magic number
+        UInt16(0x0013), // flags
+        UInt16(1000), // units per EM
+        UInt32(0), UInt32(2082844800), // created time
+        UInt32(0), UInt32(2082844800 + 86400), // modified time
+        Int16(-100), // x min
+        Int16(-200), // y min
+        Int16(900), // x max
+        Int16(800), // y max
+        UInt16(0x0003), // mac style
+        UInt16(8), // lowest PPEM
+        Int16(2), // font direction hint
+        Int16(1), // index to location format
+        Int16(0), // glyph data format
+    ]);
+
+    let table = Table::parse(&data).unwrap();
+    assert_eq!(table.font_revision, 2.5);
+    assert!(table.flags.baseline_at_y0());
+    assert!(table.flags.left_sidebearing_at_x0());
+    assert!(table.flags.instructions_may_alter_advance());
+    assert!(!table.flags.force_integer_ppem());
+    assert_eq!(table.units_per_em, 1000);
+    assert_eq!(table.created, 0);
+    assert_eq!(table.modified, 86400);
+    assert_eq!(table.global_bbox.y_max, 800);
+    assert!(table.mac_style.bold());
+    assert!(table.mac_style.italic());
+    assert!(!table.mac_style.underline());
+    assert_eq!(table.lowest_rec_ppem, 8);
+    assert_eq!(table.font_direction_hint, 2);
+    assert_eq!(table.index_to_location_format, IndexToLocationFormat::Long);
+}
+
+#[test]
+fn time_before_unix_epoch() {
+    let data = convert(&[
+        Fixed(1.0), // version
+        Fixed(1.0), // font revision
+        UInt32(0), // checksum adjustment
+        UInt32(0x5F0F3CF5), // magic number
+        UInt16(0), // flags
+        UInt16(1000), // units per EM
+        UInt32(0), UInt32(0), // created time
+        UInt32(0), UInt32(0), // modified time
+        Raw(&[0; 8]), // bbox
+        UInt16(0), // mac style
+        UInt16(8), // lowest PPEM
+        Int16(2), // font direction hint
+        Int16(0), // index to location format
+        Int16(0), // glyph data format
+    ]);
+
+    let table = Table::parse(&data).unwrap();
+    assert_eq!(table.created, -2082844800);
+}
@@ -0,0 +1,29 @@
+use ttf_parser::hhea::Table;
+use crate::{convert, Unit::*};
+
+#[test]
+fn caret() {
+    let data = convert(&[
+        Fixed(1.0), // version
+        Int16(800), // ascender
+        Int16(-200), // descender
+        Int16(90), // line gap
+        UInt16(1000), // advance width max
+        Int16(-50), // min left side bearing
+        Int16(-60), // min right side bearing
+        Int16(950), // x max extent
+        Int16(1000), // caret slope rise
+        Int16(200), // caret slope run
+        Int16(-15), // caret offset
+        Raw(&[0; 8]), // reserved
+        Int16(0), // metric data format
+        UInt16(5), // number of metrics
+    ]);
+
+    let table = Table::parse(&data).unwrap();
+    assert_eq!(table.line_gap, 90);
+    assert_eq!(table.caret_slope_rise, 1000);
+    assert_eq!(table.caret_slope_run, 200);
+    assert_eq!(table.caret_offset, -15);
+    assert_eq!(table.number_of_metrics, 5);
+}
@@ -0,0 +1,51 @@
+use std::num::NonZeroU16;
+use ttf_parser::GlyphId;
+use ttf_parser::lcar::{Carets, Table};
+use crate::{convert, Unit::*};
+
+fn data(format: u16) -> Vec<u8> {
+    convert(&[
+        Fixed(1.0), // version
+        UInt16(format), // format
+
+        // Lookup Table
+        UInt16(8), // format
+        UInt16(5), // first glyph
+        UInt16(1), // number of glyphs
+        UInt16(14), // offset [0]
+
+        // Ligature Caret Class Entry
+        UInt16(2), // number of carets
+        UInt16(100), // caret [0]
+        UInt16(200), // caret [1]
+    ])
+}
+
+#[test]
+fn distances() {
+    let data = data(0);
+    let table = Table::parse(NonZeroU16::new(10).unwrap(), &data).unwrap();
+    match table.carets(GlyphId(5)).unwrap() {
+        Carets::Distances(carets) => {
+            assert_eq!(carets.into_iter().collect::<Vec<_>>(), vec![100, 200]);
+        }
+        _ => panic!("distances are expected"),
+    }
+    assert!(table.carets(GlyphId(4)).is_none());
+    assert!(table.carets(GlyphId(6)).is_none());
+}
+
+#[test]
+fn control_points() {
+    let data = data(1);
+    let table = Table::parse(NonZeroU16::new(10).unwrap(), &data).unwrap();
+    let carets = table.carets(GlyphId(5)).unwrap();
+    assert_eq!(carets.len(), 2);
+    assert!(matches!(carets, Carets::ControlPoints(_)));
+}
+
+#[test]
+fn invalid_format() {
+    let data = data(2);
+    assert!(Table::parse(NonZeroU16::new(10).unwrap(), &data).is_none());
+}
@@ -3,12 +3,28 @@
 #[rustfmt::skip] mod cff1;
 #[rustfmt::skip] mod cmap;
 #[rustfmt::skip] mod colr;
+#[rustfmt::skip] mod coverage;
 #[rustfmt::skip] mod feat;
+#[rustfmt::skip] mod gasp;
+#[rustfmt::skip] mod gdef;
+#[rustfmt::skip] mod ggg;
 #[rustfmt::skip] mod glyf;
+#[rustfmt::skip] mod gpos;
+#[rustfmt::skip] mod gsub;
+#[rustfmt::skip] mod hdmx;
+#[rustfmt::skip] mod head;
+#[rustfmt::skip] mod hhea;
 #[rustfmt::skip] mod hmtx;
+#[rustfmt::skip] mod lcar;
+#[rustfmt::skip] mod math;
 #[rustfmt::skip] mod maxp;
+#[rustfmt::skip] mod meta;
+#[rustfmt::skip] mod name;
+#[rustfmt::skip] mod opentype_tags;
+#[rustfmt::skip] mod os2;
 #[rustfmt::skip] mod sbix;
 #[rustfmt::skip] mod trak;
+#[rustfmt::skip] mod vdmx;
 
 use ttf_parser::{fonts_in_collection, Face, FaceParsingError};
 
@@ -0,0 +1,202 @@
+use ttf_parser::{math, GlyphId};
+use crate::{convert, Unit::*};
+
+fn math_data() -> Vec<u8> {
+    convert(&[
+        UInt16(1), // major version
+        UInt16(0), // minor version
+        UInt16(0), // offset to MathConstants
+        UInt16(0), // offset to MathGlyphInfo
+        UInt16(10), // offset to MathVariants
+
+        // MathVariants
+        UInt16(20), // min connector overlap
+        UInt16(12), // offset to vertical coverage
+        UInt16(0), // offset to horizontal coverage
+        UInt16(1), // vertical glyph count
+        UInt16(0), // horizontal glyph count
+        UInt16(18), // offset to MathGlyphConstruction [0]
+
+        // Coverage
+        UInt16(1), // format
+        UInt16(1), // glyph count
+        UInt16(1), // glyph [0]
+
+        // MathGlyphConstruction
+        UInt16(12), // offset to GlyphAssembly
+        UInt16(2), // variant count
+        // MathGlyphVariantRecord [0]
+        UInt16(1), // variant glyph
+        UInt16(100), // advance measurement
+        // MathGlyphVariantRecord [1]
+        UInt16(2), // variant glyph
+        UInt16(200), // advance measurement
+
+        // GlyphAssembly
+        Int16(0), // italics correction value
+        UInt16(0), // italics correction device offset
+        UInt16(3), // part count
+        // GlyphPart [0]
+        UInt16(3), // glyph ID
+        UInt16(0), // start connector length
+        UInt16(50), // end connector length
+        UInt16(300), // full advance
+        UInt16(0), // flags
+        // GlyphPart [1]
+        UInt16(4), // glyph ID
+        UInt16(50), // start connector length
+        UInt16(50), // end connector length
+        UInt16(200), // full advance
+        UInt16(1), // flags: extender
+        // GlyphPart [2]
+        UInt16(5), // glyph ID
+        UInt16(50), // start connector length
+        UInt16(0), // end connector length
+        UInt16(300), // full advance
+        UInt16(0), // flags
+    ])
+}
+
+fn parts(glyph: math::StretchedGlyph) -> Vec<(u16, f32)> {
+    match glyph {
+        math::StretchedGlyph::Assembly(assembly) => {
+            assembly.parts().map(|p| (p.glyph_id.0, p.offset)).collect()
+        }
+        math::StretchedGlyph::Variant(_) => panic!("expected an assembly"),
+    }
+}
+
+#[test]
+fn stretch_to_variant() {
+    let data = math_data();
+    let variants = math::Table::parse(&data).unwrap().variants.unwrap();
+    let stretch = |target| match variants.stretch(GlyphId(1), math::Direction::Vertical, target) {
+        Some(math::StretchedGlyph::Variant(v)) => Some(v.variant_glyph),
+        _ => None,
+    };
+
+    assert_eq!(stretch(50), Some(GlyphId(1)));
+    assert_eq!(stretch(150), Some(GlyphId(2)));
+    assert_eq!(stretch(200), Some(GlyphId(2)));
+    assert!(variants.stretch(GlyphId(1), math::Direction::Horizontal, 50).is_none());
+    assert!(variants.stretch(GlyphId(2), math::Direction::Vertical, 50).is_none());
+}
+
+#[test]
+fn stretch_without_extenders() {
+    let data = math_data();
+    let variants = math::Table::parse(&data).unwrap().variants.unwrap();
+    let glyph = variants.stretch(GlyphId(1), math::Direction::Vertical, 500).unwrap();
+    if let math::StretchedGlyph::Assembly(assembly) = glyph {
+        assert_eq!(assembly.extender_repeats(), 0);
+        // Limited by connectors.
+        assert_eq!(assembly.overlap(), 50.0);
+        assert_eq!(assembly.size(), 550.0);
+    }
+
+    assert_eq!(parts(glyph), [(3, 0.0), (5, 250.0)]);
+}
+
+#[test]
+fn stretch_with_extenders() {
+    let data = math_data();
+    let variants = math::Table::parse(&data).unwrap().variants.unwrap();
+    let glyph = variants.stretch(GlyphId(1), math::Direction::Vertical, 1000).unwrap();
+    if let math::StretchedGlyph::Assembly(assembly) = glyph {
+        assert_eq!(assembly.extender_repeats(), 3);
+        assert_eq!(assembly.overlap(), 50.0);
+        assert_eq!(assembly.size(), 1000.0);
+    }
+
+    assert_eq!(parts(glyph), [(3, 0.0), (4, 250.0), (4, 400.0), (4, 550.0), (5, 700.0)]);
+}
+
+#[test]
+fn stretch_with_min_overlap() {
+    let data = math_data();
+    let variants = math::Table::parse(&data).unwrap().variants.unwrap();
+    let glyph = variants.stretch(GlyphId(1), math::Direction::Vertical, 740).unwrap();
+    if let math::StretchedGlyph::Assembly(assembly) = glyph {
+        assert_eq!(assembly.extender_repeats(), 1);
+        // 800 - 740 = 60, distributed between 2 joins.
+        assert_eq!(assembly.overlap(), 30.0);
+        assert_eq!(assembly.size(), 740.0);
+    }
+}
+
+fn glyph_info_data() -> Vec<u8> {
+    convert(&[
+        UInt16(1), // major version
+        UInt16(0), // minor version
+        UInt16(0), // offset to MathConstants
+        UInt16(10), // offset to MathGlyphInfo
+        UInt16(0), // offset to MathVariants
+
+        // MathGlyphInfo
+        UInt16(8), // offset to MathItalicsCorrectionInfo
+        UInt16(0), // offset to MathTopAccentAttachment
+        UInt16(0), // offset to extended shape coverage
+        UInt16(22), // offset to MathKernInfo
+
+        // MathItalicsCorrectionInfo
+        UInt16(8), // offset to coverage
+        UInt16(1), // italics correction count
+        Int16(42), // italics correction value [0]
+        UInt16(0), // device offset [0]
+        // Coverage
+        UInt16(1), // format
+        UInt16(1), // glyph count
+        UInt16(1), // glyph [0]
+
+        // MathKernInfo
+        UInt16(12), // offset to coverage
+        UInt16(1), // kern info count
+        // MathKernInfoRecord [0]
+        UInt16(18), // offset to top right MathKern
+        UInt16(0), // offset to top left MathKern
+        UInt16(0), // offset to bottom right MathKern
+        UInt16(0), // offset to bottom left MathKern
+        // Coverage
+        UInt16(1), // format
+        UInt16(1), // glyph count
+        UInt16(1), // glyph [0]
+        // MathKern
+        UInt16(2), // height count
+        Int16(100), // correction height [0]
+        UInt16(0), // device offset
+        Int16(200), // correction height [1]
+        UInt16(0), // device offset
+        Int16(-10), // kern value [0]
+        UInt16(0), // device offset
+        Int16(-20), // kern value [1]
+        UInt16(0), // device offset
+        Int16(-30), // kern value [2]
+        UInt16(0), // device offset
+    ])
+}
+
+#[test]
+fn kern_at() {
+    let data = glyph_info_data();
+    let glyph_info = math::Table::parse(&data).unwrap().glyph_info.unwrap();
+
+    let correction = glyph_info.italic_corrections.unwrap().get(GlyphId(1)).unwrap();
+    assert_eq!(correction.value, 42);
+
+    let kern_info = glyph_info.kern_infos.unwrap().get(GlyphId(1)).unwrap();
+    assert!(kern_info.corner(math::Corner::TopLeft).is_none());
+
+    let kern = kern_info.corner(math::ScriptPosition::Superscript.base_corner()).unwrap();
+    assert_eq!(kern.kern_at(50.0).unwrap().value, -10);
+    assert_eq!(kern.kern_at(100.0).unwrap().value, -20);
+    assert_eq!(kern.kern_at(150.0).unwrap().value, -20);
+    assert_eq!(kern.kern_at(250.0).unwrap().value, -30);
+}
+
+#[test]
+fn script_corners() {
+    assert_eq!(math::ScriptPosition::Subscript.base_corner(), math::Corner::BottomRight);
+    assert_eq!(math::ScriptPosition::Subscript.script_corner(), math::Corner::TopLeft);
+    assert_eq!(math::ScriptPosition::PreSuperscript.script_corner(), math::Corner::BottomRight);
+    assert!(!math::ScriptPosition::PreSubscript.is_superscript());
+}
@@ -16,21 +16,36 @@ fn version_1_full() {
     let table = Table::parse(&convert(&[
         Fixed(1.0), // version
         UInt16(1), // number of glyphs
-        UInt16(0), // maximum points in a non-composite glyph
-        UInt16(0), // maximum contours in a non-composite glyph
-        UInt16(0), // maximum points in a composite glyph
-        UInt16(0), // maximum contours in a composite glyph
-        UInt16(0), // maximum zones
-        UInt16(0), // maximum twilight points
-        UInt16(0), // number of Storage Area locations
-        UInt16(0), // number of FDEFs
-        UInt16(0), // number of IDEFs
-        UInt16(0), // maximum stack depth
-        UInt16(0), // maximum byte count for glyph instructions
-        UInt16(0), // maximum number of components
-        UInt16(0), // maximum levels of recursion
+        UInt16(1), // maximum points in a non-composite glyph
+        UInt16(2), // maximum contours in a non-composite glyph
+        UInt16(3), // maximum points in a composite glyph
+        UInt16(4), // maximum contours in a composite glyph
+        UInt16(5), // maximum zones
+        UInt16(6), // maximum twilight points
+        UInt16(7), // number of Storage Area locations
+        UInt16(8), // number of FDEFs
+        UInt16(9), // number of IDEFs
+        UInt16(10), // maximum stack depth
+        UInt16(11), // maximum byte count for glyph instructions
+        UInt16(12), // maximum number of components
+        UInt16(13), // maximum levels of recursion
     ])).unwrap();
     assert_eq!(table.number_of_glyphs, NonZeroU16::new(1).unwrap());
+
+    let limits = table.limits.unwrap();
+    assert_eq!(limits.max_points, 1);
+    assert_eq!(limits.max_contours, 2);
+    assert_eq!(limits.max_composite_points, 3);
+    assert_eq!(limits.max_composite_contours, 4);
+    assert_eq!(limits.max_zones, 5);
+    assert_eq!(limits.max_twilight_points, 6);
+    assert_eq!(limits.max_storage, 7);
+    assert_eq!(limits.max_function_defs, 8);
+    assert_eq!(limits.max_instruction_defs, 9);
+    assert_eq!(limits.max_stack_elements, 10);
+    assert_eq!(limits.max_size_of_instructions, 11);
+    assert_eq!(limits.max_component_elements, 12);
+    assert_eq!(limits.max_component_depth, 13);
 }
 
 #[test]
@@ -41,6 +56,7 @@ fn version_1_trimmed() {
         UInt16(1), // number of glyphs
     ])).unwrap();
     assert_eq!(table.number_of_glyphs, NonZeroU16::new(1).unwrap());
+    assert!(table.limits.is_none());
 }
 
 #[test]
@@ -0,0 +1,119 @@
+use ttf_parser::meta::Table;
+use ttf_parser::Tag;
+use crate::{convert, Unit::*};
+
+#[test]
+fn parse() {
+    let data = convert(&[
+        UInt32(1), // version
+        UInt32(0), // flags
+        UInt32(0), // reserved
+        UInt32(3), // number of data maps
+
+        // DataMap [0]
+        Raw(b"dlng"), // tag
+        UInt32(52), // offset
+        UInt32(7), // length
+
+        // DataMap [1]
+        Raw(b"slng"), // tag
+        UInt32(59), // offset
+        UInt32(18), // length
+
+        // DataMap [2]
+        Raw(b"APPL"), // tag
+        UInt32(77), // offset
+        UInt32(2), // length
+
+        // Data
+        Raw(b"zh-Hant"),
+        Raw(b"Hant, en,  ja-JP,,"),
+        Raw(&[1, 2]),
+    ]);
+
+    let table = Table::parse(&data).unwrap();
+    assert_eq!(table.data_maps.len(), 3);
+
+    let design = table.design_languages();
+    assert_eq!(design.into_iter().collect::<Vec<_>>(), vec!["zh-Hant"]);
+    assert!(design.supports("zh-TW"));
+    assert!(design.supports("zh-Hant-CN"));
+    assert!(!design.supports("zh-CN"));
+    assert!(!design.supports("zh"));
+    assert!(!design.supports("en"));
+
+    let supported = table.supported_languages();
+    assert_eq!(supported.into_iter().collect::<Vec<_>>(), vec!["Hant", "en", "ja-JP"]);
+    assert!(supported.supports("zh-HK"));
+    assert!(supported.supports("en-US"));
+    assert!(supported.supports("ja-JP"));
+    assert!(!supported.supports("ja"));
+    assert!(!supported.supports("zh-Hans"));
+    assert!(!supported.supports(""));
+
+    let map = table.data_maps.find(Tag::from_bytes(b"APPL")).unwrap();
+    assert_eq!(map.data, &[1, 2]);
+}
+
+#[test]
+fn script_only_tags() {
+    let data = convert(&[
+        UInt32(1), // version
+        UInt32(0), // flags
+        UInt32(0), // reserved
+        UInt32(2), // number of data maps
+
+        // DataMap [0]
+        Raw(b"dlng"), // tag
+        UInt32(40), // offset
+        UInt32(8), // length
+
+        // DataMap [1]
+        Raw(b"slng"), // tag
+        UInt32(48), // offset
+        UInt32(16), // length
+
+        // Data
+        Raw(b"und-Latn"),
+        Raw(b"Latn, Cyrl, Arab"),
+    ]);
+
+    let table = Table::parse(&data).unwrap();
+
+    let design = table.design_languages();
+    assert!(design.supports("en-US"));
+    assert!(design.supports("de"));
+    assert!(!design.supports("ru"));
+
+    let supported = table.supported_languages();
+    assert!(supported.supports("en"));
+    assert!(supported.supports("ru"));
+    assert!(supported.supports("ar"));
+    assert!(supported.supports("sr-Latn"));
+    assert!(!supported.supports("el"));
+    assert!(!supported.supports("ja"));
+    // Low German has no known default script.
+    assert!(!supported.supports("nds"));
+}
+
+#[test]
+fn out_of_bounds_data() {
+    let data = convert(&[
+        UInt32(1), // version
+        UInt32(0), // flags
+        UInt32(0), // reserved
+        UInt32(1), // number of data maps
+
+        // DataMap [0]
+        Raw(b"dlng"), // tag
+        UInt32(28), // offset
+        UInt32(10), // length
+
+        // Data
+        Raw(b"en"),
+    ]);
+
+    let table = Table::parse(&data).unwrap();
+    assert!(table.data_maps.get(0).is_none());
+    assert!(table.design_languages().is_empty());
+}
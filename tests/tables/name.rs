@@ -0,0 +1,212 @@
+use ttf_parser::name::{Table, name_id};
+use crate::{convert, Unit::*};
+
+#[test]
+fn mac_roman() {
+    let data = convert(&[
+        UInt16(0), // version
+        UInt16(1), // number of records
+        UInt16(18), // offset to storage
+
+        // NameRecord [0]
+        UInt16(1), // platform ID
+        UInt16(0), // encoding ID
+        UInt16(0), // language ID
+        UInt16(name_id::FAMILY), // name ID
+        UInt16(4), // length
+        UInt16(0), // offset
+
+        // Storage
+        Raw(&[0x43, 0x61, 0x66, 0x8E]), // "Café"
+    ]);
+
+    let table = Table::parse(&data).unwrap();
+    let name = table.names.get(0).unwrap();
+    assert!(!name.is_unicode());
+    assert_eq!(name.to_string().as_deref(), Some("Café"));
+}
+
+#[test]
+fn mac_icelandic() {
+    let data = convert(&[
+        UInt16(0), // version
+        UInt16(1), // number of records
+        UInt16(18), // offset to storage
+
+        // NameRecord [0]
+        UInt16(1), // platform ID
+        UInt16(0), // encoding ID
+        UInt16(15), // language ID
+        UInt16(name_id::FAMILY), // name ID
+        UInt16(1), // length
+        UInt16(0), // offset
+
+        // Storage
+        Raw(&[0xDC]), // "Ð"
+    ]);
+
+    let table = Table::parse(&data).unwrap();
+    assert_eq!(table.names.get(0).unwrap().to_string().as_deref(), Some("Ð"));
+}
+
+#[test]
+fn custom_decoder() {
+    let data = convert(&[
+        UInt16(0), // version
+        UInt16(2), // number of records
+        UInt16(30), // offset to storage
+
+        // NameRecord [0]
+        UInt16(3), // platform ID
+        UInt16(2), // encoding ID (ShiftJIS)
+        UInt16(0x0411), // language ID
+        UInt16(name_id::FAMILY), // name ID
+        UInt16(2), // length
+        UInt16(0), // offset
+
+        // NameRecord [1]
+        UInt16(3), // platform ID
+        UInt16(1), // encoding ID (Unicode BMP)
+        UInt16(0x0409), // language ID
+        UInt16(name_id::FAMILY), // name ID
+        UInt16(2), // length
+        UInt16(2), // offset
+
+        // Storage
+        Raw(&[0x82, 0xA0]), // "あ" in ShiftJIS
+        UInt16(0x0041), // "A"
+    ]);
+
+    let table = Table::parse(&data).unwrap();
+    let decoder = |name: &ttf_parser::name::Name| {
+        assert_eq!(name.encoding_id, 2);
+        if name.name == [0x82, 0xA0] { Some("あ".to_string()) } else { None }
+    };
+
+    let name = table.names.get(0).unwrap();
+    assert_eq!(name.to_string(), None);
+    assert_eq!(name.to_string_with(decoder).as_deref(), Some("あ"));
+
+    let name = table.names.get(1).unwrap();
+    assert_eq!(name.to_string_with(decoder).as_deref(), Some("A"));
+}
+
+#[test]
+fn language_tags() {
+    let data = convert(&[
+        UInt16(1), // version
+        UInt16(2), // number of records
+        UInt16(36), // offset to storage
+
+        // NameRecord [0]
+        UInt16(3), // platform ID
+        UInt16(1), // encoding ID
+        UInt16(0x8000), // language ID
+        UInt16(name_id::FAMILY), // name ID
+        UInt16(2), // length
+        UInt16(0), // offset
+
+        // NameRecord [1]
+        UInt16(3), // platform ID
+        UInt16(1), // encoding ID
+        UInt16(0x8001), // language ID
+        UInt16(name_id::FAMILY), // name ID
+        UInt16(2), // length
+        UInt16(0), // offset
+
+        UInt16(1), // number of language tags
+
+        // LangTagRecord [0]
+        UInt16(14), // length
+        UInt16(2), // offset
+
+        // Storage
+        UInt16(0x0041), // "A"
+        UInt16(0x007A), UInt16(0x0068), UInt16(0x002D), UInt16(0x0048),
+        UInt16(0x0061), UInt16(0x006E), UInt16(0x0074), // "zh-Hant"
+    ]);
+
+    let table = Table::parse(&data).unwrap();
+    let name = table.names.get(0).unwrap();
+    assert_eq!(name.to_string().as_deref(), Some("A"));
+    let tag = name.language_tag.unwrap();
+    assert_eq!(tag.to_string(), "zh-Hant");
+    assert!(tag.eq_ignore_ascii_case("ZH-hant"));
+    assert!(!tag.eq_ignore_ascii_case("zh-Han"));
+    assert!(!tag.eq_ignore_ascii_case("zh-Hant-HK"));
+
+    // Out of bounds tag index.
+    assert!(table.names.get(1).unwrap().language_tag.is_none());
+}
+
+#[test]
+fn find_localized() {
+    let data = convert(&[
+        UInt16(0), // version
+        UInt16(5), // number of records
+        UInt16(66), // offset to storage
+
+        // NameRecord [0]
+        UInt16(1), // platform ID
+        UInt16(0), // encoding ID
+        UInt16(0), // language ID
+        UInt16(name_id::FAMILY), // name ID
+        UInt16(1), // length
+        UInt16(8), // offset
+
+        // NameRecord [1]
+        UInt16(3), // platform ID
+        UInt16(1), // encoding ID
+        UInt16(0x0409), // language ID
+        UInt16(name_id::FAMILY), // name ID
+        UInt16(2), // length
+        UInt16(0), // offset
+
+        // NameRecord [2]
+        UInt16(3), // platform ID
+        UInt16(1), // encoding ID
+        UInt16(0x0404), // language ID
+        UInt16(name_id::FAMILY), // name ID
+        UInt16(2), // length
+        UInt16(2), // offset
+
+        // NameRecord [3]
+        UInt16(3), // platform ID
+        UInt16(1), // encoding ID
+        UInt16(0x0804), // language ID
+        UInt16(name_id::FAMILY), // name ID
+        UInt16(2), // length
+        UInt16(4), // offset
+
+        // NameRecord [4]
+        UInt16(3), // platform ID
+        UInt16(1), // encoding ID
+        UInt16(0x0407), // language ID
+        UInt16(name_id::FAMILY), // name ID
+        UInt16(2), // length
+        UInt16(6), // offset
+
+        // Storage
+        UInt16(0x0041), // "A"
+        UInt16(0x0042), // "B"
+        UInt16(0x0043), // "C"
+        UInt16(0x0044), // "D"
+        Raw(&[0x45]), // "E"
+    ]);
+
+    let table = Table::parse(&data).unwrap();
+    let find = |locale| {
+        table.names.find_localized(name_id::FAMILY, locale).and_then(|n| n.to_string())
+    };
+
+    assert_eq!(find("en-US").as_deref(), Some("A"));
+    assert_eq!(find("en-GB").as_deref(), Some("A"));
+    assert_eq!(find("zh-TW").as_deref(), Some("B"));
+    assert_eq!(find("zh-Hant-HK").as_deref(), Some("B"));
+    assert_eq!(find("zh-CN").as_deref(), Some("C"));
+    assert_eq!(find("zh-Hans").as_deref(), Some("C"));
+    assert_eq!(find("DE").as_deref(), Some("D"));
+    assert_eq!(find("fr").as_deref(), Some("A"));
+    assert_eq!(find("").as_deref(), Some("A"));
+    assert!(table.names.find_localized(name_id::FULL_NAME, "en").is_none());
+}
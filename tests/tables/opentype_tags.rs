@@ -0,0 +1,141 @@
+use ttf_parser::opentype_layout::LayoutTable;
+use ttf_parser::opentype_tags::*;
+use ttf_parser::Tag;
+use crate::{convert, Unit::*};
+
+fn tags(tags: Tags) -> Vec<String> {
+    tags.map(|tag| tag.to_string()).collect()
+}
+
+#[test]
+fn script_tags_mapping() {
+    assert_eq!(tags(script_tags("deva")), ["dev3", "dev2", "deva"]);
+    assert_eq!(tags(script_tags("Mymr")), ["mym2", "mymr"]);
+    assert_eq!(tags(script_tags("Latn")), ["latn"]);
+    assert_eq!(tags(script_tags("Zyyy")), ["DFLT"]);
+    assert_eq!(tags(script_tags("Jpan")), ["hani", "kana"]);
+    assert_eq!(tags(script_tags("Laoo")), ["lao "]);
+    assert!(tags(script_tags("Lat")).is_empty());
+
+    assert_eq!(script_code(Tag::from_bytes(b"dev2")), Some("Deva"));
+    assert_eq!(script_code(Tag::from_bytes(b"latn")), Some("Latn"));
+    assert_eq!(script_code(Tag::from_bytes(b"kana")), Some("Kana"));
+    assert_eq!(script_code(Tag::from_bytes(b"lao ")), Some("Laoo"));
+    assert_eq!(script_code(DEFAULT_SCRIPT), Some("Zyyy"));
+    assert_eq!(script_code(Tag::from_bytes(b"abcd")), None);
+}
+
+#[test]
+fn language_tags_mapping() {
+    assert_eq!(tags(language_tags("tr-TR")), ["TRK "]);
+    assert_eq!(tags(language_tags("hy")), ["HYE0", "HYE "]);
+    assert_eq!(tags(language_tags("nn-NO")), ["NYN ", "NOR "]);
+    assert_eq!(tags(language_tags("zh")), ["ZHS "]);
+    assert_eq!(tags(language_tags("zh-Hans-SG")), ["ZHS "]);
+    assert_eq!(tags(language_tags("zh_TW")), ["ZHT "]);
+    assert_eq!(tags(language_tags("zh-HK")), ["ZHH ", "ZHT "]);
+    assert_eq!(tags(language_tags("zh-MO")), ["ZHTM", "ZHH ", "ZHT "]);
+    assert!(tags(language_tags("xx")).is_empty());
+    assert!(tags(language_tags("")).is_empty());
+
+    assert_eq!(language_code(Tag::from_bytes(b"TRK ")), Some("tr"));
+    assert_eq!(language_code(Tag::from_bytes(b"NOR ")), Some("nb"));
+    assert_eq!(language_code(Tag::from_bytes(b"ZHS ")), Some("zh-Hans"));
+    assert_eq!(language_code(Tag::from_bytes(b"ZHH ")), Some("zh-HK"));
+    assert_eq!(language_code(DEFAULT_LANGUAGE), None);
+
+    assert_eq!(default_script("ru"), Some("Cyrl"));
+    assert_eq!(default_script("sr-Latn"), Some("Latn"));
+    assert_eq!(default_script("zh-TW"), Some("Hant"));
+    assert_eq!(default_script("zh"), Some("Hani"));
+    assert_eq!(default_script("ja-JP"), Some("Jpan"));
+    assert_eq!(default_script("xx"), None);
+}
+
+#[test]
+fn select_language_system() {
+    let data = convert(&[
+        UInt16(1), // major version
+        UInt16(0), // minor version
+        UInt16(10), // offset to script list
+        UInt16(90), // offset to feature list
+        UInt16(92), // offset to lookup list
+
+        // Script List
+        UInt16(3), // number of scripts
+        // Script Record [0]
+        UInt32(Tag::from_bytes(b"dev2").0), // tag
+        UInt16(20), // offset to script
+        // Script Record [1]
+        UInt32(Tag::from_bytes(b"hani").0), // tag
+        UInt16(42), // offset to script
+        // Script Record [2]
+        UInt32(Tag::from_bytes(b"latn").0), // tag
+        UInt16(58), // offset to script
+
+        // Script [0]
+        UInt16(10), // offset to default language system
+        UInt16(1), // number of language systems
+        // Language System Record [0]
+        UInt32(Tag::from_bytes(b"MAR ").0), // tag
+        UInt16(16), // offset to language system
+        // Default Language System
+        UInt16(0), // lookup order
+        UInt16(0xFFFF), // required feature index
+        UInt16(0), // number of features
+        // Language System [0]
+        UInt16(0), // lookup order
+        UInt16(0xFFFF), // required feature index
+        UInt16(0), // number of features
+
+        // Script [1]
+        UInt16(0), // offset to default language system
+        UInt16(1), // number of language systems
+        // Language System Record [0]
+        UInt32(Tag::from_bytes(b"ZHT ").0), // tag
+        UInt16(10), // offset to language system
+        // Language System [0]
+        UInt16(0), // lookup order
+        UInt16(0xFFFF), // required feature index
+        UInt16(0), // number of features
+
+        // Script [2]
+        UInt16(10), // offset to default language system
+        UInt16(1), // number of language systems
+        // Language System Record [0]
+        UInt32(Tag::from_bytes(b"TRK ").0), // tag
+        UInt16(16), // offset to language system
+        // Default Language System
+        UInt16(0), // lookup order
+        UInt16(0xFFFF), // required feature index
+        UInt16(0), // number of features
+        // Language System [0]
+        UInt16(0), // lookup order
+        UInt16(0xFFFF), // required feature index
+        UInt16(0), // number of features
+
+        // Feature List
+        UInt16(0), // number of features
+
+        // Lookup List
+        UInt16(0), // number of lookups
+    ]);
+
+    let table = LayoutTable::parse(&data).unwrap();
+    let select = |locale| {
+        table
+            .select_language_system(locale)
+            .map(|(script, language)| (script.tag.to_string(), language.tag.to_string()))
+    };
+
+    let pair = |script: &str, language: &str| Some((script.to_string(), language.to_string()));
+    assert_eq!(select("mr-IN"), pair("dev2", "MAR "));
+    assert_eq!(select("hi"), pair("dev2", "dflt"));
+    assert_eq!(select("tr"), pair("latn", "TRK "));
+    assert_eq!(select("zh-TW"), pair("hani", "ZHT "));
+    // `hani` has neither `ZHS ` nor a default language system.
+    assert_eq!(select("zh-CN"), pair("latn", "dflt"));
+    // There is no `cyrl` script.
+    assert_eq!(select("ru"), pair("latn", "dflt"));
+    assert_eq!(select(""), pair("latn", "dflt"));
+}
@@ -0,0 +1,93 @@
+use ttf_parser::os2::Table;
+use ttf_parser::Tag;
+use crate::{convert, Unit::*};
+
+fn table_v5() -> Vec<u8> {
+    convert(&[
+        UInt16(5), // version
+        Int16(500), // average char width
+        UInt16(400), // weight class
+        UInt16(5), // width class
+        UInt16(0), // type
+        Raw(&[0; 20]), // subscript, superscript and strikeout metrics
+        Int16(0), // family class
+        Raw(&[2, 11, 5, 2, 4, 5, 4, 2, 2, 4]), // PANOSE
+        UInt32(1), // Unicode range 1
+        UInt32(0), // Unicode range 2
+        UInt32(0), // Unicode range 3
+        UInt32(0), // Unicode range 4
+        Raw(b"TEST"), // vendor ID
+        UInt16(0x0160), // selection
+        UInt16(0x0020), // first char index
+        UInt16(0xFFFF), // last char index
+        Int16(800), // typographic ascender
+        Int16(-200), // typographic descender
+        Int16(0), // typographic line gap
+        UInt16(1000), // Windows ascender
+        UInt16(250), // Windows descender
+        UInt32(0x0000_0003), // code page range 1
+        UInt32(0x8000_0000), // code page range 2
+        Int16(500), // x height
+        Int16(700), // capital height
+        UInt16(0), // default char
+        UInt16(0x0020), // break char
+        UInt16(3), // max context
+        UInt16(180), // lower optical point size
+        UInt16(0xFFFF), // upper optical point size
+    ])
+}
+
+#[test]
+fn v5_fields() {
+    let data = table_v5();
+    let table = Table::parse(&data).unwrap();
+
+    let panose = table.panose();
+    assert_eq!(panose.family_type, 2);
+    assert_eq!(panose.serif_style, 11);
+    assert_eq!(panose.weight, 5);
+    assert_eq!(panose.x_height, 4);
+
+    assert_eq!(table.vendor_id(), Tag::from_bytes(b"TEST"));
+
+    let flags = table.selection_flags();
+    assert!(flags.bold());
+    assert!(flags.regular());
+    assert!(flags.wws());
+    assert!(!flags.italic());
+    assert!(!flags.use_typo_metrics());
+
+    assert_eq!(table.first_char_index(), 0x0020);
+    assert_eq!(table.last_char_index(), 0xFFFF);
+
+    let ranges = table.code_page_ranges().unwrap();
+    assert!(ranges.contains_code_page(1252));
+    assert!(ranges.contains_code_page(1250));
+    assert!(ranges.contains_code_page(437));
+    assert!(!ranges.contains_code_page(1251));
+    assert!(!ranges.contains_code_page(1));
+    assert!(!ranges.contains_symbol());
+
+    assert_eq!(table.default_char(), Some(0));
+    assert_eq!(table.break_char(), Some(0x0020));
+    assert_eq!(table.max_context(), Some(3));
+    assert_eq!(table.lower_optical_point_size(), Some(9.0));
+    assert_eq!(table.upper_optical_point_size(), Some(3276.75));
+}
+
+#[test]
+fn v0_fields() {
+    let mut data = table_v5();
+    data[1] = 0; // version
+    data.truncate(78);
+    let table = Table::parse(&data).unwrap();
+
+    assert_eq!(table.vendor_id(), Tag::from_bytes(b"TEST"));
+    assert_eq!(table.last_char_index(), 0xFFFF);
+    assert!(table.code_page_ranges().is_none());
+    assert!(table.default_char().is_none());
+    assert!(table.break_char().is_none());
+    assert!(table.max_context().is_none());
+    assert!(table.lower_optical_point_size().is_none());
+    assert!(table.upper_optical_point_size().is_none());
+}
@@ -0,0 +1,75 @@
+use ttf_parser::vdmx::{Metrics, Table};
+use crate::{convert, Unit::*};
+
+#[test]
+fn basic() {
+    let data = convert(&[
+        UInt16(1), // version
+        UInt16(2), // number of groups
+        UInt16(2), // number of ratio ranges
+
+        // RatioRange [0]
+        UInt8(1), // character set
+        UInt8(2), // x ratio
+        UInt8(1), // y start ratio
+        UInt8(1), // y end ratio
+
+        // RatioRange [1]
+        UInt8(1), // character set
+        UInt8(0), // x ratio
+        UInt8(0), // y start ratio
+        UInt8(0), // y end ratio
+
+        // Offsets
+        UInt16(18), // offset [0]
+        UInt16(28), // offset [1]
+
+        // VDMX Group [0]
+        UInt16(1), // number of records
+        UInt8(10), // start size
+        UInt8(10), // end size
+        UInt16(10), // y pel height
+        Int16(8), // y max
+        Int16(-3), // y min
+
+        // VDMX Group [1]
+        UInt16(2), // number of records
+        UInt8(10), // start size
+        UInt8(11), // end size
+        UInt16(10), // y pel height
+        Int16(9), // y max
+        Int16(-2), // y min
+        UInt16(11), // y pel height
+        Int16(10), // y max
+        Int16(-3), // y min
+    ]);
+
+    let table = Table::parse(&data).unwrap();
+    assert_eq!(table.ratios.len(), 2);
+    assert_eq!(table.group(1).unwrap().metrics.len(), 2);
+
+    assert_eq!(
+        table.metrics(2, 1, 10),
+        Some(Metrics { pixels_per_em: 10, y_max: 8, y_min: -3 })
+    );
+    assert_eq!(
+        table.metrics(1, 1, 10),
+        Some(Metrics { pixels_per_em: 10, y_max: 9, y_min: -2 })
+    );
+    assert_eq!(
+        table.metrics(1, 1, 11),
+        Some(Metrics { pixels_per_em: 11, y_max: 10, y_min: -3 })
+    );
+    assert_eq!(table.metrics(1, 1, 12), None);
+}
+
+#[test]
+fn unknown_version() {
+    let data = convert(&[
+        UInt16(2), // version
+        UInt16(0), // number of groups
+        UInt16(0), // number of ratio ranges
+    ]);
+
+    assert!(Table::parse(&data).is_none());
+}